@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, clippy::derivable_impls, clippy::new_ret_no_self)]
 
 #[macro_use]
 extern crate derive_builder;
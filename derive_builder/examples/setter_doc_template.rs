@@ -0,0 +1,29 @@
+//! Setters can get their doc comments from a template, which keeps `#![deny(missing_docs)]`
+//! happy for fields without documentation of their own.
+//!
+//! NOTE: This can only be tested in examples, but not integration tests.
+#![deny(missing_docs)]
+
+#[macro_use]
+extern crate derive_builder;
+
+/// Traditional form of communication.
+#[derive(Debug, Builder)]
+#[builder(setter(into, doc = "Sets the `{field}` of the letter."))]
+pub struct Letter {
+    /// Be creative.
+    pub message: String,
+    recipient: String,
+    #[builder(setter(doc = "Sets who signs the letter."))]
+    signature: String,
+}
+
+fn main() {
+    let x = LetterBuilder::default()
+        .message("Hello World!")
+        .recipient("Jane")
+        .signature("John")
+        .build()
+        .unwrap();
+    println!("{} {} {}", x.recipient, x.message, x.signature);
+}
@@ -23,7 +23,7 @@ impl UninitializedFieldError {
 
 impl fmt::Display for UninitializedFieldError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Field not initialized: {}", self.0)
+        write!(f, "`{}` must be initialized", self.0)
     }
 }
 
@@ -35,3 +35,214 @@ impl From<&'static str> for UninitializedFieldError {
         Self::new(field_name)
     }
 }
+
+/// Runtime error when a `#[builder(setter(once))]` setter is called for a field which
+/// already has a value.
+#[derive(Debug, Clone)]
+pub struct AlreadySetError(&'static str);
+
+impl AlreadySetError {
+    /// Create a new `AlreadySetError` for the specified field name.
+    pub fn new(field_name: &'static str) -> Self {
+        AlreadySetError(field_name)
+    }
+
+    /// Get the name of the field that was set twice
+    pub fn field_name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for AlreadySetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is already set", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AlreadySetError {}
+
+impl From<&'static str> for AlreadySetError {
+    fn from(field_name: &'static str) -> Self {
+        Self::new(field_name)
+    }
+}
+
+/// Runtime error when a `#[builder(setter(checked_add))]` method adds to a field and the sum
+/// overflows.
+#[derive(Debug, Clone)]
+pub struct OverflowError(&'static str);
+
+impl OverflowError {
+    /// Create a new `OverflowError` for the specified field name.
+    pub fn new(field_name: &'static str) -> Self {
+        OverflowError(field_name)
+    }
+
+    /// Get the name of the field whose sum overflowed
+    pub fn field_name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Adding to `{}` overflowed", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for OverflowError {}
+
+impl From<&'static str> for OverflowError {
+    fn from(field_name: &'static str) -> Self {
+        Self::new(field_name)
+    }
+}
+
+/// Runtime error when the `build()` method of a `#[derive(EnumBuilder)]` builder is called
+/// before any variant was chosen.
+#[derive(Debug, Clone)]
+pub struct UnchosenError(&'static str);
+
+impl UnchosenError {
+    /// Create a new `UnchosenError` for the specified enum name.
+    pub fn new(enum_name: &'static str) -> Self {
+        UnchosenError(enum_name)
+    }
+
+    /// Get the name of the enum whose variant was not chosen
+    pub fn enum_name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for UnchosenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No variant of `{}` was chosen", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for UnchosenError {}
+
+/// Runtime error when a builder's `from_env` constructor cannot read or parse an
+/// environment variable.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVarError {
+    var_name: String,
+    message: String,
+}
+
+#[cfg(feature = "std")]
+impl EnvVarError {
+    /// Create a new `EnvVarError` for the specified variable name.
+    pub fn new(var_name: String, message: String) -> Self {
+        EnvVarError { var_name, message }
+    }
+
+    /// Get the name of the environment variable that could not be used
+    pub fn var_name(&self) -> &str {
+        &self.var_name
+    }
+
+    /// Get the description of what went wrong, e.g. the parse error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for EnvVarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Environment variable {}: {}",
+            self.var_name, self.message
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for EnvVarError {}
+
+/// Runtime error when the `from_env` constructor of a builder with
+/// `#[builder(impl_from_env_prefixed(env_prefix = "..."))]` cannot read the environment.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    /// The variables of required fields which are not set, in field declaration order.
+    Missing(Vec<&'static str>),
+    /// A variable which is set, but is not valid unicode or cannot be parsed.
+    Invalid(EnvVarError),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EnvError::Missing(ref var_names) => {
+                write!(f, "Missing environment variables: {}", var_names.join(", "))
+            }
+            EnvError::Invalid(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for EnvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            EnvError::Missing(_) => None,
+            EnvError::Invalid(ref error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<EnvVarError> for EnvError {
+    fn from(error: EnvVarError) -> Self {
+        EnvError::Invalid(error)
+    }
+}
+
+/// Runtime error when the `parse_from_str` function of a builder's field enum cannot parse a
+/// value, or does not know the field name.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldParseError {
+    field_name: String,
+    message: String,
+}
+
+#[cfg(feature = "std")]
+impl FieldParseError {
+    /// Create a new `FieldParseError` for the specified field name.
+    pub fn new(field_name: String, message: String) -> Self {
+        FieldParseError {
+            field_name,
+            message,
+        }
+    }
+
+    /// Get the name of the field whose value could not be parsed
+    pub fn field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    /// Get the description of what went wrong, e.g. the parse error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for FieldParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Field {}: {}", self.field_name, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for FieldParseError {}
@@ -119,6 +119,7 @@
 //!
 //! * Setters take and return `self`.
 //! * PRO: Setter calls and final build method can be chained.
+//! * PRO: The build method moves the fields out of the builder, without cloning them.
 //! * CON: If you don't chain your calls, you have to create a reference to each return value,
 //!   e.g. `builder = builder.ipsum(42)`.
 //!
@@ -137,12 +138,33 @@
 //!
 //! Precede your struct (or field) with `#[builder(pattern = "immutable")]` to opt into this pattern.
 //!
-//! * Setters take and return `&self`.
+//! * Setters take `&self` and return a modified clone of the builder, the build method takes
+//!   `&self`.
 //! * PRO: Setter calls and final build method can be chained.
+//! * PRO: An intermediate builder can be kept and branched into several configurations.
 //! * CON: If you don't chain your calls, you have to create a reference to each return value,
 //!   e.g. `builder = builder.ipsum(42)`.
 //! * CON: The build method _and each setter_ must clone or copy data to create something owned
-//!   out of a reference. **(*)**
+//!   out of a reference. **(*)** Type parameters of generic builders are bounded by `Clone`.
+//!
+//! ```rust
+//! # #[macro_use] extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(pattern = "immutable")]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: u32,
+//! }
+//!
+//! # fn main() {
+//! let base = LoremBuilder::default().ipsum(1);
+//! let a = base.dolor(2).build().unwrap();
+//! let b = base.dolor(3).build().unwrap();
+//!
+//! assert_eq!(a, Lorem { ipsum: 1, dolor: 2 });
+//! assert_eq!(b, Lorem { ipsum: 1, dolor: 3 });
+//! # }
+//! ```
 //!
 //! ## (*) Performance Considerations
 //!
@@ -209,6 +231,12 @@
 //! - `#[builder(setter(custom = true))]`
 //! - `#[builder(setter(custom = false))]`
 //!
+//! Custom setters are declared on the field rather than by marking methods in an
+//! `impl FooBuilder` block: a derive macro only sees the struct it is attached to, so it cannot
+//! find out which setters another item provides. The field is still initialized by the
+//! generated build method, so a custom setter only needs to store the value in the builder
+//! field.
+//!
 //! ## Setter Visibility
 //!
 //! Setters are public by default. You can precede your struct (or field) with `#[builder(public)]`
@@ -217,6 +245,53 @@
 //! Otherwise precede your struct (or field) with `#[builder(private)]` to opt into private
 //! setters.
 //!
+//! Fields with a restricted visibility such as `pub(super)` or `pub(crate)` get setters with the
+//! same visibility, unless `public`, `private` or `vis = "..."` is declared on the field or the
+//! struct. Up to 0.11 these setters were always public; add `#[builder(public)]` to the struct
+//! to keep them that way:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! mod outer {
+//!     pub mod lorem {
+//!         #[derive(Builder)]
+//!         #[builder(public)]
+//!         pub struct Lorem {
+//!             pub(super) ipsum: u8,
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! // Without `#[builder(public)]`, `ipsum` could only be set within `outer`.
+//! outer::lorem::LoremBuilder::default().ipsum(1).build().unwrap();
+//! # }
+//! ```
+//!
+//! With `#[builder(setters_visibility_from_struct)]`, the remaining setters get the visibility
+//! of the struct instead of `pub`, like the builder itself, so that the setters of a
+//! `pub(crate) struct` are `pub(crate)` too.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(setters_visibility_from_struct)]
+//! pub(crate) struct Lorem {
+//!     ipsum: u32,
+//!     #[builder(public)]
+//!     dolor: u32,
+//! }
+//! #
+//! # fn main() {
+//! #     let x = LoremBuilder::default().ipsum(1).dolor(2).build().unwrap();
+//! #     assert_eq!(x, Lorem { ipsum: 1, dolor: 2 });
+//! # }
+//! ```
+//!
 //! ## Generated builder struct name
 //!
 //! By default, the builder struct for `struct Foo` is `FooBuilder`.
@@ -236,6 +311,36 @@
 //! # }
 //! ```
 //!
+//! ## Builder Entry Point
+//!
+//! The struct also gets an inherent `builder()` method returning a default builder, with the
+//! struct's visibility. Name it differently with `#[builder(builder_method = "new_builder")]`,
+//! or leave it out with `#[builder(builder_method = false)]`, e.g. if the struct defines its own
+//! `builder()`. It is not emitted with `#[builder(custom_constructor)]`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! # fn main() {
+//! let x = Lorem::builder().ipsum(42).build().unwrap();
+//! assert_eq!(x, Lorem { ipsum: 42 });
+//! # }
+//! ```
+//!
+//! ## Empty Builders
+//!
+//! Every builder has an inherent `create_empty()` function returning a builder with all fields
+//! unset, which `Default` and `builder()` call. Default values are only applied by the build
+//! method, so this is a blank slate, e.g. for tests that set just the fields they care about.
+//! Rename it with `#[builder(create_empty = "bare")]`. It has the builder's visibility, unless
+//! the builder has a `custom_constructor`, where it stays private to the builder's module.
+//!
 //! ## Setter Name/Prefix
 //!
 //! Setter methods are named after their corresponding field by default.
@@ -244,9 +349,76 @@
 //! - Alternatively you can set a prefix via `#[builder(setter(prefix = "xyz"))`, which will change
 //!   the method name to `xyz_foo` if the field is named `foo`. Note that an underscore is
 //!   inserted, since Rust favors snake case here.
+//! - Likewise, a suffix set via `#[builder(setter(suffix = "xyz"))` changes the method name to
+//!   `foo_xyz`. Combined with a prefix, as in `#[builder(setter(prefix = "with", suffix =
+//!   "value"))`, the method is named `with_foo_value`.
+//!
+//! Prefixes and suffixes can also be defined on the struct level, but renames only work on
+//! fields. Renames take precedence over prefix and suffix definitions. An empty prefix or suffix
+//! behaves as if it was not set.
+//!
+//! Add `keep_bare` next to a prefix or suffix, as in `#[builder(setter(prefix = "with", keep_bare))]`, to
+//! emit both `with_foo` and `foo`. The bare setter simply calls the prefixed one.
+//!
+//! Setters are defined on the builder, so they never collide with inherent methods of your
+//! struct, such as a getter named like the field. If the builder itself needs a method of that
+//! name, prefix the setters to free it up:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder)]
+//! #[builder(setter(prefix = "with"))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! impl Lorem {
+//!     pub fn ipsum(&self) -> u32 {
+//!         self.ipsum
+//!     }
+//! }
+//!
+//! impl LoremBuilder {
+//!     pub fn ipsum(&self) -> Option<u32> {
+//!         self.ipsum
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.with_ipsum(42);
+//! assert_eq!(builder.ipsum(), Some(42));
+//! assert_eq!(builder.build().unwrap().ipsum(), 42);
+//! # }
+//! ```
+//!
+//! Two fields whose setters end up with the same name, e.g. through `name`, `prefix`, `each`,
+//! `each(remove)`, `try_setter` or `type_alias`, are reported as an error at both fields.
 //!
-//! Prefixes can also be defined on the struct level, but renames only work on fields. Renames
-//! take precedence over prefix definitions.
+//! ## Deprecated Setters
+//!
+//! `#[builder(setter(deprecated = "..."))]` marks the setters of a field with
+//! `#[deprecated(note = "...")]`, so calling them triggers the `deprecated` lint with your note.
+//! This covers all setters of the field, including its `try_`, `each` and unprefixed setters.
+//! The builder's own use of the setters, e.g. by `impl_extend`, does not trigger the lint.
+//!
+//! ```rust
+//! # #![allow(deprecated)]
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! struct Lorem {
+//!     #[builder(setter(deprecated = "use `dolor` instead"))]
+//!     ipsum: u32,
+//!     #[builder(default)]
+//!     dolor: u32,
+//! }
+//! #
+//! # fn main() {
+//! # let _ = LoremBuilder::default().ipsum(1).build();
+//! # }
+//! ```
 //!
 //! ## Generic Setters
 //!
@@ -273,6 +445,61 @@
 //! }
 //! ```
 //!
+//! For `String` fields, `#[builder(setter(as_ref))]` instead makes the setter generic over
+//! `AsRef<str>`, so it accepts `&str`, `&String` and `String` alike and stores an owned copy.
+//! It cannot be combined with `setter(into)` on the same field, and overrides a struct-level
+//! `setter(into)`.
+//!
+//! Fields holding a tuple newtype, such as `struct Kilograms(f32)`, can use
+//! `#[builder(setter(newtype_inner))]`. The setter is then generic over `Into<f32>` and wraps
+//! the converted value in `Kilograms`. The macro cannot see the definition of the newtype, so
+//! mark it with `#[derive(Newtype)]`, which implements [`Newtype`] for tuple structs with a
+//! single field:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Debug, PartialEq, Clone, Newtype)]
+//! struct Kilograms(f32);
+//!
+//! #[derive(Builder)]
+//! struct Parcel {
+//!     #[builder(setter(newtype_inner))]
+//!     weight: Kilograms,
+//! }
+//!
+//! # fn main() {
+//! let parcel = ParcelBuilder::default().weight(2u8).build().unwrap();
+//! assert_eq!(parcel.weight, Kilograms(2.0));
+//! # }
+//! ```
+//!
+//! For newtypes which cannot derive `Newtype`, e.g. because they are defined in another crate,
+//! spell out the inner type with `#[builder(setter(newtype_inner = "f32"))]`. The setter then
+//! wraps the value as `Kilograms(value.into())`, so the field of the newtype must be accessible
+//! where the builder is defined.
+//!
+//! Setters of `String` fields can normalize their value with
+//! `#[builder(setter(normalize = "..."))]`, after any `into` or `as_ref` conversion. The
+//! built-in normalizers are `trim`, `lowercase` and `uppercase`; any other value is the path of
+//! a function taking `&str` and returning `String`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(into, normalize = "trim"))]
+//!     ipsum: String,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default().ipsum("  foo ").build().unwrap();
+//! assert_eq!(x.ipsum, "foo");
+//! # }
+//! ```
+//!
 //! ## Setters for Option
 //!
 //! You can avoid to user to wrap value into `Some(...)` for field of type `Option<T>`. It's as simple as adding
@@ -304,6 +531,215 @@
 //!
 //! Limitation: only the `Option` type name is supported, not type alias nor `std::option::Option`.
 //!
+//! `strip_option` peels exactly one `Option` layer. The setter of a tri-state
+//! `Option<Option<T>>` field therefore takes an `Option<T>`, which it stores as `Some(value)`:
+//! passing `None` sets the field to `Some(None)`, while leaving the field unset gives `None`
+//! (with `default`).
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(strip_option), default)]
+//!     pub ipsum: Option<Option<bool>>,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(Some(true)).build().unwrap();
+//!     assert_eq!(x.ipsum, Some(Some(true)));
+//!
+//!     let x = LoremBuilder::default().ipsum(None).build().unwrap();
+//!     assert_eq!(x.ipsum, Some(None));
+//!
+//!     let x = LoremBuilder::default().build().unwrap();
+//!     assert_eq!(x.ipsum, None);
+//! }
+//! ```
+//!
+//! To offer both, `#[builder(setter(option_setter))]` strips the `Option` from the setter of
+//! the field, and adds a `maybe_` setter taking the `Option` as is, which clears the field
+//! when passed `None`. The prefix can be changed with `option_setter(prefix = "...")`, and
+//! `strip_option = false` keeps the `Option` in the primary setter.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(into, option_setter))]
+//!     pub ipsum: Option<String>,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum("foo").build().unwrap();
+//!     assert_eq!(x.ipsum, Some("foo".to_string()));
+//!
+//!     let fallback: Option<String> = None;
+//!     let x = LoremBuilder::default().ipsum("foo").maybe_ipsum(fallback).build().unwrap();
+//!     assert_eq!(x.ipsum, None);
+//! }
+//! ```
+//!
+//! ## Setters for Smart Pointers
+//!
+//! `#[builder(setter(boxed))]` on a field of type `Box<T>`, `Rc<T>` or `Arc<T>` makes the setter
+//! generic over `Into<T>` and wrap the value in the pointer. It can be combined with
+//! `strip_option` for fields such as `Option<Box<T>>`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! # use std::rc::Rc;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(boxed))]
+//!     ipsum: Box<u32>,
+//!     #[builder(setter(boxed))]
+//!     dolor: Rc<String>,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default().ipsum(1u8).dolor("dolor").build().unwrap();
+//! assert_eq!(x, Lorem { ipsum: Box::new(1), dolor: Rc::new("dolor".into()) });
+//! # }
+//! ```
+//!
+//! ## Setters for Collections
+//!
+//! `#[builder(setter(each(name = "...")))]` on a field whose type implements `Default` and
+//! `Extend`, such as `Vec<T>` or `HashMap<K, V>`, adds a setter of that name pushing one item
+//! at a time. With `each(name = "...", into)`, that setter accepts any `Into<T>` too, while
+//! the shorthand `each = "..."` takes the item as is.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(each(name = "arg", into)))]
+//!     args: Vec<String>,
+//!     #[builder(setter(each = "flag"))]
+//!     flags: Vec<char>,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default()
+//!     .arg("--ipsum")
+//!     .arg(String::from("--dolor"))
+//!     .flag('v')
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(x.args, vec!["--ipsum".to_string(), "--dolor".to_string()]);
+//! assert_eq!(x.flags, vec!['v']);
+//! # }
+//! ```
+//!
+//! `#[builder(setter(into_iter))]` on a collection field such as `Vec<T>` or `BTreeSet<T>` makes
+//! the setter accept any `IntoIterator` whose items implement `Into<T>`, and collect them into
+//! the field. Arrays, iterators and other collections can then be passed without converting
+//! them first. Combined with `each`, the setter replaces the whole collection while the `each`
+//! setter adds one item.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(into_iter, each = "ipsum_item"))]
+//!     ipsum: Vec<String>,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default()
+//!     .ipsum(vec!["a", "b"])
+//!     .ipsum_item("c".to_string())
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(x, Lorem { ipsum: vec!["a".into(), "b".into(), "c".into()] });
+//! # }
+//! ```
+//!
+//! For `Vec` fields, `each(name = "...", dedup)` makes the `each` setter skip an item equal to
+//! one already in the field, which requires `PartialEq` only on that setter. With
+//! `dedup = "path::to::fn"`, items are compared by the keys the `Fn(&T) -> K` returns instead.
+//! The setter of the whole collection keeps its items as they are.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! fn lowercase(tag: &str) -> String {
+//!     tag.to_lowercase()
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(each(name = "feature", dedup)))]
+//!     features: Vec<u8>,
+//!     #[builder(setter(each(name = "tag", dedup = "lowercase")))]
+//!     tags: Vec<String>,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default()
+//!     .feature(1)
+//!     .feature(1)
+//!     .tag("Ipsum".to_string())
+//!     .tag("ipsum".to_string())
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(x, Lorem { features: vec![1], tags: vec!["Ipsum".into()] });
+//! # }
+//! ```
+//!
+//! ## Transforming Setter Arguments
+//!
+//! `#[builder(setter(transform = "|x: String| x.to_uppercase()"))]` makes the setter take the
+//! type of the closure's parameter and store what the closure returns, which must be the field
+//! type. This is more general than `into` or `as_ref`, which it cannot be combined with. Such a
+//! setter has no `try_` variant, which would bypass the closure.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(transform = "|(major, minor): (u8, u8)| format!(\"{}.{}\", major, minor)"))]
+//!     version: String,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default().version((1, 2)).build().unwrap();
+//! assert_eq!(x, Lorem { version: "1.2".into() });
+//! # }
+//! ```
+//!
+//! ## Setter Parameter Type Aliases
+//!
+//! `#[builder(setter(type_alias = "LoremPort"))]` makes the setter take a `LoremPort` instead
+//! of the field type. A bare identifier emits `type LoremPort = u16;` next to the builder, with
+//! the setter's visibility; a path such as `"crate::Port"` or `"self::Port"` refers to an alias
+//! you declared yourself, which is not emitted again. An emitted alias cannot refer to the
+//! struct's type parameters.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(type_alias = "LoremPort"))]
+//!     port: u16,
+//! }
+//!
+//! # fn main() {
+//! let port: LoremPort = 8080;
+//! let x = LoremBuilder::default().port(port).build().unwrap();
+//! assert_eq!(x, Lorem { port: 8080 });
+//! # }
+//! ```
+//!
 //! ## Fallible Setters
 //!
 //! Alongside the normal setter methods, you can expose fallible setters which are generic over
@@ -343,162 +779,1407 @@
 //!        .build()
 //!        .expect("1 fits into a u8");
 //! }
-//! ```
-//!
-//! ## Default Values
+//! ```
+//!
+//! To make the setter itself fallible instead, use `#[builder(setter(try_into))]`: it is generic
+//! over `TryInto<T>` and returns a `Result` with the conversion error, storing the value only if
+//! the conversion succeeds. This is the fallible counterpart to `setter(into)`, and cannot be
+//! combined with it or with other options converting the value.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(try_into))]
+//!     pub ipsum: u8,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     assert!(builder.ipsum(256u16).is_err());
+//!
+//!     let x = builder.ipsum(42u16).unwrap().build().unwrap();
+//!     assert_eq!(x, Lorem { ipsum: 42 });
+//! }
+//! ```
+//!
+//! ## Validating Setters
+//!
+//! `#[builder(setter(validate = "..."))]` checks the value when it is set rather than when the
+//! struct is built. The expression, a closure or a path to a function, is called with a
+//! reference to the converted value and returns `Result<(), E>`. The setter then returns
+//! `Result<&mut Self, FooBuilderError>` (or `Result<Self, FooBuilderError>` for the owned and
+//! immutable patterns), converting `E` into the builder's error type, and leaves the field
+//! unchanged on error. No `try_` setter is emitted for such fields.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(validate = r#"|v: &i32| if *v < 0 { Err("negative".to_string()) } else { Ok(()) }"#))]
+//!     ipsum: i32,
+//! }
+//!
+//! # fn main() {
+//! assert!(LoremBuilder::default().ipsum(-1).is_err());
+//! let x = LoremBuilder::default().ipsum(1).unwrap().build().unwrap();
+//! assert_eq!(x, Lorem { ipsum: 1 });
+//! # }
+//! ```
+//!
+//! ## Set-Once Setters
+//!
+//! Fields which must not be overwritten can use `#[builder(setter(once))]`. The setter then
+//! returns `Result<&mut Self, AlreadySetError>` (or `Result<Self, AlreadySetError>` for the owned
+//! and immutable patterns), failing if the field already has a value. Use
+//! `#[builder(setter(once(panic)))]` to keep the plain signature and panic instead.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(once))]
+//!     ipsum: u32,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(1).unwrap();
+//! let err = builder.ipsum(2).err().unwrap();
+//! assert_eq!(&err.to_string(), "`ipsum` is already set");
+//! # }
+//! ```
+//!
+//! `once` cannot be combined with a custom builder field type, with `each` setters or with
+//! `try_setter`, whose `try_` setter could not return the `AlreadySetError`. `once(panic)` works
+//! with `try_setter`.
+//!
+//! ## Setters Returning the Previous Value
+//!
+//! `#[builder(setter(return_previous))]` makes a setter return what the builder field held
+//! before the call, `None` if it was unset, instead of the builder. Such a setter can no longer
+//! be chained, and it is only available with the mutable pattern. It cannot be combined with
+//! `setter(once)`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! struct Lorem {
+//!     #[builder(setter(return_previous))]
+//!     state: u32,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! assert_eq!(builder.state(1), None);
+//! assert_eq!(builder.state(2), Some(1));
+//! # }
+//! ```
+//!
+//! ## Adding to Integer Fields
+//!
+//! `#[builder(setter(checked_add))]` on a field of a primitive integer type, like `u64` or
+//! `i32`, emits two more methods next to its setter, which add a `delta` to the field:
+//!
+//! - `try_add_foo(delta)` returns an `OverflowError` naming the field if the sum overflows, and
+//!   leaves the builder unchanged.
+//! - `saturating_add_foo(delta)` stops at the numeric bounds instead.
+//!
+//! Both set an unset field to `delta`, as if it was zero.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! struct Lorem {
+//!     #[builder(setter(checked_add))]
+//!     retries: u8,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.retries(200).try_add_retries(50).unwrap();
+//! assert!(builder.try_add_retries(50).is_err());
+//! builder.saturating_add_retries(50);
+//! assert_eq!(builder.build().unwrap().retries, 255);
+//! # }
+//! ```
+//!
+//! ## Modifying Fields in Place
+//!
+//! `#[builder(setter(with_mut))]` additionally emits a `with_ipsum` method for a field `ipsum`,
+//! which passes a mutable reference to the field to a closure, rather than replacing its value.
+//! An unset field is first set to its default value, so the field needs `default` or
+//! `setter(or_default)`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! struct Lorem {
+//!     #[builder(setter(with_mut), default = "vec![\"ipsum\".to_string()]")]
+//!     ipsum: Vec<String>,
+//! }
+//!
+//! # fn main() {
+//! let x = LoremBuilder::default()
+//!     .with_ipsum(|v| v.push("dolor".to_string()))
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(x.ipsum, ["ipsum", "dolor"]);
+//! # }
+//! ```
+//!
+//! ## Default Values
+//!
+//! You can define default values for each field via annotation by `#[builder(default = "...")]`,
+//! where `...` stands for any Rust expression and must be string-escaped, e.g.
+//!
+//! * `#[builder(default = "42")]`
+//! * `#[builder(default = "Status::Code(404)")]`
+//! * `#[builder(default)]` delegates to the [`Default`] trait of the base type.
+//!
+//! The expression will be evaluated with each call to `build`. It is type-checked against the
+//! field type, and a mismatch is reported at the default expression.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(default = "42")]
+//!     pub ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     // If we don't set the field `ipsum`,
+//!     let x = LoremBuilder::default().build().unwrap();
+//!
+//!     // .. the custom default will be used for `ipsum`:
+//!     assert_eq!(x, Lorem {
+//!         ipsum: 42,
+//!     });
+//! }
+//! ```
+//!
+//! ### Fallible Defaults
+//!
+//! Add `try` next to a default expression which returns a `Result`, as in
+//! `#[builder(default = "...", try)]`. The build method evaluates it like any other default,
+//! but returns its error, converted with `Into` into the build method's error type. The
+//! generated error type converts from `String`; a custom error type needs a `From` impl for
+//! the error of the expression.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! fn port_from_env() -> Result<u16, String> {
+//!     std::env::var("LOREM_PORT")
+//!         .map_err(|e| e.to_string())?
+//!         .parse()
+//!         .map_err(|e: std::num::ParseIntError| e.to_string())
+//! }
+//!
+//! #[derive(Builder, Debug)]
+//! struct Lorem {
+//!     #[builder(default = "port_from_env()", try)]
+//!     port: u16,
+//! }
+//!
+//! # fn main() {
+//! # std::env::remove_var("LOREM_PORT");
+//! assert!(LoremBuilder::default().build().is_err());
+//! assert_eq!(LoremBuilder::default().port(80).build().unwrap().port, 80);
+//! # }
+//! ```
+//!
+//! ### Defaulting to the field type's `Default`
+//!
+//! `#[builder(setter(or_default))]` on a field is shorthand for falling back to
+//! `<FieldType as Default>::default()` when the field is unset. Unlike `#[builder(default)]`,
+//! this adds an explicit `FieldType: Default` bound to the build method, so the requirement
+//! shows up in the generated signature. It can be combined with `setter(skip)` and
+//! `setter(strip_option)`, but not with `default`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(or_default))]
+//!     pub ipsum: Vec<u32>,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().build().unwrap();
+//!     assert_eq!(x, Lorem { ipsum: vec![] });
+//! }
+//! ```
+//!
+//! ### Storing fields without `Option`
+//!
+//! `#[builder(field(default))]` on the struct stores every builder field as its plain type,
+//! initialized with `Default::default()`, instead of wrapping it in an `Option`. Every field
+//! type must implement `Default`, and the build method returns the struct itself instead of a
+//! `Result`, since it can no longer fail.
+//!
+//! The tradeoff is that the builder cannot tell an unset field from one set to its default
+//! value, so there are no uninitialized field errors. For the same reason, this cannot be
+//! combined with `#[builder(default)]`, `#[builder(setter(once))]`, build-time validation or
+//! `#[builder(lazy_build)]`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(field(default))]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: String,
+//! }
+//!
+//! fn main() {
+//!     let x: Lorem = LoremBuilder::default().ipsum(42).build();
+//!     assert_eq!(x, Lorem { ipsum: 42, dolor: String::new() });
+//! }
+//! ```
+//!
+//! ### Required Fields
+//!
+//! `#[builder(required)]` exempts a field from the struct-level `#[builder(default)]`, so the
+//! build method fails with an uninitialized field error unless it was set. This comes in handy
+//! for `Option` fields which must be decided explicitly, rather than silently defaulting to
+//! `None`: the builder still stores the `Option` and tells whether it was set. It cannot be
+//! combined with a default of the field's own, `setter(skip)` or a custom `field(type = "...")`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, Default, PartialEq)]
+//! #[builder(default)]
+//! struct Lorem {
+//!     #[builder(setter(strip_option), required)]
+//!     ipsum: Option<String>,
+//!     dolor: Option<u32>,
+//! }
+//!
+//! fn main() {
+//!     assert!(LoremBuilder::default().dolor(Some(42)).build().is_err());
+//!
+//!     let x = LoremBuilder::default().ipsum("ipsum".to_string()).build().unwrap();
+//!     assert_eq!(x, Lorem { ipsum: Some("ipsum".to_string()), dolor: None });
+//! }
+//! ```
+//!
+//! ### Builders Starting from the Struct's Default
+//!
+//! With `#[builder(default_struct)]` on a struct implementing `Default`, the builder's `Default`
+//! impl (and thus `Foo::builder()`) calls `Foo::default()` and sets each builder field from it,
+//! so the defaults are written once, in `impl Default for Foo`. Unlike the struct-level
+//! `#[builder(default)]`, the fields are set in the builder itself, where they can be read.
+//!
+//! Fields with a default of their own, such as `#[builder(default = "...")]`, keep it and start
+//! unset, as do required fields and fields without a setter. Since the fields are moved out of
+//! `Foo::default()`, the struct cannot implement `Drop`. This cannot be combined with
+//! `custom_constructor` or `field(default)`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(default_struct)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     #[builder(default = "8080")]
+//!     port: u16,
+//! }
+//!
+//! impl Default for Lorem {
+//!     fn default() -> Self {
+//!         Lorem { ipsum: "ipsum".to_string(), port: 80 }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let builder = LoremBuilder::default();
+//!     assert_eq!(builder.build().unwrap(), Lorem { ipsum: "ipsum".to_string(), port: 8080 });
+//! }
+//! ```
+//!
+//! ### Tips on Defaults
+//!
+//! * The `#[builder(default)]` annotation can be used on the struct level, too. Overrides are
+//!   still possible.
+//! * Delegate to a private helper method on `FooBuilder` for anything fancy. This way
+//!   you will get _much better error diagnostics_ from the rust compiler and it will be _much
+//!   more readable_ for other human beings. :-)
+//! * Defaults will not work while using `#[builder(build_fn(skip))]`. In this case, you'll
+//!   need to handle default values yourself when converting from the builder, such as by
+//!   using `.unwrap_or()` and `.unwrap_or_else()`.
+//!
+//! [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! # #[derive(Builder, PartialEq, Debug)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     // Custom defaults can delegate to helper methods
+//!     // and pass errors to the enclosing `build()` method via `?`.
+//!     #[builder(default = "self.default_dolor()?")]
+//!     dolor: String,
+//! }
+//!
+//! impl LoremBuilder {
+//!     // Private helper method with access to the builder struct.
+//!     fn default_dolor(&self) -> Result<String, String> {
+//!         match self.ipsum {
+//!             Some(ref x) if x.chars().count() > 3 => Ok(format!("dolor {}", x)),
+//!             _ => Err("ipsum must at least 3 chars to build dolor".to_string()),
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #     let x = LoremBuilder::default()
+//! #         .ipsum("ipsum".to_string())
+//! #         .build()
+//! #         .unwrap();
+//! #
+//! #     assert_eq!(x, Lorem {
+//! #         ipsum: "ipsum".to_string(),
+//! #         dolor: "dolor ipsum".to_string(),
+//! #     });
+//! # }
+//! ```
+//!
+//! You can even reference other fields, but you have to remember that the builder struct
+//! will wrap every type in an Option ([as illustrated earlier](#what-you-get)).
+//!
+//! ## Lazy Defaults
+//!
+//! `#[builder(setter(lazy = "|| ..."))]` computes the value of an unset field by calling a
+//! closure, or the path of a function, in the build method rather than when the builder is
+//! created. The builder field holds
+//! an `Option<Box<dyn FnOnce() -> T>>`, which the setter fills with a closure returning its value.
+//! Calling a `FnOnce` consumes it, so this requires `pattern = "owned"`, and the builder cannot
+//! derive `Clone`, `PartialEq` or `Eq`. Lazy fields can't be hashed or read from the environment, and `impl_diff` and
+//! `impl_display` only tell whether they are set.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! fn resolve_host() -> String {
+//!     // e.g. a DNS lookup, which should only happen if no host is set
+//!     "127.0.0.1".to_string()
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(pattern = "owned")]
+//! struct Lorem {
+//!     #[builder(setter(into, lazy = "|| resolve_host()"))]
+//!     host: String,
+//! }
+//!
+//! # fn main() {
+//! let lorem = LoremBuilder::default().build().unwrap();
+//! assert_eq!(lorem.host, "127.0.0.1");
+//! # }
+//! ```
+//!
+//! ## Mapping Values in the Build Method
+//!
+//! `#[builder(field(map = "..."))]` applies a function or closure to the value of a field in
+//! the build method. It runs after defaulting, so it also sees default values, and its result
+//! goes into the built struct. It takes and returns the type of the target field.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(into), field(map = "|v| v.trim().to_string()"))]
+//!     ipsum: String,
+//!     #[builder(default = "5", field(map = "|v| v.min(3)"))]
+//!     dolor: u32,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum("  sit ").build().unwrap();
+//!     assert_eq!(x, Lorem { ipsum: "sit".to_string(), dolor: 3 });
+//! }
+//! ```
+//!
+//! ## Computed Fields
+//!
+//! `#[builder(field(computed = "..."))]` computes a field from other fields in the build method,
+//! instead of generating a setter for it. The expression reads the values the other fields get
+//! in the built struct as `self.field`, after validation and defaulting. These values still go
+//! into the built struct, so the expression has to clone those which are not `Copy`.
+//!
+//! Computed fields may read each other, in any declaration order, as long as no field is
+//! computed from itself, directly or through other computed fields.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Rectangle {
+//!     width: u32,
+//!     height: u32,
+//!     #[builder(field(computed = "self.area >= 100"))]
+//!     large: bool,
+//!     #[builder(field(computed = "self.width * self.height"))]
+//!     area: u32,
+//! }
+//!
+//! fn main() {
+//!     let x = RectangleBuilder::default().width(4).height(5).build().unwrap();
+//!     assert_eq!(x, Rectangle { width: 4, height: 5, large: false, area: 20 });
+//! }
+//! ```
+//!
+//! ## Options per Field Type
+//!
+//! Instead of repeating the same options on every field of a type, declare them once with
+//! `#[builder(on(type = "...", ...))]`. A rule applies to every field whose type is written
+//! exactly like `type`, token by token, so `String` does not match `std::string::String`. It
+//! supports these options:
+//!
+//! - `into`, like `#[builder(setter(into))]` on each field. A rule takes precedence over the
+//!   struct-level `setter(into)`, so `into = false` opts the matching fields out again.
+//! - `transform = "..."`, like `#[builder(field(map = "..."))]` on each field.
+//!
+//! Options set on a field itself take precedence over any rule. When several rules match a
+//! field, the option of the last one declared wins.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! fn trim_to_owned(value: String) -> String {
+//!     value.trim().to_owned()
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(setter(into), on(type = "String", into = false, transform = "trim_to_owned"))]
+//! struct Lorem {
+//!     ipsum: String,
+//!     #[builder(setter(into))]
+//!     dolor: String,
+//!     sit: u64,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default()
+//!         .ipsum(" ipsum ".to_string())
+//!         .dolor(" dolor ")
+//!         .sit(1u8)
+//!         .build()
+//!         .unwrap();
+//!     assert_eq!(x, Lorem { ipsum: "ipsum".into(), dolor: "dolor".into(), sit: 1 });
+//! }
+//! ```
+//!
+//! ## Conditional Options
+//!
+//! Builder options can be wrapped in `cfg_attr`, on the struct as well as on fields. The
+//! compiler expands `cfg_attr` before the derive sees the struct, so an option only applies
+//! while its condition holds:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! struct Lorem {
+//!     // `ipsum` is optional only with the `std` feature of this crate.
+//!     #[cfg_attr(feature = "std", builder(default = "1"))]
+//!     ipsum: u32,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ## Generic Structs
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq, Default, Clone)]
+//! struct GenLorem<T: Clone> {
+//!     ipsum: &'static str,
+//!     dolor: T,
+//! }
+//!
+//! fn main() {
+//!     let x = GenLoremBuilder::default().ipsum("sit").dolor(42).build().unwrap();
+//!     assert_eq!(x, GenLorem { ipsum: "sit".into(), dolor: 42 });
+//! }
+//! ```
+//!
+//! Lifetimes, type parameters and const generics such as `struct Buf<const N: usize>` are
+//! carried over to the builder, so `BufBuilder::<4>::default()` builds a `Buf<4>`. Defaults of
+//! type and const parameters are not, so the builder's parameters need to be given or inferred.
+//! A borrowed field such as `name: &'a str` is stored as `Option<&'a str>`, so the builder
+//! borrows the value just like the struct.
+//!
+//! Field types are copied to the builder as written, including qualified paths such as
+//! `<T as Trait>::Out`. `Self` in a field type is replaced with the struct, e.g. `Vec<Self>`
+//! becomes `Vec<Lorem<T>>` in the builder, where `Self` would name the builder instead.
+//!
+//! A default of `T::default()` for a type parameter `T`, as in
+//! `#[builder(default = "T::default()")]`, adds a `T: Default` bound to the build method, so the
+//! struct itself does not need to declare it. Other default expressions rely on the bounds of the
+//! struct.
+//!
+//! ## Build Method Customization
+//!
+//! You can rename or suppress the auto-generated build method, leaving you free to implement
+//! your own version. Suppression is done using `#[builder(build_fn(skip))]` at the struct level,
+//! and renaming is done with `#[builder(build_fn(name = "YOUR_NAME"))]`.
+//!
+//! ### Build Method Receiver
+//!
+//! The build method takes `self` under the owned pattern and `&self` otherwise. Override this
+//! with `#[builder(build_fn(receiver = "self"))]`, `"ref"` or `"ref_mut"`. A consuming build
+//! method moves the fields out of the builder instead of cloning them, even if the setters
+//! take `&mut self`; such a builder must be bound to a variable before it is built. A
+//! field-level `pattern` only changes the setters of that field, not how the build method reads
+//! it.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(receiver = "self"))]
+//! struct Lorem {
+//!     ipsum: String,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     builder.ipsum("ipsum".to_string());
+//!     assert_eq!(builder.build().unwrap(), Lorem { ipsum: "ipsum".to_string() });
+//! }
+//! ```
+//!
+//! ### Build Method Return Type
+//!
+//! `#[builder(build_fn(into = "Arc<Lorem>"))]` makes the build method return an `Arc<Lorem>`,
+//! or any other type implementing `From<Lorem>`, converting the built struct. With just
+//! `#[builder(build_fn(into))]`, the build method is generic over the returned type, which the
+//! caller chooses, e.g. with `let x: Box<Lorem> = builder.build()?`. Neither can be combined
+//! with `lazy_build` or `impl_from_parts`; the unchecked and panicking build methods still return
+//! the struct itself.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! # use std::sync::Arc;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(into = "Arc<Lorem>"))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(1).build().unwrap();
+//!     assert_eq!(x, Arc::new(Lorem { ipsum: 1 }));
+//! }
+//! ```
+//!
+//! When neither `error` nor `into` is expressive enough, `#[builder(build_fn(result_type =
+//! "anyhow::Result<Lorem>"))]` sets the full return type of the build method, which must be a
+//! `Result`. The build runs as usual, and its error is converted into the error type of
+//! `result_type` with `?`, so that type must implement `From<LoremBuilderError>` (or whichever
+//! error the build method would otherwise return). The `Ok` type must be what the build method
+//! would otherwise return, e.g. the type given to `into`. This cannot be combined with
+//! `#[builder(field(default))]`, `impl_from_parts` or `into` without a type.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Debug)]
+//! struct AppError(String);
+//!
+//! impl From<LoremBuilderError> for AppError {
+//!     fn from(error: LoremBuilderError) -> Self {
+//!         AppError(error.to_string())
+//!     }
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(result_type = "Result<Lorem, AppError>"))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     let error = LoremBuilder::default().build().unwrap_err();
+//!     assert_eq!(error.0, "`ipsum` must be initialized");
+//! }
+//! ```
+//!
+//! ### Unchecked Build Method
+//!
+//! `#[builder(build_fn(unchecked))]` additionally emits an `unsafe fn build_unchecked()`,
+//! named after the build method, which returns the struct directly. It assumes that every
+//! field without a default value is set, and skips the validation function. Calling it with
+//! an unset required field is undefined behavior; its `# Safety` section lists the fields
+//! the caller must set. Defaults and custom field builds must not use `?` with this option.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(unchecked))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     // SAFETY: `ipsum` is set.
+//!     let x = unsafe { LoremBuilder::default().ipsum(42).build_unchecked() };
+//!     assert_eq!(x, Lorem { ipsum: 42 });
+//! }
+//! ```
+//!
+//! ### Panicking Build Method
+//!
+//! `#[builder(build_fn(expect))]` additionally emits a safe `fn build_expect()`, named after the
+//! build method, which returns the struct directly as well. This saves handling the error in
+//! prototypes and tests which set every field: it panics with a message naming the first unset
+//! field without a default value, and skips the validation function. Its `# Panics` section
+//! lists the fields which must be set. Defaults and custom field builds must not use `?` with
+//! this option.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(expect))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(42).build_expect();
+//!     assert_eq!(x, Lorem { ipsum: 42 });
+//!
+//!     // Panics with "`ipsum` must be initialized".
+//!     let result = std::panic::catch_unwind(|| LoremBuilder::default().build_expect());
+//!     assert!(result.is_err());
+//! }
+//! ```
+//!
+//! ### Building into an Existing Value
+//!
+//! `#[builder(build_fn(into_target))]` additionally emits a `fn build_into(&self, target: &mut
+//! Foo)`, named after the build method, which writes into an existing struct rather than
+//! returning a new one. Each field set in the builder is cloned into `target` with
+//! `Clone::clone_from`, which can reuse its allocations when rebuilding in a loop. Unset fields
+//! keep their value in `target` instead of taking their default, while unset fields without a
+//! default still fail with an uninitialized field error. Validation and the checks for missing
+//! fields run first, so `target` is left unchanged on error.
+//!
+//! The fields must be stored as is, so this cannot be combined with `field(type = "...")`,
+//! `field(build = "...")`, computed or mapped fields, field assertions or `setter(lazy)`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(into_target))]
+//! struct Lorem {
+//!     ipsum: String,
+//!     #[builder(default)]
+//!     dolor: Vec<u32>,
+//! }
+//!
+//! fn main() {
+//!     let mut x = Lorem { ipsum: "ipsum".to_string(), dolor: vec![1, 2] };
+//!     LoremBuilder::default().ipsum("lorem".to_string()).build_into(&mut x).unwrap();
+//!     assert_eq!(x, Lorem { ipsum: "lorem".to_string(), dolor: vec![1, 2] });
+//!
+//!     assert!(LoremBuilder::default().dolor(vec![3]).build_into(&mut x).is_err());
+//! }
+//! ```
+//!
+//! ### Partial Build Method
+//!
+//! `#[builder(build_partial)]` adds a `try_build_partial()` method returning a `PartialFoo`
+//! struct, which holds every field of `Foo` as an `Option`: `Some` with the value set in the
+//! builder, and `None` if the field is unset, instead of its default. Required fields without
+//! a default must still be set, or the method returns the uninitialized field error of the
+//! build method. This comes in handy to build incrementally, report progress or validate part
+//! of a struct. Values are taken as set, before `field(map = "...")` and validation.
+//!
+//! Use `#[builder(build_partial(derive(...)))]` to derive traits on the partial struct. Fields
+//! with a `field(type = "...")` or `setter(lazy = "...")` are not supported.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug)]
+//! #[builder(build_partial(derive(Debug, PartialEq)))]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     #[builder(default = "42")]
+//!     dolor: u32,
+//! }
+//!
+//! fn main() {
+//!     let partial = LoremBuilder::default().ipsum(1).try_build_partial().unwrap();
+//!     assert_eq!(partial, PartialLorem { ipsum: Some(1), dolor: None });
+//!
+//!     assert!(LoremBuilder::default().dolor(1).try_build_partial().is_err());
+//! }
+//! ```
+//!
+//! ## Pre-Build Validation
+//!
+//! If you're using the provided `build` method, you can declare
+//! `#[builder(build_fn(validate = "path::to::fn"))]` to specify a validator function which gets
+//! access to the builder before construction. The path does not need to be fully-qualified, and
+//! will consider `use` statements made at module level. It must be accessible from the scope
+//! where the target struct is declared.
+//!
+//! The provided function must have the signature `(&FooBuilder) -> Result<_, String>`;
+//! the `Ok` variant is not used by the `build` method.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(validate = "Self::validate"))]
+//! struct Lorem {
+//!     pub ipsum: u8,
+//! }
+//!
+//! impl LoremBuilder {
+//!     /// Check that `Lorem` is putting in the right amount of effort.
+//!     fn validate(&self) -> Result<(), String> {
+//!         if let Some(ref ipsum) = self.ipsum {
+//!             match *ipsum {
+//!                 i if i < 20 => Err("Try harder".to_string()),
+//!                 i if i > 100 => Err("You'll tire yourself out".to_string()),
+//!                 _ => Ok(())
+//!             }
+//!         } else {
+//!             Ok(())
+//!         }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     // If we're trying too hard...
+//!     let x = LoremBuilder::default().ipsum(120).build().unwrap_err();
+//!
+//!     // .. the build will fail:
+//!     assert_eq!(&x.to_string(), "You'll tire yourself out");
+//! }
+//! ```
+//!
+//! Note:
+//! * Default values are applied _after_ validation, and will therefore not be validated!
+//!
+//! ### Field Assertions
+//!
+//! Conditions on a single field can be declared with
+//! `#[builder(field(assert = "...", error = "..."))]` instead. The condition reads the value
+//! of the field as `value`, after defaults and `field(map = "...")` are applied, and the
+//! `build` method returns the message as a validation error if it does not hold. A field can
+//! have several assertions, each followed by its message, which are checked in order.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(field(
+//!         assert = "value > 1024",
+//!         error = "port must be > 1024",
+//!         assert = "value % 2 == 0",
+//!         error = "port must be even"
+//!     ))]
+//!     port: u16,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().port(80).build().unwrap_err();
+//!     assert_eq!(&x.to_string(), "port must be > 1024");
+//!
+//!     let x = LoremBuilder::default().port(2049).build().unwrap_err();
+//!     assert_eq!(&x.to_string(), "port must be even");
+//! }
+//! ```
+//!
+//! Like a validator function, the assertions are not checked by an
+//! [unchecked](#unchecked-build-method) or [panicking](#panicking-build-method) build method,
+//! and cannot be used with
+//! `#[builder(field(default))]` at the struct level, whose build method cannot fail.
+//!
+//! ## Comparing Builders
+//!
+//! `#[builder(impl_diff)]` adds a `diff(&self, other: &Self)` method to the builder, returning
+//! a `FooBuilderDiff` struct with one `Option<bool>` per field: `None` if the field is the same
+//! in both builders, `Some(true)` if it is only set in one of them, and `Some(false)` if both
+//! values differ. `has_changes()` tells whether any field differs.
+//!
+//! Values are compared with `PartialEq`. Mark fields whose type does not implement it with
+//! `#[builder(diff_presence_only)]` to only compare whether they are set.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! #[builder(impl_diff)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: bool,
+//! }
+//!
+//! # fn main() {
+//! let mut a = LoremBuilder::default();
+//! a.ipsum(1);
+//! let mut b = LoremBuilder::default();
+//! b.ipsum(2).dolor(true);
+//!
+//! let diff = a.diff(&b);
+//! assert_eq!(diff.ipsum, Some(false));
+//! assert_eq!(diff.dolor, Some(true));
+//! assert!(diff.has_changes());
+//! # }
+//! ```
+//!
+//! ## Hashing Builders
+//!
+//! `#[builder(impl_hash)]` implements `Hash` for the builder, hashing the fields in declaration
+//! order. Unset fields hash as `None`. Every field type must implement `Hash`; otherwise the
+//! error points at the offending field. Combine it with `#[builder(derive(PartialEq, Eq))]` to
+//! use builders as keys in a `HashMap` or `HashSet`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! # use std::collections::HashSet;
+//! #[derive(Builder, Debug)]
+//! #[builder(impl_hash, derive(PartialEq, Eq))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! # fn main() {
+//! let mut seen = HashSet::new();
+//! seen.insert(LoremBuilder::default().ipsum(1).clone());
+//! assert!(seen.contains(LoremBuilder::default().ipsum(1)));
+//! # }
+//! ```
+//!
+//! ## Displaying Builders
+//!
+//! `#[builder(impl_display)]` implements `Display` for the builder, printing which fields are
+//! set, e.g. `LoremBuilder { ipsum: 42, dolor: unset }`. Set fields print their value if its
+//! type implements `Display`, and `set` otherwise; this includes fields of a generic type.
+//! Use `#[builder(impl_display(show_values = false))]` to only print `set` or `unset`, e.g. to
+//! keep sensitive values out of logs.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! #[builder(impl_display)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: Vec<u8>,
+//!     sit: String,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(42).dolor(vec![1]);
+//! assert_eq!(
+//!     builder.to_string(),
+//!     "LoremBuilder { ipsum: 42, dolor: set, sit: unset }"
+//! );
+//! # }
+//! ```
+//!
+//! ## Snapshots of Builders
+//!
+//! `#[builder(impl_snapshot)]` adds a `snapshot(&self)` method to the builder, returning a
+//! `FooBuilderSnapshot` struct with one `Option<String>` per field: `None` if the field is
+//! unset, and its value formatted with `Debug` otherwise. This gives a structured view of the
+//! builder state, e.g. to log why a build failed, without requiring more than `Debug` of the
+//! field types. The snapshot implements `Display`, printing a TOML-like summary.
+//!
+//! Mark fields holding credentials with `#[builder(field(sensitive))]`; they need not implement
+//! `Debug` and appear as `Some("<redacted>")` once set. Lazy fields appear as `Some("<set>")`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! #[builder(impl_snapshot)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: String,
+//!     #[builder(field(sensitive))]
+//!     password: String,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(42).password("hunter2".to_string());
+//!
+//! let snapshot = builder.snapshot();
+//! assert_eq!(snapshot.ipsum, Some("42".to_string()));
+//! assert_eq!(snapshot.dolor, None);
+//! assert_eq!(snapshot.password, Some("<redacted>".to_string()));
+//! assert_eq!(
+//!     snapshot.to_string(),
+//!     "[LoremBuilder]\nipsum = 42\n# dolor = unset\npassword = <redacted>"
+//! );
+//! # }
+//! ```
+//!
+//! ## Serializing Builders
+//!
+//! With the `serde` feature of `derive_builder` enabled, `#[builder(impl_serialize)]`
+//! implements `serde::Serialize` for the builder, e.g. to persist a builder which is still being
+//! filled in. Unset fields are left out, like with `#[serde(skip_serializing_if =
+//! "Option::is_none")]`, and fields marked `#[builder(field(sensitive))]` serialize as `null` once set.
+//!
+//! Every other field type must implement `Serialize`. Fields which do not, such as handles or
+//! timestamps, are left out with `#[builder(serialize_skip)]`. Lazy fields are always left out.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! # extern crate serde_json;
+//! # #[cfg(feature = "serde")]
+//! # mod example {
+//! # use serde_json;
+//! # use std::time::Instant;
+//! #[derive(Builder)]
+//! #[builder(impl_serialize)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: String,
+//!     #[builder(field(sensitive))]
+//!     password: String,
+//!     #[builder(serialize_skip)]
+//!     started: Instant,
+//! }
+//!
+//! # pub fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(42).password("hunter2".to_string());
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&builder).unwrap(),
+//!     r#"{"ipsum":42,"password":null}"#
+//! );
+//! # }
+//! # }
+//! # fn main() {
+//! #     #[cfg(feature = "serde")]
+//! #     example::main();
+//! # }
+//! ```
+//!
+//! ## Splitting Builders into Parts
+//!
+//! `#[builder(impl_parts)]` adds an `into_parts(self)` method to the builder, returning a
+//! `FooBuilderParts` tuple struct which holds the builder fields in declaration order, and a
+//! `from_parts` constructor turning such a struct back into a builder. This gives access to
+//! the raw builder state, e.g. to convert it into another type. Fields without a setter are
+//! not part of the tuple.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! #[builder(impl_parts)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: String,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(42);
+//!
+//! let LoremBuilderParts(ipsum, dolor) = builder.into_parts();
+//! assert_eq!(ipsum, Some(42));
+//! assert_eq!(dolor, None);
+//!
+//! let builder = LoremBuilder::from_parts(LoremBuilderParts(ipsum, Some("dolor".into())));
+//! assert!(builder.build().is_ok());
+//! # }
+//! ```
+//!
+//! ## Batch Updates
+//!
+//! `#[builder(impl_extend)]` emits a `FooField` enum with one variant per setter, named after
+//! the field in upper camel case and holding the value the setter converts its argument into.
+//! The builder implements `Extend<FooField>`, calling the setter of each value in turn, so it
+//! can be populated from dynamic data. Fields without a setter, or whose setter can fail
+//! (`setter(validate = "...")` or `setter(once)`), have no variant.
+//!
+//! With `#[builder(impl_extend(parse))]`, `FooField::parse_from_str(name, value)` parses the
+//! value of the field named `name` using `FromStr`, returning a [`FieldParseError`] for unknown
+//! names or invalid values. This requires the `std` feature.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(impl_extend(parse))]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor_sit: String,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.extend(vec![
+//!     LoremField::Ipsum(42),
+//!     LoremField::parse_from_str("dolor_sit", "dolor").unwrap(),
+//! ]);
+//!
+//! assert_eq!(
+//!     builder.build().unwrap(),
+//!     Lorem { ipsum: 42, dolor_sit: "dolor".into() }
+//! );
+//! assert!(LoremField::parse_from_str("ipsum", "many").is_err());
+//! # }
+//! ```
+//!
+//! `#[builder(patch)]` emits the same kind of enum, named `FooPatch`, together with an inherent
+//! `FooBuilder::apply` method taking any `IntoIterator` of patches, for builders which should
+//! not implement `Extend`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(patch)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor_sit: String,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.apply(vec![LoremPatch::Ipsum(42), LoremPatch::DolorSit("dolor".into())]);
+//!
+//! assert_eq!(
+//!     builder.build().unwrap(),
+//!     Lorem { ipsum: 42, dolor_sit: "dolor".into() }
+//! );
+//! # }
+//! ```
+//!
+//! ## Builders with a Base
+//!
+//! `#[builder(struct_update)]` adds a `with_base(self, base: Self) -> Self` method to the
+//! builder, the builder analog of struct update syntax `..base`: every field which is unset
+//! in the builder is moved from `base`. Fields set in the builder take precedence over those of
+//! `base`, so a shared base configuration can be reused while overriding some of its fields.
+//! No bounds are needed on the field types, as the values are moved. Fields which are not
+//! stored as an `Option`, such as those with a custom builder type or with
+//! `#[builder(field(default))]`, keep the value of the builder.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(struct_update, setter(into))]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u32,
+//! }
+//!
+//! # fn main() {
+//! let mut base = LoremBuilder::default();
+//! base.ipsum("ipsum").dolor(1u32);
+//!
+//! let mut builder = LoremBuilder::default();
+//! builder.dolor(2u32);
+//!
+//! assert_eq!(
+//!     builder.with_base(base).build().unwrap(),
+//!     Lorem { ipsum: "ipsum".into(), dolor: 2 }
+//! );
+//! # }
+//! ```
+//!
+//! ## Converting Structs into Builders
+//!
+//! `#[builder(into_builder)]` implements `From<Foo>` for `FooBuilder`, moving every field of the
+//! struct into the builder as if its setter had been called. This is useful to derive a
+//! modified copy of an existing value. Fields without a setter are reset to their default,
+//! and fields with a custom builder field type are converted with `Into`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(into_builder)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: String,
+//! }
 //!
-//! You can define default values for each field via annotation by `#[builder(default = "...")]`,
-//! where `...` stands for any Rust expression and must be string-escaped, e.g.
+//! # fn main() {
+//! let lorem = Lorem { ipsum: 42, dolor: "dolor".into() };
 //!
-//! * `#[builder(default = "42")]`
-//! * `#[builder(default)]` delegates to the [`Default`] trait of the base type.
+//! let mut builder = LoremBuilder::from(lorem);
+//! builder.ipsum(7);
+//! assert_eq!(builder.build().unwrap(), Lorem { ipsum: 7, dolor: "dolor".into() });
+//! # }
+//! ```
 //!
-//! The expression will be evaluated with each call to `build`.
+//! `#[builder(impl_builder_on_original)]` instead adds a `to_builder(&self)` method to the struct,
+//! which leaves it untouched and sets every field of the builder from a clone of it. The method
+//! is only available if all field types implement `Clone`.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
-//! #
 //! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(impl_builder_on_original)]
 //! struct Lorem {
-//!     #[builder(default = "42")]
-//!     pub ipsum: u32,
+//!     ipsum: u32,
+//!     dolor: String,
 //! }
 //!
-//! fn main() {
-//!     // If we don't set the field `ipsum`,
-//!     let x = LoremBuilder::default().build().unwrap();
+//! # fn main() {
+//! let lorem = Lorem { ipsum: 42, dolor: "dolor".into() };
 //!
-//!     // .. the custom default will be used for `ipsum`:
-//!     assert_eq!(x, Lorem {
-//!         ipsum: 42,
-//!     });
-//! }
+//! let mut builder = lorem.to_builder();
+//! builder.ipsum(7);
+//! assert_eq!(builder.build().unwrap(), Lorem { ipsum: 7, dolor: "dolor".into() });
+//! assert_eq!(lorem.ipsum, 42);
+//! # }
 //! ```
 //!
-//! ### Tips on Defaults
+//! ## Building Structs from Tuples
 //!
-//! * The `#[builder(default)]` annotation can be used on the struct level, too. Overrides are
-//!   still possible.
-//! * Delegate to a private helper method on `FooBuilder` for anything fancy. This way
-//!   you will get _much better error diagnostics_ from the rust compiler and it will be _much
-//!   more readable_ for other human beings. :-)
-//! * Defaults will not work while using `#[builder(build_fn(skip))]`. In this case, you'll
-//!   need to handle default values yourself when converting from the builder, such as by
-//!   using `.unwrap_or()` and `.unwrap_or_else()`.
+//! `#[builder(impl_from_parts)]` implements `From<(T1, T2, ...)>` for the struct, taking the
+//! fields without a default in declaration order. Each element goes through the field's setter
+//! and the struct is built with the build method, so validation still applies. The element
+//! types are the types the setters convert into, e.g. `String` for a `setter(into)` field of
+//! type `String`, or `u32` for a `setter(strip_option)` field of type `Option<u32>`.
 //!
-//! [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+//! Since `From` cannot fail, the conversion panics if building fails, which requires the error
+//! type to implement `Debug`. A struct whose fields all have defaults gets `From<()>`.
+//!
+//! At most twelve fields without a default are supported, and each of them needs a setter
+//! which cannot fail, so `setter(custom)`, `setter(skip)`, `setter(validate = "...")` and
+//! `setter(once)` are not allowed on them.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
-//! #
-//! # #[derive(Builder, PartialEq, Debug)]
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(impl_from_parts)]
 //! struct Lorem {
 //!     ipsum: String,
-//!     // Custom defaults can delegate to helper methods
-//!     // and pass errors to the enclosing `build()` method via `?`.
-//!     #[builder(default = "self.default_dolor()?")]
-//!     dolor: String,
+//!     dolor: u16,
+//!     #[builder(default)]
+//!     sit: bool,
 //! }
 //!
-//! impl LoremBuilder {
-//!     // Private helper method with access to the builder struct.
-//!     fn default_dolor(&self) -> Result<String, String> {
-//!         match self.ipsum {
-//!             Some(ref x) if x.chars().count() > 3 => Ok(format!("dolor {}", x)),
-//!             _ => Err("ipsum must at least 3 chars to build dolor".to_string()),
-//!         }
-//!     }
+//! # fn main() {
+//! let lorem = Lorem::from(("localhost".to_string(), 8080));
+//! assert_eq!(lorem, Lorem { ipsum: "localhost".into(), dolor: 8080, sit: false });
+//! # }
+//! ```
+//!
+//! ## Listing Field Names
+//!
+//! `#[builder(field_names)]` adds a `FIELD_NAMES` constant to the builder, listing the names of
+//! all fields of the struct in declaration order, e.g. to validate configuration keys or to
+//! generate command line options. Raw identifiers are listed without their `r#` prefix.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder)]
+//! #[builder(field_names)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     dolor: String,
 //! }
 //!
 //! # fn main() {
-//! #     let x = LoremBuilder::default()
-//! #         .ipsum("ipsum".to_string())
-//! #         .build()
-//! #         .unwrap();
-//! #
-//! #     assert_eq!(x, Lorem {
-//! #         ipsum: "ipsum".to_string(),
-//! #         dolor: "dolor ipsum".to_string(),
-//! #     });
+//! assert_eq!(LoremBuilder::FIELD_NAMES, ["ipsum", "dolor"]);
 //! # }
 //! ```
 //!
-//! You can even reference other fields, but you have to remember that the builder struct
-//! will wrap every type in an Option ([as illustrated earlier](#what-you-get)).
+//! ## Checking Whether Fields Are Set
 //!
-//! ## Generic Structs
+//! `#[builder(impl_has_methods)]` adds an `#[inline]` method per field to the builder, telling
+//! whether the field is set. It is named `has_ipsum` for a field `ipsum` with a default, and
+//! `has_required_ipsum` if building fails without it. Change the `has_` prefix with
+//! `#[builder(impl_has_methods(prefix = "is_set_"))]`. Fields stored with
+//! `#[builder(field(type = "..."))]` have no such method.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
-//! #
-//! #[derive(Builder, Debug, PartialEq, Default, Clone)]
-//! struct GenLorem<T: Clone> {
-//!     ipsum: &'static str,
-//!     dolor: T,
+//! #[derive(Builder)]
+//! #[builder(impl_has_methods)]
+//! struct Lorem {
+//!     ipsum: u32,
+//!     #[builder(default)]
+//!     dolor: String,
 //! }
 //!
-//! fn main() {
-//!     let x = GenLoremBuilder::default().ipsum("sit").dolor(42).build().unwrap();
-//!     assert_eq!(x, GenLorem { ipsum: "sit".into(), dolor: 42 });
-//! }
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(1);
+//! assert!(builder.has_required_ipsum());
+//! assert!(!builder.has_dolor());
+//! # }
 //! ```
 //!
-//! ## Build Method Customization
+//! ## Reading Fields from the Environment
 //!
-//! You can rename or suppress the auto-generated build method, leaving you free to implement
-//! your own version. Suppression is done using `#[builder(build_fn(skip))]` at the struct level,
-//! and renaming is done with `#[builder(build_fn(name = "YOUR_NAME"))]`.
+//! `#[builder(from_env)]` adds a `FooBuilder::from_env(prefix)` constructor which reads each
+//! field from the environment variable `{prefix}_{FIELD_NAME}`, with the field name in upper
+//! case. Values are parsed with `FromStr`, that of `T` for an `Option<T>` field; fields whose
+//! type does not implement it must be marked `#[builder(env_skip)]` and set through their
+//! setter instead.
 //!
-//! ## Pre-Build Validation
+//! Variables for fields with a default value may be absent. For all other fields, a missing
+//! variable makes `from_env` fail with an [`EnvVarError`] naming the variable, as does a
+//! value which cannot be parsed.
 //!
-//! If you're using the provided `build` method, you can declare
-//! `#[builder(build_fn(validate = "path::to::fn"))]` to specify a validator function which gets
-//! access to the builder before construction. The path does not need to be fully-qualified, and
-//! will consider `use` statements made at module level. It must be accessible from the scope
-//! where the target struct is declared.
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(from_env)]
+//! struct Lorem {
+//!     ipsum: u16,
+//!     #[builder(default)]
+//!     dolor: bool,
+//! }
 //!
-//! The provided function must have the signature `(&FooBuilder) -> Result<_, String>`;
-//! the `Ok` variant is not used by the `build` method.
+//! # fn main() {
+//! std::env::set_var("LOREM_IPSUM", "42");
+//! let x = LoremBuilder::from_env("LOREM").unwrap().build().unwrap();
+//! assert_eq!(x, Lorem { ipsum: 42, dolor: false });
+//! # }
+//! ```
+//!
+//! `from_env` is not available for `no_std` builders.
+//!
+//! `#[builder(impl_from_env_prefixed(env_prefix = "APP"))]` emits the same constructor with
+//! the prefix fixed: `FooBuilder::from_env()` reads `APP_{FIELD_NAME}`, and the variable names
+//! are string literals in the generated code. It returns an [`EnvError`], which lists every
+//! missing required variable at once in `EnvError::Missing`, and wraps the [`EnvVarError`] of
+//! a value which cannot be read or parsed in `EnvError::Invalid`. It cannot be combined with
+//! `from_env`.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
-//! #
+//! # use derive_builder::EnvError;
 //! #[derive(Builder, Debug, PartialEq)]
-//! #[builder(build_fn(validate = "Self::validate"))]
+//! #[builder(impl_from_env_prefixed(env_prefix = "APP"))]
 //! struct Lorem {
-//!     pub ipsum: u8,
+//!     ipsum: u16,
+//!     dolor: String,
 //! }
 //!
-//! impl LoremBuilder {
-//!     /// Check that `Lorem` is putting in the right amount of effort.
-//!     fn validate(&self) -> Result<(), String> {
-//!         if let Some(ref ipsum) = self.ipsum {
-//!             match *ipsum {
-//!                 i if i < 20 => Err("Try harder".to_string()),
-//!                 i if i > 100 => Err("You'll tire yourself out".to_string()),
-//!                 _ => Ok(())
-//!             }
-//!         } else {
-//!             Ok(())
-//!         }
-//!     }
+//! # fn main() {
+//! match LoremBuilder::from_env() {
+//!     Err(EnvError::Missing(vars)) => assert_eq!(vars, vec!["APP_IPSUM", "APP_DOLOR"]),
+//!     _ => unreachable!(),
 //! }
+//! # }
+//! ```
 //!
-//! fn main() {
-//!     // If we're trying too hard...
-//!     let x = LoremBuilder::default().ipsum(120).build().unwrap_err();
+//! ## Caching the Build Result
 //!
-//!     // .. the build will fail:
-//!     assert_eq!(&x.to_string(), "You'll tire yourself out");
+//! If building is expensive, `#[builder(lazy_build)]` makes the builder remember what it
+//! built. The build method then takes `&mut self` and returns `Result<Arc<Foo>, _>`: the
+//! first call builds a fresh value and caches it, later calls return a clone of the cached
+//! `Arc`. Calling any setter clears the cache, so the next build sees the new values.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! # use std::sync::Arc;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(lazy_build)]
+//! struct Lorem {
+//!     ipsum: u32,
 //! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.ipsum(1);
+//! let first = builder.build().unwrap();
+//! assert!(Arc::ptr_eq(&first, &builder.build().unwrap()));
+//!
+//! builder.ipsum(2);
+//! assert_eq!(builder.build().unwrap().ipsum, 2);
+//! # }
 //! ```
 //!
-//! Note:
-//! * Default values are applied _after_ validation, and will therefore not be validated!
+//! Only the generated setters clear the cache. A custom setter (`#[builder(setter(custom))]`)
+//! or code writing a public builder field (`#[builder(field(public))]`) must call the
+//! generated `clear_build_cache(&mut self)` method, or later builds keep returning the stale
+//! value.
+//!
+//! `lazy_build` cannot be combined with the owned pattern. Traits derived on the builder
+//! via `#[builder(derive(...))]` must also be implemented by `Arc<Foo>`.
+//!
+//! ## Unused Builders
+//!
+//! The generated builder struct and its build method are marked `#[must_use]`, so
+//! discarding a builder or the result of `build()` triggers the `unused_must_use` lint.
+//! Setters which return `Self` (the owned and immutable patterns) are covered by the
+//! attribute on the builder struct.
+//!
+//! You can opt out of this with `#[builder(no_must_use)]` on the struct.
+//!
+//! ## Builder Layout
+//!
+//! When the builder struct holds a single field, e.g. the `Option<Bar>` of a struct wrapping a
+//! `Bar`, it is marked `#[repr(transparent)]`, so it has the same layout as that field. The
+//! `PhantomData` of fields without setters does not count, while the cache of
+//! `#[builder(lazy_build)]` does. A `repr` passed with `#[builder_struct_attr(repr(...))]` takes
+//! precedence.
 //!
 //! ## Additional Trait Derivations
 //!
@@ -522,6 +2203,47 @@
 //!
 //! Attributes declared for those traits are _not_ forwarded to the fields on the builder.
 //!
+//! The builder stores each field of type `T` as an `Option<T>`, so deriving `PartialEq` or `Eq`
+//! requires `T: PartialEq` or `T: Eq` for every field that has a setter. Two builders then
+//! compare equal when the same fields are set to equal values; an unset field differs from any
+//! set one. This comes in handy to test that two builders are configured identically.
+//!
+//! ### Sensitive Fields
+//!
+//! Mark fields holding passwords or keys with `#[builder(field(sensitive))]` to keep their
+//! values out of logs. `#[builder(derive(Debug))]` then implements `Debug` by hand instead of
+//! deriving it, printing `Some("<sensitive>")` for a set field. The field also prints as
+//! `<sensitive>` with `#[builder(impl_display)]`, as `<redacted>` with
+//! `#[builder(impl_snapshot)]`, and as `null` with `#[builder(impl_serialize)]`. The field type need not implement `Debug`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Debug)]
+//! #[builder(derive(Debug), impl_display)]
+//! struct Lorem {
+//!     user: String,
+//!     #[builder(field(sensitive))]
+//!     password: String,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = LoremBuilder::default();
+//! builder.user("ipsum".to_string()).password("hunter2".to_string());
+//! assert_eq!(
+//!     format!("{:?}", builder),
+//!     r#"LoremBuilder { user: Some("ipsum"), password: Some("<sensitive>") }"#
+//! );
+//! assert_eq!(
+//!     builder.to_string(),
+//!     "LoremBuilder { user: ipsum, password: <sensitive> }"
+//! );
+//! # }
+//! ```
+//!
+//! A `Debug` derived with `#[builder_struct_attr(derive(Debug))]` is not masked, so it triggers
+//! a warning when the builder has sensitive fields.
+//!
 //! ## Documentation Comments and Attributes
 //!
 //! `#[derive(Builder)]` copies doc comments and attributes (`#[...]`) from your fields
@@ -554,6 +2276,14 @@
 //! # fn main() {}
 //! ```
 //!
+//! ### Setter Doc Templates
+//!
+//! Setters for undocumented fields can be documented from a template with
+//! `#[builder(setter(doc = "Sets the `{field}` field."))]` on the struct, where `{field}` is
+//! replaced with the field name. Setters which already have a doc comment keep it. Use
+//! `#[builder(setter(doc = "..."))]` on a field to override the template for that setter; it
+//! also replaces the field's own doc comment on the setter.
+//!
 //! ### Pass-through Attributes
 //!
 //! You can set attributes on elements of the builder using the `builder_*_attr` attributes:
@@ -582,6 +2312,49 @@
 //! # }
 //! ```
 //!
+//! Attributes for the builder struct can also be given inside `#[builder(...)]` using
+//! `struct_attr(...)`. Each comma-separated entry is emitted verbatim as `#[...]` on the builder
+//! struct, whatever tokens it holds (e.g. `doc = concat!(...)`), and repeated entries
+//! accumulate:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(struct_attr(repr(C)), struct_attr(repr(align(64))))]
+//! struct Lorem {
+//!     ipsum: u8,
+//! }
+//!
+//! # fn main() {
+//! assert_eq!(std::mem::align_of::<LoremBuilder>(), 64);
+//! # }
+//! ```
+//!
+//! ### Clippy Lints in Generated Code
+//!
+//! The generated items are marked `#[allow(...)]` for a few style lints which the generated
+//! code may trip depending on the options and field types, such as `clippy::too_many_arguments`
+//! or `clippy::useless_conversion`, so crates denying `clippy::all` still pass `cargo clippy`.
+//! Replace the list with `#[builder(allow_lints(...))]`, or leave it empty with
+//! `#[builder(allow_lints())]`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(allow_lints(clippy::needless_update))]
+//! struct Lorem {
+//!     ipsum: u8,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! `#[derive(EnumBuilder)]` accepts `#[builder(allow_lints(...))]` on the enum as well. With the
+//! `clippy` feature enabled, no lints are allowed at all, to lint the generated code itself.
+//!
 //! # Error return type from autogenerated `build` function
 //!
 //! By default, `build` returns an autogenerated error type:
@@ -597,6 +2370,8 @@
 //! pub enum LoremBuilderError { // where `LoremBuilder` is the name of the builder struct
 //!     /// Uninitialized field
 //!     UninitializedField(&'static str),
+//!     /// Uninitialized fields, all reported at once
+//!     UninitializedFields(Vec<&'static str>),
 //!     /// Custom validation error
 //!     ValidationError(String),
 //! }
@@ -611,6 +2386,11 @@
 //! impl std::error::Error for LoremBuilderError {}
 //! ```
 //!
+//! When several required fields are unset, `build` returns a single `UninitializedFields` error
+//! listing all of them. Use `#[builder(build_fn(error(first_only)))]` to return an
+//! `UninitializedField` error for the first one instead. `no_std(static_str_errors)` builders
+//! and builders with a custom error type always report only the first field.
+//!
 //! Alternatively, you can specify your own error type:
 //! ```rust
 //! # #[macro_use]
@@ -631,7 +2411,7 @@
 //!
 //! # fn main() {
 //! let err: OurLoremError = LoremBuilder::default().build().unwrap_err();
-//! assert_eq!(&err.0, "Field not initialized: ipsum");
+//! assert_eq!(&err.0, "`ipsum` must be initialized");
 //! # }
 //! ```
 //!
@@ -675,21 +2455,135 @@
 //! The argument to `build` must be a literal string containing Rust code for the contents of a block, which must evaluate to the type of the target field.
 //! It may refer to the builder struct as `self`, use `?`, etc.
 //!
-//! # **`#![no_std]`** Support (on Nightly)
+//! # Inheriting Setters
+//!
+//! `#[builder(inherits = "BaseBuilder")]` stores the field of type `Base` as a `BaseBuilder`
+//! and forwards every setter of `BaseBuilder` to it, so the outer builder exposes them
+//! directly. `Base` must opt in with `#[builder(inheritable)]`. Building the outer struct builds the inner one, and an error from it is reported
+//! as a validation error with its message.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Builder, Clone, Debug, PartialEq)]
+//! #[builder(inheritable)]
+//! struct Base {
+//!     #[builder(setter(into))]
+//!     host: String,
+//!     #[builder(default = "80")]
+//!     port: u16,
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(inherits = "BaseBuilder")]
+//! struct Server {
+//!     base: Base,
+//!     workers: u32,
+//! }
+//!
+//! # fn main() {
+//! let server = ServerBuilder::default().host("localhost").workers(4).build().unwrap();
+//! assert_eq!(server.base, Base { host: "localhost".into(), port: 80 });
+//! # }
+//! ```
+//!
+//! An inheritable builder implements [`BuilderMethods`], which is used to check that the
+//! inherited builder builds the field's type. The setters are forwarded through a macro which
+//! the inheritable builder's derive emits, so:
+//!
+//! - The inherited struct must be declared before the inheriting one in the same module, or
+//!   in a module declared earlier with `#[macro_use]`.
+//! - The inherited struct must not have type parameters.
+//! - Setters returning something other than the builder, such as `setter(once)` or
+//!   `setter(return_previous)` setters, and `each` and `try_` setters are not forwarded.
+//! - Forwarded setters must not have the same name as a setter of the inheriting builder.
+//! - The inheriting builder cannot use `lazy_build`, and its error type must be convertible
+//!   from `String`.
+//! - A builder whose build method borrows the builder cannot inherit one with the owned
+//!   pattern, whose build method consumes it.
+//!
+//! A builder cannot inherit from itself. Longer cycles, where `ABuilder` inherits `BBuilder`
+//! which inherits `ABuilder`, are not supported: the derive only sees one struct at a time and
+//! cannot detect them, so they fail to compile with an error about a recursive type rather
+//! than a message from `derive_builder`.
+//!
+//! # **`#![no_std]`** Support
+//!
+//! You can activate support for `#![no_std]` by adding `#[builder(no_std)]` to your struct,
+//! `extern crate alloc` to your crate and depending on `derive_builder` with
+//! `default-features = false`.
+//!
+//! The generated code then only refers to `core` and `alloc`, and the generated error type does
+//! not implement `std::error::Error`. Validation errors carry a `String` message, as in the
+//! `std` case.
+//!
+//! To keep allocations out of the generated code, use `#[builder(no_std(static_str_errors))]`
+//! instead. Validation errors then carry a `&'static str` message, so validators must return
+//! `Result<_, &'static str>`, and `build` reports only the first uninitialized field.
+//! `lazy_build`, `impl_snapshot` and `setter(lazy = "...")` are not available in this mode.
+//!
+//! # Enum Builders
+//!
+//! `#[derive(Builder)]` only supports structs. For an enum with unit variants,
+//! `#[derive(EnumBuilder)]` generates a `FooBuilder` with one `choose_*` method per variant,
+//! named after the variant in snake case. Choosing a variant replaces any variant chosen
+//! before, and `build()` returns the chosen variant, or an [`UnchosenError`] if none was
+//! chosen.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #[derive(Debug, PartialEq, EnumBuilder)]
+//! enum Color {
+//!     Red,
+//!     DarkGreen,
+//! }
+//!
+//! # fn main() {
+//! let mut builder = ColorBuilder::default();
+//! assert!(builder.build().is_err());
 //!
-//! You can activate support for `#![no_std]` by adding `#[builder(no_std)]` to your struct
-//! and `#![feature(alloc)] extern crate alloc` to your crate.
+//! builder.choose_red().choose_dark_green();
+//! assert_eq!(builder.build().unwrap(), Color::DarkGreen);
+//! # }
+//! ```
 //!
-//! The latter requires the _nightly_ toolchain.
+//! Variants with fields are not supported yet. Setting their fields through the builder, with
+//! a setter per field of the chosen variant, is a possible extension.
 //!
 //! # Troubleshooting
 //!
+//! ## Inspecting the Generated Code
+//!
+//! `#[builder(code_gen_debug = "builder_expansion.rs")]` writes the code generated for the
+//! struct to the given file, relative to the directory of the crate's `Cargo.toml`, headed by
+//! a comment naming the struct and the time of the expansion. The file is only written if the
+//! `DERIVE_BUILDER_DEBUG` environment variable is set when the crate is built, e.g. by
+//! `DERIVE_BUILDER_DEBUG=1 cargo build`, so the attribute does not touch the filesystem
+//! otherwise.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(code_gen_debug = "builder_expansion.rs")]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! Note:
+//! * The file is overwritten on each expansion, so structs should not share a file.
+//! * Cargo does not rebuild a crate when only the environment variable changes; touch a source
+//!   file to expand the macro again.
+//!
 //! ## Gotchas
 //!
 //! - Tuple structs and unit structs are not supported as they have no field
 //!   names.
-//! - Generic setters introduce a type parameter `VALUE: Into<_>`. Therefore you can't use
-//!  `VALUE` as a type parameter on a generic struct in combination with generic setters.
 //! - The `try_setter` attribute and `owned` builder pattern are not compatible in practice;
 //!   an error during building will consume the builder, making it impossible to continue
 //!   construction.
@@ -698,6 +2592,12 @@
 //! - If derive_builder depends on your crate, and vice versa, then a cyclic
 //!   dependency would occur. To break it you could try to depend on the
 //!   [`derive_builder_core`] crate instead.
+//! - Fields whose type is a type alias, e.g. `type Meters = f64`, work like any other field, as
+//!   the type is copied into the builder as written. Options which look at the shape of the
+//!   type, such as `setter(strip_option)`, `setter(option_setter)`, `setter(into_iter)` or
+//!   `setter(boxed)`, cannot see through the alias though, and need the concrete type, e.g.
+//!   `Option<u16>` rather than `type Port = Option<u16>`. `setter(strip_option)` on an alias
+//!   leaves the setter taking the alias itself.
 //!
 //! ## Report Issues and Ideas
 //!
@@ -719,19 +2619,53 @@ extern crate derive_builder_macro;
 
 mod error;
 
-pub use derive_builder_macro::Builder;
+pub use derive_builder_macro::{Builder, EnumBuilder, Newtype};
+
+#[doc(inline)]
+pub use error::{AlreadySetError, OverflowError, UnchosenError, UninitializedFieldError};
 
+#[cfg(feature = "std")]
 #[doc(inline)]
-pub use error::UninitializedFieldError;
+pub use error::{EnvError, EnvVarError, FieldParseError};
+
+/// Implemented by every builder derived with `#[builder(inheritable)]`.
+///
+/// Builders declared with `#[builder(inherits = "...")]` use it to check that the inherited
+/// builder builds the type of the field holding it.
+pub trait BuilderMethods {
+    /// The struct built by this builder.
+    type Target;
+}
+
+/// Implemented by tuple structs with a single field through `#[derive(Newtype)]`.
+///
+/// `#[builder(setter(newtype_inner))]` uses it to find the inner type of a field's newtype and
+/// to wrap the setter's value in it.
+pub trait Newtype {
+    /// The type of the single field.
+    type Inner;
+
+    /// Wrap `inner` in the newtype.
+    fn from_inner(inner: Self::Inner) -> Self;
+}
 
 #[doc(hidden)]
 pub mod export {
     pub mod core {
+        #[cfg(not(feature = "std"))]
+        pub use alloc::boxed;
         #[cfg(not(feature = "std"))]
         pub use alloc::string;
         #[cfg(not(feature = "std"))]
+        pub use alloc::sync;
+        #[cfg(not(feature = "std"))]
+        pub use alloc::vec;
+        #[cfg(not(feature = "std"))]
         pub use core::*;
         #[cfg(feature = "std")]
         pub use std::*;
     }
+
+    #[cfg(feature = "serde")]
+    pub extern crate serde;
 }
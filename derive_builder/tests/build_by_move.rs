@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Deliberately does not implement `Clone`.
+#[derive(Debug, PartialEq)]
+struct Handle(u8);
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Lorem {
+    ipsum: Handle,
+    #[builder(setter(strip_option), default)]
+    dolor: Option<Handle>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Ipsum {
+    /// The field-level pattern of the setter does not change how `build(&self)` reads the field.
+    #[builder(pattern = "owned")]
+    sit: String,
+}
+
+#[test]
+fn owned_builder_moves_fields() {
+    let x = LoremBuilder::default()
+        .ipsum(Handle(1))
+        .dolor(Handle(2))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: Handle(1),
+            dolor: Some(Handle(2)),
+        }
+    );
+}
+
+#[test]
+fn borrowing_build_method_clones_owned_pattern_fields() {
+    let builder = IpsumBuilder::default().sit("sit".to_string());
+
+    assert_eq!(builder.build().unwrap().sit, "sit");
+    assert_eq!(builder.build().unwrap().sit, "sit");
+}
@@ -13,6 +13,27 @@ pub struct Ipsum {
     percentile: u8,
 }
 
+mod dolor {
+    #[derive(Debug, Builder, PartialEq)]
+    #[builder(build_fn(skip), field(vis = "pub(crate)"))]
+    pub struct Dolor {
+        pub sit: u8,
+        pub amet: String,
+    }
+}
+
+use dolor::{Dolor, DolorBuilder};
+
+/// Reads the builder fields from outside the module declaring them.
+impl DolorBuilder {
+    pub fn build(&self) -> Result<Dolor, String> {
+        Ok(Dolor {
+            sit: self.sit.unwrap_or(1),
+            amet: self.amet.clone().ok_or("amet was not initialized")?,
+        })
+    }
+}
+
 impl Lorem {
     pub fn new(pct: u8) -> Result<Self, String> {
         if pct <= 100 {
@@ -64,3 +85,20 @@ fn rename() {
     let ipsum = IpsumBuilder::default().percentile(110).finish().unwrap();
     assert_eq!(Ipsum { percentile: 110 }, ipsum);
 }
+
+#[test]
+fn custom_build_reads_crate_visible_fields() {
+    let mut builder = DolorBuilder::default();
+    builder.amet("amet".to_string());
+    assert_eq!(builder.amet, Some("amet".to_string()));
+    assert_eq!(builder.sit, None);
+
+    let dolor = builder.build().unwrap();
+    assert_eq!(
+        dolor,
+        Dolor {
+            sit: 1,
+            amet: "amet".to_string(),
+        }
+    );
+}
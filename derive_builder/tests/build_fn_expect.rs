@@ -0,0 +1,80 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(expect, validate = "Self::validate"))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default = "\"dolor\".to_string()")]
+    dolor: String,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.ipsum {
+            Some(0) => Err("ipsum must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", build_fn(name = "finish", unchecked, expect))]
+struct Owned {
+    ipsum: String,
+}
+
+#[test]
+fn builds_set_fields() {
+    let x = LoremBuilder::default().ipsum(1).build_expect();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "`ipsum` must be initialized")]
+fn panics_on_missing_field() {
+    let _ = LoremBuilder::default()
+        .dolor("dolor".to_string())
+        .build_expect();
+}
+
+#[test]
+fn skips_validation() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(0);
+
+    assert!(builder.build().is_err());
+    assert_eq!(builder.build_expect().ipsum, 0);
+}
+
+#[test]
+fn renamed_owned_alongside_unchecked() {
+    let x = OwnedBuilder::default()
+        .ipsum("ipsum".to_string())
+        .finish_expect();
+
+    assert_eq!(
+        x,
+        Owned {
+            ipsum: "ipsum".to_string()
+        }
+    );
+
+    // SAFETY: `ipsum` is set.
+    let y = unsafe {
+        OwnedBuilder::default()
+            .ipsum("ipsum".to_string())
+            .finish_unchecked()
+    };
+
+    assert_eq!(x, y);
+}
@@ -0,0 +1,80 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(into = "Arc<Lorem>"))]
+struct Lorem {
+    ipsum: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Wrapper<T>(T);
+
+impl<T> From<Ipsum<T>> for Wrapper<Ipsum<T>> {
+    fn from(value: Ipsum<T>) -> Self {
+        Wrapper(value)
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", build_fn(into = "Wrapper<Ipsum<T>>"))]
+struct Ipsum<T> {
+    dolor: T,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(into))]
+struct Dolor {
+    #[builder(default)]
+    sit: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(field(default), build_fn(into = "Box<Sit>"))]
+struct Sit {
+    amet: u8,
+}
+
+#[test]
+fn explicit_type() {
+    let x = LoremBuilder::default().ipsum(1).build().unwrap();
+
+    assert_eq!(x, Arc::new(Lorem { ipsum: 1 }));
+}
+
+#[test]
+fn explicit_generic_type() {
+    let x = IpsumBuilder::default().dolor("dolor").build().unwrap();
+
+    assert_eq!(x, Wrapper(Ipsum { dolor: "dolor" }));
+}
+
+#[test]
+fn inferred_type() {
+    let builder = DolorBuilder::default();
+    let x: Rc<Dolor> = builder.build().unwrap();
+    let y: Box<Dolor> = builder.build().unwrap();
+    let z = builder.build::<Dolor>().unwrap();
+
+    assert_eq!(*x, z);
+    assert_eq!(*y, z);
+}
+
+#[test]
+fn infallible_build_method() {
+    let x = SitBuilder::default().amet(1).build();
+
+    assert_eq!(x, Box::new(Sit { amet: 1 }));
+}
+
+#[test]
+fn errors_are_unchanged() {
+    let error = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(&error.to_string(), "`ipsum` must be initialized");
+}
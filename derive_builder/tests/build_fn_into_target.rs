@@ -0,0 +1,133 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Clone, Builder)]
+#[builder(build_fn(into_target, validate = "LoremBuilder::validate"))]
+struct Lorem {
+    ipsum: String,
+    #[builder(default)]
+    dolor: Vec<u32>,
+    #[builder(setter(into, strip_option), default)]
+    sit: Option<String>,
+    #[builder(setter(skip))]
+    amet: bool,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.ipsum {
+            Some(ref ipsum) if ipsum.is_empty() => Err("ipsum is empty".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(
+    pattern = "owned",
+    name = "IpsumFactory",
+    build_fn(name = "finish", into_target, error(first_only))
+)]
+struct Ipsum<T: Clone> {
+    value: T,
+    other: T,
+}
+
+fn target() -> Lorem {
+    Lorem {
+        ipsum: "ipsum".to_string(),
+        dolor: vec![1, 2, 3],
+        sit: Some("sit".to_string()),
+        amet: true,
+    }
+}
+
+#[test]
+fn only_set_fields_change() {
+    let mut x = target();
+    LoremBuilder::default()
+        .ipsum("lorem".to_string())
+        .build_into(&mut x)
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "lorem".to_string(),
+            ..target()
+        }
+    );
+
+    LoremBuilder::default()
+        .ipsum("lorem".to_string())
+        .dolor(vec![4])
+        .sit("amet")
+        .build_into(&mut x)
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "lorem".to_string(),
+            dolor: vec![4],
+            sit: Some("amet".to_string()),
+            amet: true,
+        }
+    );
+}
+
+#[test]
+fn reuses_the_builder() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum("lorem".to_string());
+    let mut x = Lorem::default();
+
+    for i in 0..3 {
+        builder.dolor(vec![i]);
+        builder.build_into(&mut x).unwrap();
+        assert_eq!(x.dolor, vec![i]);
+    }
+    assert_eq!(builder.build().unwrap(), x);
+}
+
+#[test]
+fn missing_fields_still_error() {
+    let mut x = target();
+    match LoremBuilder::default().dolor(vec![]).build_into(&mut x) {
+        Err(LoremBuilderError::UninitializedFields(ref fields)) => assert_eq!(fields, &["ipsum"]),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(x, target());
+}
+
+#[test]
+fn validation_errors_leave_the_target_unchanged() {
+    let mut x = target();
+    let error = LoremBuilder::default()
+        .ipsum(String::new())
+        .dolor(vec![])
+        .build_into(&mut x)
+        .unwrap_err();
+
+    assert_eq!(&error.to_string(), "ipsum is empty");
+    assert_eq!(x, target());
+}
+
+#[test]
+fn generic_owned_builder() {
+    let mut x = Ipsum { value: 1, other: 2 };
+    let builder = IpsumFactory::default().value(3);
+    assert_eq!(
+        &builder.finish_into(&mut x).unwrap_err().to_string(),
+        "`other` must be initialized"
+    );
+
+    IpsumFactory::default()
+        .value(3)
+        .other(4)
+        .finish_into(&mut x)
+        .unwrap();
+    assert_eq!(x, Ipsum { value: 3, other: 4 });
+}
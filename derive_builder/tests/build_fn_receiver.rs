@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(receiver = "self"))]
+struct Consumed {
+    ipsum: String,
+    #[builder(default)]
+    dolor: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", build_fn(receiver = "ref"))]
+struct Borrowed {
+    ipsum: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "immutable", build_fn(receiver = "ref_mut"))]
+struct MutablyBorrowed {
+    ipsum: String,
+}
+
+#[test]
+fn consuming_build_with_mutable_setters() {
+    let mut builder = ConsumedBuilder::default();
+    builder.ipsum("ipsum".to_string()).dolor(vec![1, 2]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Consumed {
+            ipsum: "ipsum".to_string(),
+            dolor: vec![1, 2],
+        }
+    );
+}
+
+#[test]
+fn consuming_build_reports_missing_fields() {
+    assert_eq!(
+        ConsumedBuilder::default().build().unwrap_err().to_string(),
+        "`ipsum` must be initialized"
+    );
+}
+
+#[test]
+fn borrowing_build_with_owned_setters() {
+    let builder = BorrowedBuilder::default().ipsum("ipsum".to_string());
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Borrowed {
+            ipsum: "ipsum".to_string()
+        }
+    );
+    assert_eq!(builder.build().unwrap(), builder.build().unwrap());
+}
+
+#[test]
+fn mutably_borrowing_build() {
+    let mut builder = MutablyBorrowedBuilder::default().ipsum("ipsum".to_string());
+
+    assert_eq!(
+        builder.build().unwrap(),
+        MutablyBorrowed {
+            ipsum: "ipsum".to_string()
+        }
+    );
+}
@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    Build(String),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::Build(message) => write!(f, "build failed: {}", message),
+        }
+    }
+}
+
+impl<E: std::error::Error> From<E> for AppError {
+    fn from(error: E) -> Self {
+        AppError::Build(error.to_string())
+    }
+}
+
+type AppResult<T> = Result<T, AppError>;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(result_type = "AppResult<Lorem>", validate = "Self::validate"))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default)]
+    dolor: String,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.ipsum {
+            Some(0) => Err("ipsum must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(into = "Box<Ipsum<T>>", result_type = "AppResult<Box<Ipsum<T>>>")
+)]
+struct Ipsum<T> {
+    sit: T,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(lazy_build, build_fn(result_type = "Result<Arc<Sit>, AppError>"))]
+struct Sit {
+    amet: u8,
+}
+
+#[test]
+fn returns_custom_result() {
+    let x: AppResult<Lorem> = LoremBuilder::default().ipsum(1).build();
+
+    assert_eq!(
+        x,
+        Ok(Lorem {
+            ipsum: 1,
+            dolor: String::new(),
+        })
+    );
+}
+
+#[test]
+fn converts_errors() {
+    assert_eq!(
+        LoremBuilder::default().build().unwrap_err(),
+        AppError::Build("`ipsum` must be initialized".to_string())
+    );
+    assert_eq!(
+        LoremBuilder::default().ipsum(0).build().unwrap_err(),
+        AppError::Build("ipsum must not be zero".to_string())
+    );
+}
+
+#[test]
+fn owned_builder_with_into() {
+    let x = IpsumBuilder::default().sit("sit").build().unwrap();
+
+    assert_eq!(x, Box::new(Ipsum { sit: "sit" }));
+    assert_eq!(
+        &IpsumBuilder::<u8>::default()
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "build failed: `sit` must be initialized"
+    );
+}
+
+#[test]
+fn lazy_build() {
+    let mut builder = SitBuilder::default();
+    assert!(builder.build().is_err());
+
+    builder.amet(1);
+    let x = builder.build().unwrap();
+    let y = builder.build().unwrap();
+
+    assert!(Arc::ptr_eq(&x, &y));
+    assert_eq!(*x, Sit { amet: 1 });
+}
@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(unchecked, validate = "Self::validate"))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default = "\"dolor\".to_string()")]
+    dolor: String,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.ipsum {
+            Some(0) => Err("ipsum must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", build_fn(name = "finish", unchecked))]
+struct Owned {
+    ipsum: String,
+}
+
+#[test]
+fn builds_set_fields() {
+    let x = unsafe { LoremBuilder::default().ipsum(1).build_unchecked() };
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+        }
+    );
+}
+
+#[test]
+fn skips_validation() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(0);
+
+    assert!(builder.build().is_err());
+    assert_eq!(unsafe { builder.build_unchecked() }.ipsum, 0);
+}
+
+#[test]
+fn renamed_owned() {
+    let x = unsafe {
+        OwnedBuilder::default()
+            .ipsum("ipsum".to_string())
+            .finish_unchecked()
+    };
+
+    assert_eq!(
+        x,
+        Owned {
+            ipsum: "ipsum".to_string()
+        }
+    );
+}
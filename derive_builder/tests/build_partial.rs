@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_partial(derive(Debug, PartialEq)))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default = "42")]
+    dolor: u32,
+    #[builder(setter(into, strip_option), default)]
+    sit: Option<String>,
+    #[builder(setter(skip))]
+    amet: bool,
+}
+
+#[derive(Debug, PartialEq, Default, Builder)]
+#[builder(pattern = "owned", default, build_partial, build_fn(error(first_only)))]
+struct Ipsum<T: Clone + Default> {
+    value: T,
+    #[builder(required)]
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_partial(derive(Debug, PartialEq)))]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn unset_fields_are_none() {
+    let partial = LoremBuilder::default()
+        .ipsum(1)
+        .try_build_partial()
+        .unwrap();
+
+    assert_eq!(
+        partial,
+        PartialLorem {
+            ipsum: Some(1),
+            dolor: None,
+            sit: None,
+            amet: None,
+        }
+    );
+}
+
+#[test]
+fn set_fields_are_some() {
+    let partial = LoremBuilder::default()
+        .ipsum(1)
+        .dolor(2)
+        .sit("sit")
+        .try_build_partial()
+        .unwrap();
+
+    assert_eq!(partial.dolor, Some(2));
+    assert_eq!(partial.sit, Some(Some("sit".to_string())));
+}
+
+#[test]
+fn required_fields_must_be_set() {
+    match LoremBuilder::default().dolor(2).try_build_partial() {
+        Err(LoremBuilderError::UninitializedFields(ref fields)) => assert_eq!(fields, &["ipsum"]),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn generic_owned_builder() {
+    match IpsumBuilder::<u8>::default().value(7).try_build_partial() {
+        Err(error) => assert_eq!(&error.to_string(), "`name` must be initialized"),
+        Ok(_) => panic!("`name` is required"),
+    }
+
+    let partial = IpsumBuilder::<u8>::default()
+        .name("ipsum".to_string())
+        .try_build_partial()
+        .unwrap();
+    assert_eq!(partial.value, None);
+    assert_eq!(partial.name, Some("ipsum".to_string()));
+}
+
+#[test]
+fn generic() {
+    let partial = GenericBuilder::default()
+        .value("value".to_string())
+        .try_build_partial()
+        .unwrap();
+
+    assert_eq!(
+        partial,
+        PartialGeneric {
+            value: Some("value".to_string()),
+        }
+    );
+}
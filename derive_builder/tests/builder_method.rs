@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+pub struct Lorem {
+    ipsum: String,
+    #[builder(default)]
+    dolor: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(builder_method = "new_builder")]
+struct Renamed<T> {
+    ipsum: T,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Owned {
+    ipsum: String,
+}
+
+/// Defines its own `builder` method, so the derive must not emit one.
+#[derive(Debug, PartialEq, Builder)]
+#[builder(builder_method = false)]
+struct Custom {
+    ipsum: u32,
+}
+
+impl Custom {
+    fn builder() -> CustomBuilder {
+        let mut builder = CustomBuilder::default();
+        builder.ipsum(42);
+        builder
+    }
+}
+
+#[test]
+fn builder_method() {
+    let x = Lorem::builder().ipsum("ipsum".to_string()).build().unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: 0,
+        }
+    );
+}
+
+#[test]
+fn renamed_generic_builder_method() {
+    let x = Renamed::new_builder().ipsum(42).build().unwrap();
+
+    assert_eq!(x, Renamed { ipsum: 42 });
+}
+
+#[test]
+fn owned_builder_method() {
+    let x = Owned::builder().ipsum("ipsum".to_string()).build().unwrap();
+
+    assert_eq!(
+        x,
+        Owned {
+            ipsum: "ipsum".to_string()
+        }
+    );
+}
+
+#[test]
+fn own_builder_method() {
+    assert_eq!(Custom::builder().build().unwrap(), Custom { ipsum: 42 });
+    assert_eq!(CustomBuilder::default().ipsum(1).build().unwrap().ipsum, 1);
+}
@@ -15,7 +15,10 @@ struct Lorem {
 #[test]
 fn error_if_uninitialized() {
     let error = MyBuilder::default().build().unwrap_err();
-    assert_eq!(&error.to_string(), "`ipsum` must be initialized");
+    assert_eq!(
+        &error.to_string(),
+        "`ipsum`, `dolor`, `sit`, `amet` must be initialized"
+    );
 }
 
 #[test]
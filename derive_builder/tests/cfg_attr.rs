@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[cfg_attr(feature = "std", builder(derive(Debug)))]
+struct Lorem {
+    #[cfg_attr(feature = "std", builder(default = "1"))]
+    ipsum: u32,
+    #[cfg_attr(not(feature = "std"), builder(default = "2"))]
+    dolor: u32,
+    #[cfg_attr(feature = "std", builder(setter(into)), builder(default))]
+    sit: String,
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn active_cfg_applies_the_attribute() {
+    let x = LoremBuilder::default().dolor(3).build().unwrap();
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: 3,
+            sit: String::new(),
+        }
+    );
+
+    assert_eq!(
+        format!("{:?}", LoremBuilder::default().sit("sit")),
+        "LoremBuilder { ipsum: None, dolor: None, sit: Some(\"sit\") }"
+    );
+}
+
+// Run with `cargo test --no-default-features --features alloc`.
+#[test]
+#[cfg(not(feature = "std"))]
+fn inactive_cfg_ignores_the_attribute() {
+    let error = LoremBuilder::default()
+        .sit(String::new())
+        .build()
+        .unwrap_err();
+    assert_eq!(&error.to_string(), "`ipsum` must be initialized");
+
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .sit(String::new())
+        .build()
+        .unwrap();
+    assert_eq!(x.dolor, 2);
+}
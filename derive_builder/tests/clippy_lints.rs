@@ -0,0 +1,107 @@
+#![deny(clippy::all)]
+
+#[macro_use]
+extern crate derive_builder;
+
+use derive_builder::EnumBuilder;
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(
+    impl_diff,
+    impl_hash,
+    impl_parts,
+    impl_display,
+    impl_extend,
+    patch,
+    into_builder,
+    impl_builder_on_original,
+    impl_from_parts,
+    impl_snapshot,
+    impl_has_methods,
+    field_names,
+    try_setter
+)]
+struct Lorem {
+    ipsum: String,
+    #[builder(default)]
+    dolor: Option<u32>,
+    #[builder(setter(into), default)]
+    sit: Vec<u8>,
+    #[builder(setter(each = "tag"), default)]
+    tags: Vec<String>,
+    #[builder(field(computed = "self.tags.len()"))]
+    tag_count: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Builder)]
+#[builder(pattern = "owned", build_fn(validate = "Self::validate"))]
+struct Amet {
+    consectetur: u8,
+    #[builder(setter(strip_option), default)]
+    adipiscing: Option<u8>,
+}
+
+impl AmetBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.consectetur {
+            Some(0) => Err("consectetur must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(allow_lints(clippy::needless_update), build_fn(error = "ElitError"))]
+struct Elit<'a, T: Clone + Default> {
+    sed: &'a str,
+    #[builder(default)]
+    eiusmod: T,
+}
+
+#[derive(Debug)]
+struct ElitError(&'static str);
+
+impl From<derive_builder::UninitializedFieldError> for ElitError {
+    fn from(error: derive_builder::UninitializedFieldError) -> Self {
+        ElitError(error.field_name())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, EnumBuilder)]
+enum Tempor {
+    Incididunt,
+    Labore,
+}
+
+#[test]
+fn generated_code_passes_clippy() {
+    let lorem = LoremBuilder::default()
+        .ipsum("ipsum".to_string())
+        .sit(vec![1])
+        .tag("a".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(LoremBuilder::from(lorem.clone()).build().unwrap(), lorem);
+
+    let amet = AmetBuilder::default()
+        .consectetur(1)
+        .adipiscing(2)
+        .build()
+        .unwrap();
+    assert_eq!(amet.adipiscing, Some(2));
+    assert!(AmetBuilder::default().consectetur(0).build().is_err());
+
+    let elit: Elit<u8> = ElitBuilder::default().sed("sed").build().unwrap();
+    assert_eq!(
+        elit,
+        Elit {
+            sed: "sed",
+            eiusmod: 0
+        }
+    );
+    assert_eq!(ElitBuilder::<u8>::default().build().unwrap_err().0, "sed");
+
+    let mut tempor = TemporBuilder::default();
+    tempor.choose_labore();
+    assert_eq!(tempor.build().unwrap(), Tempor::Labore);
+}
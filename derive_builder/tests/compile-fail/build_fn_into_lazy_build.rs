@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(lazy_build, build_fn(into = "std::sync::Arc<Lorem>"))]
+pub struct Lorem {
+    pub ipsum: u32,
+}
+
+fn main() {}
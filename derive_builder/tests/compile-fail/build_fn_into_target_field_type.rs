@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(build_fn(into_target))]
+pub struct Lorem {
+    pub ipsum: String,
+    #[builder(field(type = "u32", build = "self.dolor.to_string()"))]
+    pub dolor: String,
+}
+
+fn main() {}
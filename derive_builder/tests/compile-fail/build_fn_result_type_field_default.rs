@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(field(default), build_fn(result_type = "Result<Lorem, String>"))]
+pub struct Lorem {
+    pub ipsum: u32,
+}
+
+fn main() {}
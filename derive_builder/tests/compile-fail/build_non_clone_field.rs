@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+pub struct Handle(u8);
+
+// `build(&self)` clones the fields, so it requires `Clone` unlike the owned pattern.
+#[derive(Builder)]
+pub struct Lorem {
+    pub ipsum: Handle,
+}
+
+fn main() {}
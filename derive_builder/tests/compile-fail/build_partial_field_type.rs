@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(build_partial)]
+pub struct Lorem {
+    #[builder(field(type = "u32"))]
+    pub ipsum: u32,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[allow(dead_code)]
+#[derive(Clone)]
+enum Color {
+    Red,
+}
+
+#[allow(dead_code)]
+#[derive(Clone)]
+enum Status {
+    Code(u16),
+}
+
+#[allow(dead_code)]
+#[derive(Builder)]
+struct Lorem {
+    #[builder(default = "Status::Code(404)")]
+    ipsum: Color,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[allow(dead_code)]
+#[derive(Builder)]
+struct Lorem {
+    #[builder(default = "invalid expr")]
+    ipsum: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Default, Builder)]
+#[builder(default_struct, custom_constructor)]
+pub struct Lorem {
+    pub ipsum: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(each = "item"))]
+    pub ipsum: Vec<String>,
+    #[builder(setter(each = "item"))]
+    pub dolor: Vec<String>,
+    #[builder(setter(prefix = "with"))]
+    pub sit: String,
+    #[builder(setter(each = "with_sit"))]
+    pub amet: Vec<String>,
+}
+
+fn main() {}
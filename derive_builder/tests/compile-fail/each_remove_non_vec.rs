@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::HashSet;
+
+#[derive(Builder)]
+struct Lorem {
+    #[builder(setter(each(name = "add_ipsum", remove = "remove_ipsum")))]
+    ipsum: HashSet<String>,
+}
+
+fn main() {}
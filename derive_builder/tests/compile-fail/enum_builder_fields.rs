@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(EnumBuilder)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+}
+
+#[derive(Builder)]
+enum Color {
+    Red,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(inot))]
+    pub ipsum: String,
+    #[builder(setter(strip_option), defualt)]
+    pub dolor: Option<u32>,
+}
+
+fn main() {}
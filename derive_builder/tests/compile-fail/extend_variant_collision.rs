@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(impl_extend)]
+struct Lorem {
+    foo_bar: u32,
+    foo__bar: u32,
+}
+
+fn main() {}
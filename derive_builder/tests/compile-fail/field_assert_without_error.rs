@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+struct Lorem {
+    #[builder(field(assert = "value > 1024", assert = "value < 60000", error = "port must be > 1024"))]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+struct Lorem {
+    #[builder(field(computed = "self.dolor + 1"))]
+    ipsum: u32,
+    #[builder(field(computed = "self.ipsum + 1"))]
+    dolor: u32,
+    #[builder(field(computed = "self.ipsum + self.consectetur"))]
+    sit: u32,
+    #[builder(field(computed = "self.amet"))]
+    amet: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(field(default))]
+struct Lorem {
+    #[builder(default = "42")]
+    ipsum: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(required, default)]
+    pub ipsum: Option<u32>,
+}
+
+fn main() {}
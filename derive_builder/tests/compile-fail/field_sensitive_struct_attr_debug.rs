@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder_struct_attr(derive(Debug))]
+pub struct Lorem {
+    #[builder(field(sensitive))]
+    pub password: String,
+}
+
+fn main() {}
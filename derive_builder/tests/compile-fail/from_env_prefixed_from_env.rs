@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(from_env, impl_from_env_prefixed(env_prefix = "APP"))]
+pub struct Lorem {
+    pub ipsum: String,
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(impl_from_parts)]
+struct Lorem {
+    #[builder(setter(custom))]
+    ipsum: u8,
+    #[builder(setter(validate = "check_dolor"))]
+    dolor: u8,
+}
+
+fn check_dolor(_: &u8) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Builder)]
+#[builder(impl_from_parts)]
+struct Sit {
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    f: u8,
+    g: u8,
+    h: u8,
+    i: u8,
+    j: u8,
+    k: u8,
+    l: u8,
+    #[builder(default)]
+    m: u8,
+    n: u8,
+}
+
+fn main() {}
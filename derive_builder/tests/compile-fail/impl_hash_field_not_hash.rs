@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, Clone)]
+struct Handle(f32);
+
+#[derive(Builder)]
+#[builder(impl_hash)]
+struct Lorem {
+    ipsum: u32,
+    handle: Handle,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(inherits = "LoremBuilder")]
+struct Lorem {
+    ipsum: Box<Lorem>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![deny(unused_must_use)]
+
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    ipsum: u32,
+}
+
+fn main() {
+    LoremBuilder::default();
+    LoremBuilder::default().ipsum(42).build();
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Newtype)]
+struct Point(f32, f32);
+
+#[derive(Newtype)]
+struct Kilograms {
+    value: f32,
+}
+
+fn main() {}
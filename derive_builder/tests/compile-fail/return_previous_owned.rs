@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+struct Lorem {
+    #[builder(setter(return_previous))]
+    ipsum: u32,
+}
+
+fn main() {}
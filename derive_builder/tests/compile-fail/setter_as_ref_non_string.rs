@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+struct Lorem {
+    #[builder(setter(as_ref))]
+    ipsum: u32,
+    #[builder(setter(as_ref, into))]
+    dolor: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+struct Lorem {
+    #[builder(setter(boxed))]
+    ipsum: Vec<u32>,
+}
+
+fn main() {}
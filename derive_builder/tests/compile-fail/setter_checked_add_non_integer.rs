@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(checked_add))]
+    pub ipsum: f64,
+    #[builder(setter(checked_add, lazy = "|| 1"))]
+    pub dolor: u32,
+}
+
+fn main() {}
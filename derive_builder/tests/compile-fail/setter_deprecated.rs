@@ -0,0 +1,16 @@
+#![deny(deprecated)]
+
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(deprecated = "use `dolor` instead"))]
+    pub ipsum: u32,
+    #[builder(default)]
+    pub dolor: u32,
+}
+
+fn main() {
+    let _ = LoremBuilder::default().dolor(1).ipsum(2).build();
+}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+struct Lorem {
+    #[builder(setter(into_iter))]
+    ipsum: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(pattern = "owned", derive(Clone, PartialEq))]
+struct Lorem {
+    #[builder(setter(lazy = "|| 1"))]
+    ipsum: u32,
+}
+
+#[derive(Builder)]
+struct Dolor {
+    #[builder(setter(lazy = "|| 1"))]
+    sit: u32,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+struct Amet {
+    #[builder(default, setter(lazy = "|| 1"))]
+    consectetur: u32,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(try_setter)]
+struct Lorem {
+    #[builder(setter(name = "amet"))]
+    ipsum: String,
+    amet: String,
+    #[builder(setter(each = "item"))]
+    items: Vec<String>,
+    #[builder(setter(name = "item"))]
+    dolor: String,
+    #[builder(setter(each = "tags"))]
+    tags: Vec<String>,
+    #[builder(setter(type_alias = "Name"))]
+    sit: String,
+    #[builder(setter(type_alias = "Name"))]
+    consectetur: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(try_setter)]
+struct Lorem {
+    #[builder(setter(once))]
+    ipsum: u32,
+    #[builder(setter(once(panic)))]
+    dolor: u32,
+}
+
+#[derive(Builder)]
+struct Sit {
+    #[builder(try_setter, setter(once))]
+    amet: u32,
+}
+
+fn main() {}
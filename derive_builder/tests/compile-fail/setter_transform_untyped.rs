@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(transform = "|x| x + 1"))]
+    pub ipsum: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(try_into, into))]
+    pub ipsum: u8,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate derive_builder;
+
+pub mod outer {
+    pub mod inner {
+        #[derive(Builder)]
+        pub struct Lorem {
+            pub(super) ipsum: u8,
+        }
+    }
+}
+
+fn main() {
+    outer::inner::LoremBuilder::default().ipsum(1);
+}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+pub struct Lorem {
+    #[builder(setter(with_mut))]
+    pub ipsum: Vec<String>,
+}
+
+fn main() {}
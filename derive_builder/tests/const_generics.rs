@@ -0,0 +1,75 @@
+// Const generics are stable since Rust 1.51, and their defaults since Rust 1.59.
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+extern crate rustversion;
+
+#[rustversion::since(1.51)]
+mod const_generics {
+    #[derive(Debug, PartialEq, Builder)]
+    struct Buf<const N: usize> {
+        data: [u8; N],
+        #[builder(default = "N")]
+        capacity: usize,
+    }
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(pattern = "owned")]
+    struct Matrix<T: Clone, const ROWS: usize, const COLS: usize>
+    where
+        T: Copy,
+    {
+        cells: [[T; COLS]; ROWS],
+    }
+
+    #[test]
+    fn build_with_concrete_n() {
+        let x = BufBuilder::<3>::default().data([1, 2, 3]).build().unwrap();
+
+        assert_eq!(
+            x,
+            Buf {
+                data: [1, 2, 3],
+                capacity: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn error_if_uninitialized() {
+        let error = BufBuilder::<3>::default().build().unwrap_err();
+
+        assert_eq!(&error.to_string(), "`data` must be initialized");
+    }
+
+    #[test]
+    fn mixed_generics() {
+        let x = MatrixBuilder::default()
+            .cells([[1u8, 2, 3], [4, 5, 6]])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            x,
+            Matrix {
+                cells: [[1, 2, 3], [4, 5, 6]],
+            }
+        );
+    }
+}
+
+#[rustversion::since(1.59)]
+mod const_generic_defaults {
+    #[derive(Debug, PartialEq, Builder)]
+    struct Buf<const N: usize = 4> {
+        data: [u8; N],
+    }
+
+    #[test]
+    fn build_with_default_n() {
+        let x: Buf = BufBuilder::default().data([0; 4]).build().unwrap();
+
+        assert_eq!(x, Buf { data: [0; 4] });
+    }
+}
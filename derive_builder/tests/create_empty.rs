@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+mod inner {
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(derive(Debug, PartialEq))]
+    pub struct Lorem {
+        #[builder(default = "42")]
+        pub ipsum: u32,
+        pub dolor: String,
+    }
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(create_empty = "bare", field(public))]
+    pub struct Renamed {
+        #[builder(default)]
+        pub ipsum: u32,
+    }
+}
+
+use inner::{LoremBuilder, RenamedBuilder};
+
+#[test]
+fn create_empty_is_blank() {
+    let builder = LoremBuilder::create_empty();
+
+    assert_eq!(builder, LoremBuilder::default());
+    assert_eq!(
+        builder.build().unwrap_err().to_string(),
+        "`dolor` must be initialized"
+    );
+}
+
+#[test]
+fn defaults_apply_when_building() {
+    let x = LoremBuilder::create_empty()
+        .dolor("dolor".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, 42);
+}
+
+#[test]
+fn renamed() {
+    assert_eq!(RenamedBuilder::bare().ipsum, None);
+}
@@ -72,6 +72,58 @@ mod field_level {
     }
 }
 
+mod enum_field {
+    #[derive(Debug, PartialEq, Clone)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    #[derive(Debug, PartialEq, Clone)]
+    enum Status {
+        Ok,
+        Code(u16),
+    }
+
+    #[derive(Debug, PartialEq, Builder)]
+    struct Lorem {
+        #[builder(default = "Color::Red")]
+        color: Color,
+        #[builder(default = "Status::Code(404)")]
+        status: Status,
+    }
+
+    #[test]
+    fn variant_defaults() {
+        let x = LoremBuilder::default().build().unwrap();
+
+        assert_eq!(
+            x,
+            Lorem {
+                color: Color::Red,
+                status: Status::Code(404),
+            }
+        );
+    }
+
+    #[test]
+    fn variant_overrides() {
+        let x = LoremBuilder::default()
+            .color(Color::Green)
+            .status(Status::Ok)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            x,
+            Lorem {
+                color: Color::Green,
+                status: Status::Ok,
+            }
+        );
+    }
+}
+
 mod struct_level {
     #[derive(Debug, Clone, PartialEq, Eq, Builder)]
     #[builder(default = "explicit_default()")]
@@ -0,0 +1,63 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use derive_builder::UninitializedFieldError;
+
+/// An application-wide error type which the builder reuses instead of generating its own.
+#[derive(Debug, PartialEq)]
+pub enum MyError {
+    MissingField(&'static str),
+    Invalid(String),
+}
+
+impl From<UninitializedFieldError> for MyError {
+    fn from(e: UninitializedFieldError) -> Self {
+        MyError::MissingField(e.field_name())
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::MyError", validate = "Self::validate"))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default)]
+    dolor: bool,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), MyError> {
+        match self.ipsum {
+            Some(0) => Err(MyError::Invalid("ipsum must not be zero".to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn converts_uninitialized_field_error() {
+    let error: MyError = LoremBuilder::default().build().unwrap_err();
+    assert_eq!(error, MyError::MissingField("ipsum"));
+}
+
+#[test]
+fn returns_validation_error() {
+    let error = LoremBuilder::default().ipsum(0).build().unwrap_err();
+    assert_eq!(
+        error,
+        MyError::Invalid("ipsum must not be zero".to_string())
+    );
+}
+
+#[test]
+fn builds_with_custom_error_type() {
+    let x: Result<Lorem, MyError> = LoremBuilder::default().ipsum(1).build();
+    assert_eq!(
+        x,
+        Ok(Lorem {
+            ipsum: 1,
+            dolor: false,
+        })
+    );
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(default_struct, derive(Debug))]
+struct Lorem {
+    ipsum: String,
+    #[builder(default = "7")]
+    dolor: u32,
+    #[builder(setter(into, strip_option))]
+    sit: Option<String>,
+    #[builder(setter(skip))]
+    amet: bool,
+}
+
+impl Default for Lorem {
+    fn default() -> Self {
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: 42,
+            sit: Some("sit".to_string()),
+            amet: true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Default, Builder)]
+#[builder(default_struct, lazy_build)]
+struct Ipsum<T: Clone> {
+    value: T,
+    #[builder(required)]
+    name: String,
+}
+
+#[test]
+fn builder_starts_from_the_struct_default() {
+    let builder = LoremBuilder::default();
+    assert_eq!(builder.ipsum, Some("ipsum".to_string()));
+    assert_eq!(builder.sit, Some(Some("sit".to_string())));
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: 7,
+            sit: Some("sit".to_string()),
+            amet: false,
+        }
+    );
+}
+
+#[test]
+fn setters_override_the_struct_default() {
+    let x = LoremBuilder::default()
+        .ipsum("lorem".to_string())
+        .dolor(1)
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, "lorem");
+    assert_eq!(x.dolor, 1);
+    assert_eq!(x.sit, Some("sit".to_string()));
+}
+
+#[test]
+fn field_defaults_take_precedence() {
+    assert_eq!(LoremBuilder::default().dolor, None);
+    assert_eq!(Lorem::builder().build().unwrap().dolor, 7);
+}
+
+#[test]
+fn generic_struct() {
+    let mut builder = IpsumBuilder::<u8>::default();
+    assert_eq!(
+        &builder.build().unwrap_err().to_string(),
+        "`name` must be initialized"
+    );
+
+    let x = IpsumBuilder::<u8>::default()
+        .name("ipsum".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(
+        *x,
+        Ipsum {
+            value: 0,
+            name: "ipsum".to_string(),
+        }
+    );
+}
@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::num::ParseIntError;
+
+fn parse_port(value: &str) -> Result<u16, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid port `{}`", value))
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(default = "parse_port(\"8080\")", try)]
+    ipsum: u16,
+    #[builder(default = "parse_port(\"port\")", try)]
+    dolor: u16,
+    #[builder(setter(skip), default = "parse_port(\"80\")", try)]
+    sit: u16,
+}
+
+#[derive(Debug, PartialEq)]
+enum MyError {
+    Uninitialized(String),
+    Parse(ParseIntError),
+}
+
+impl From<derive_builder::UninitializedFieldError> for MyError {
+    fn from(e: derive_builder::UninitializedFieldError) -> Self {
+        MyError::Uninitialized(e.field_name().to_string())
+    }
+}
+
+impl From<ParseIntError> for MyError {
+    fn from(e: ParseIntError) -> Self {
+        MyError::Parse(e)
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(error = "MyError"))]
+struct Ipsum {
+    #[builder(default = "\"-1\".parse::<u8>()", try)]
+    dolor: u8,
+}
+
+#[test]
+fn default_error_is_returned() {
+    let error = LoremBuilder::default().build().unwrap_err();
+    assert_eq!(error.to_string(), "invalid port `port`");
+}
+
+#[test]
+fn set_fields_skip_default() {
+    let x = LoremBuilder::default().dolor(1).build().unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 8080,
+            dolor: 1,
+            sit: 80,
+        }
+    );
+}
+
+#[test]
+fn custom_error_conversion() {
+    match IpsumBuilder::default().build() {
+        Err(MyError::Parse(_)) => {}
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(
+        IpsumBuilder::default().dolor(1).build(),
+        Ok(Ipsum { dolor: 1 })
+    );
+}
@@ -23,3 +23,24 @@ fn defaults() {
     // so this one line is testing that the requested traits were really generated.
     assert_eq!(LoremBuilder::default(), LoremBuilder::default());
 }
+
+#[test]
+fn same_fields_compare_equal() {
+    let mut x = LoremBuilder::default();
+    x.foo(1);
+    let mut y = LoremBuilder::default();
+    y.foo(1);
+
+    assert_eq!(x, y);
+}
+
+#[test]
+fn different_fields_compare_unequal() {
+    let mut x = LoremBuilder::default();
+    x.foo(1);
+    let mut y = LoremBuilder::default();
+    y.foo(2);
+
+    assert_ne!(x, y);
+    assert_ne!(x, LoremBuilder::default());
+}
@@ -0,0 +1,30 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Empty {}
+
+#[derive(Debug, PartialEq, Default, Builder)]
+#[builder(default, pattern = "owned", build_fn(validate = "Self::validate"))]
+struct EmptyWithOptions {}
+
+impl EmptyWithOptionsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[test]
+fn build_empty() {
+    assert_eq!(EmptyBuilder::default().build().unwrap(), Empty {});
+}
+
+#[test]
+fn build_empty_with_options() {
+    assert_eq!(
+        EmptyWithOptionsBuilder::default().build().unwrap(),
+        EmptyWithOptions {}
+    );
+}
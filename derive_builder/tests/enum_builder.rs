@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, EnumBuilder)]
+pub enum Color {
+    Red,
+    DarkGreen,
+    Blue,
+}
+
+#[derive(Debug, PartialEq, EnumBuilder)]
+#[allow(clippy::upper_case_acronyms)]
+enum Protocol {
+    HTTPS,
+    #[allow(dead_code)]
+    Ftp,
+}
+
+#[test]
+fn choose_variant() {
+    let color = ColorBuilder::default().choose_dark_green().build().unwrap();
+
+    assert_eq!(color, Color::DarkGreen);
+}
+
+#[test]
+fn choose_again_overwrites() {
+    let mut builder = ColorBuilder::default();
+    builder.choose_red();
+    builder.choose_blue();
+
+    assert_eq!(builder.build().unwrap(), Color::Blue);
+}
+
+#[test]
+fn unchosen() {
+    let err = ColorBuilder::default().build().unwrap_err();
+
+    assert_eq!(err.enum_name(), "Color");
+    assert_eq!(&err.to_string(), "No variant of `Color` was chosen");
+}
+
+#[test]
+fn acronym_variant() {
+    let protocol = ProtocolBuilder::default().choose_https().build().unwrap();
+
+    assert_eq!(protocol, Protocol::HTTPS);
+}
@@ -0,0 +1,154 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(build_fn(unchecked))]
+struct Lorem {
+    #[builder(field(
+        assert = "value > 1024",
+        error = "port must be > 1024",
+        assert = "value % 2 == 0",
+        error = "port must be even"
+    ))]
+    port: u16,
+    #[builder(
+        setter(into),
+        default = "\"localhost\".to_string()",
+        field(assert = "!value.is_empty()", error = "host must not be empty")
+    )]
+    host: String,
+    #[builder(field(
+        map = "|v: Vec<u8>| v.into_iter().filter(|x| *x != 0).collect()",
+        assert = "value.len() < 3",
+        error = "at most two non-zero flags"
+    ))]
+    flags: Vec<u8>,
+}
+
+#[test]
+fn assertions_pass() {
+    let x = LoremBuilder::default()
+        .port(8080)
+        .flags(vec![0, 1, 0, 2])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            port: 8080,
+            host: "localhost".to_string(),
+            flags: vec![1, 2],
+        }
+    );
+}
+
+#[test]
+fn failed_assertion_is_a_validation_error() {
+    let error = LoremBuilder::default()
+        .port(80)
+        .flags(vec![])
+        .build()
+        .unwrap_err();
+
+    assert_eq!(&error.to_string(), "port must be > 1024");
+    match error {
+        LoremBuilderError::ValidationError(message) => assert_eq!(message, "port must be > 1024"),
+        error => panic!("unexpected error {:?}", error),
+    }
+}
+
+#[test]
+fn every_assertion_is_checked() {
+    let error = LoremBuilder::default()
+        .port(2049)
+        .flags(vec![])
+        .build()
+        .unwrap_err();
+
+    assert_eq!(&error.to_string(), "port must be even");
+}
+
+#[test]
+fn assertions_check_defaulted_and_mapped_values() {
+    let error = LoremBuilder::default()
+        .port(8080)
+        .host("")
+        .flags(vec![])
+        .build()
+        .unwrap_err();
+    assert_eq!(&error.to_string(), "host must not be empty");
+
+    let error = LoremBuilder::default()
+        .port(8080)
+        .flags(vec![1, 2, 3])
+        .build()
+        .unwrap_err();
+    assert_eq!(&error.to_string(), "at most two non-zero flags");
+}
+
+#[test]
+fn unchecked_build_skips_assertions() {
+    let x = unsafe {
+        LoremBuilder::default()
+            .port(80)
+            .flags(vec![])
+            .build_unchecked()
+    };
+
+    assert_eq!(x.port, 80);
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", build_fn(error = "IpsumError"))]
+struct Ipsum {
+    #[builder(field(assert = "value != \"dolor\"", error = "dolor is reserved"))]
+    name: String,
+}
+
+#[derive(Debug)]
+enum IpsumError {
+    Missing(String),
+    Invalid(String),
+}
+
+impl From<derive_builder::UninitializedFieldError> for IpsumError {
+    fn from(e: derive_builder::UninitializedFieldError) -> Self {
+        IpsumError::Missing(e.field_name().to_string())
+    }
+}
+
+impl From<String> for IpsumError {
+    fn from(s: String) -> Self {
+        IpsumError::Invalid(s)
+    }
+}
+
+#[test]
+fn assertions_with_custom_error() {
+    match IpsumBuilder::default()
+        .name("dolor".to_string())
+        .build()
+        .unwrap_err()
+    {
+        IpsumError::Invalid(message) => assert_eq!(message, "dolor is reserved"),
+        error => panic!("unexpected error {:?}", error),
+    }
+
+    match IpsumBuilder::default().build().unwrap_err() {
+        IpsumError::Missing(field) => assert_eq!(field, "name"),
+        error => panic!("unexpected error {:?}", error),
+    }
+
+    assert_eq!(
+        IpsumBuilder::default()
+            .name("sit".to_string())
+            .build()
+            .unwrap(),
+        Ipsum {
+            name: "sit".to_string()
+        }
+    );
+}
@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate", unchecked))]
+struct Lorem {
+    width: u32,
+    #[builder(field(computed = "self.half_area * 2"))]
+    area: u32,
+    #[builder(field(computed = "self.width * self.height / 2"))]
+    half_area: u32,
+    #[builder(default = "3")]
+    height: u32,
+    #[builder(field(computed = "format!(\"{}x{}\", self.width, self.height)"))]
+    label: String,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.width {
+            Some(0) => Err("width must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(default, pattern = "owned")]
+struct Ipsum {
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: u8,
+    #[builder(field(computed = "self.dolor.len() + self.sit as usize"))]
+    amet: usize,
+}
+
+impl Default for Ipsum {
+    fn default() -> Self {
+        Ipsum {
+            dolor: "dolor".to_string(),
+            sit: 2,
+            amet: 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(lazy_build)]
+struct Cached {
+    ipsum: u32,
+    #[builder(field(computed = "self.ipsum + 1"))]
+    dolor: u32,
+}
+
+#[test]
+fn computed_from_other_fields() {
+    let x = LoremBuilder::default().width(4).build().unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            width: 4,
+            area: 12,
+            half_area: 6,
+            height: 3,
+            label: "4x3".to_string(),
+        }
+    );
+}
+
+#[test]
+fn computed_after_validation() {
+    let error = LoremBuilder::default().width(0).build().unwrap_err();
+
+    assert_eq!(&error.to_string(), "width must not be zero");
+}
+
+#[test]
+fn computed_in_unchecked_build() {
+    let x = unsafe { LoremBuilder::default().width(2).height(5).build_unchecked() };
+
+    assert_eq!(x.area, 10);
+    assert_eq!(x.label, "2x5");
+}
+
+#[test]
+fn computed_with_struct_default() {
+    assert_eq!(
+        IpsumBuilder::default().build().unwrap(),
+        Ipsum {
+            dolor: "dolor".to_string(),
+            sit: 2,
+            amet: 7,
+        }
+    );
+    assert_eq!(
+        IpsumBuilder::default()
+            .dolor("ipsum ipsum".into())
+            .build()
+            .unwrap()
+            .amet,
+        13
+    );
+}
+
+#[test]
+fn computed_in_lazy_build() {
+    let mut builder = CachedBuilder::default();
+
+    assert_eq!(builder.ipsum(1).build().unwrap().dolor, 2);
+    assert_eq!(builder.ipsum(2).build().unwrap().dolor, 3);
+}
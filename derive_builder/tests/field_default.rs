@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(field(default))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into))]
+    dolor: String,
+    #[builder(setter(each(name = "sit_item", remove = "remove_sit")))]
+    sit: Vec<u8>,
+    #[builder(setter(strip_option))]
+    amet: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", field(default))]
+struct Owned {
+    ipsum: String,
+    dolor: u16,
+}
+
+#[test]
+fn unset_fields_are_default() {
+    let x: Lorem = LoremBuilder::default().ipsum(1).build();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: String::new(),
+            sit: vec![],
+            amet: None,
+        }
+    );
+}
+
+#[test]
+fn all_fields_set() {
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .dolor("dolor")
+        .sit_item(1)
+        .sit_item(2)
+        .sit_item(3)
+        .remove_sit(&2)
+        .amet(true)
+        .build();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+            sit: vec![1, 3],
+            amet: Some(true),
+        }
+    );
+}
+
+#[test]
+fn owned_builder() {
+    let x = OwnedBuilder::default().ipsum("ipsum".to_string()).build();
+
+    assert_eq!(
+        x,
+        Owned {
+            ipsum: "ipsum".to_string(),
+            dolor: 0,
+        }
+    );
+}
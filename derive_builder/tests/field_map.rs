@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+fn normalize(value: Vec<u8>) -> Vec<u8> {
+    let mut value = value;
+    value.sort_unstable();
+    value.dedup();
+    value
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(into), field(map = "|v| v.trim().to_string()"))]
+    ipsum: String,
+    #[builder(default = "vec![3, 1, 3]", field(map = "normalize"))]
+    dolor: Vec<u8>,
+    #[builder(setter(skip), field(map = "|v: u32| v + 1"))]
+    sit: u32,
+}
+
+/// Not shadowed by the helper the build method uses to call the mapping.
+fn apply_map(value: u8) -> u8 {
+    value * 3
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Consectetur {
+    #[builder(field(map = "apply_map"))]
+    adipiscing: u8,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Amet {
+    #[builder(field(
+        type = "u16",
+        build = "self.consectetur.into()",
+        map = "|v: u32| v * 2"
+    ))]
+    consectetur: u32,
+}
+
+#[test]
+fn map_set_value() {
+    let x = LoremBuilder::default()
+        .ipsum("  ipsum\n")
+        .dolor(vec![2, 2, 1])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: vec![1, 2],
+            sit: 1,
+        }
+    );
+}
+
+#[test]
+fn map_default_value() {
+    let x = LoremBuilder::default().ipsum("ipsum").build().unwrap();
+
+    assert_eq!(x.dolor, vec![1, 3]);
+}
+
+#[test]
+fn map_uninitialized() {
+    let err = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(&err.to_string(), "`ipsum` must be initialized");
+}
+
+#[test]
+fn map_custom_field() {
+    let x = AmetBuilder::default().consectetur(21).build().unwrap();
+
+    assert_eq!(x, Amet { consectetur: 42 });
+}
+
+#[test]
+fn map_named_like_helper() {
+    let x = ConsecteturBuilder::default().adipiscing(2).build().unwrap();
+
+    assert_eq!(x, Consectetur { adipiscing: 6 });
+}
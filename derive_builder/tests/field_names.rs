@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(field_names)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(field_names)]
+struct Raw {
+    r#type: u8,
+    #[builder(setter(skip))]
+    hidden: bool,
+}
+
+#[test]
+fn lists_fields_in_order() {
+    assert_eq!(LoremBuilder::FIELD_NAMES, ["ipsum", "dolor"]);
+}
+
+#[test]
+fn lists_raw_and_skipped_fields() {
+    assert_eq!(RawBuilder::FIELD_NAMES, ["type", "hidden"]);
+}
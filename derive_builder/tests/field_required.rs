@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder)]
+#[builder(default)]
+struct Lorem {
+    #[builder(setter(strip_option), required)]
+    ipsum: Option<String>,
+    #[builder(setter(strip_option))]
+    dolor: Option<u32>,
+    #[builder(required)]
+    sit: Option<u8>,
+}
+
+#[test]
+fn required_options_must_be_set() {
+    let error = LoremBuilder::default().sit(None).build().unwrap_err();
+    assert_eq!(&error.to_string(), "`ipsum` must be initialized");
+
+    match LoremBuilder::default().build().unwrap_err() {
+        LoremBuilderError::UninitializedFields(ref fields) => {
+            assert_eq!(fields, &["ipsum", "sit"]);
+        }
+        ref other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[test]
+fn required_options_may_be_set_to_none_explicitly() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum".to_string())
+        .sit(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: Some("ipsum".to_string()),
+            dolor: None,
+            sit: None,
+        }
+    );
+}
+
+#[test]
+fn other_fields_keep_the_struct_default() {
+    let error = LoremBuilder::default()
+        .ipsum("ipsum".to_string())
+        .build()
+        .unwrap_err();
+
+    assert_eq!(&error.to_string(), "`sit` must be initialized");
+}
@@ -0,0 +1,106 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, Builder)]
+#[builder(derive(Debug, PartialEq), impl_display, impl_snapshot)]
+struct Lorem {
+    user: String,
+    #[builder(field(sensitive))]
+    password: String,
+}
+
+#[derive(Debug, Builder)]
+#[builder(
+    pattern = "owned",
+    derive(Debug),
+    impl_display,
+    impl_snapshot,
+    field(public)
+)]
+struct Ipsum {
+    #[builder(field(sensitive))]
+    key: Vec<u8>,
+    #[builder(field(type = "String", build = "self.pin.clone()", sensitive))]
+    pin: String,
+}
+
+#[test]
+fn debug_masks_sensitive_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.user("ipsum".to_string());
+
+    assert_eq!(
+        format!("{:?}", builder),
+        "LoremBuilder { user: Some(\"ipsum\"), password: None }"
+    );
+
+    builder.password("hunter2".to_string());
+
+    assert_eq!(
+        format!("{:?}", builder),
+        "LoremBuilder { user: Some(\"ipsum\"), password: Some(\"<sensitive>\") }"
+    );
+}
+
+#[test]
+fn other_derives_are_kept() {
+    let mut builder = LoremBuilder::default();
+    builder
+        .user("ipsum".to_string())
+        .password("hunter2".to_string());
+
+    assert_ne!(builder, LoremBuilder::default());
+    assert_eq!(builder.build().unwrap().password, "hunter2");
+}
+
+#[test]
+fn display_masks_sensitive_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.user("ipsum".to_string());
+    assert_eq!(
+        builder.to_string(),
+        "LoremBuilder { user: ipsum, password: unset }"
+    );
+
+    builder.password("hunter2".to_string());
+    assert_eq!(
+        builder.to_string(),
+        "LoremBuilder { user: ipsum, password: <sensitive> }"
+    );
+}
+
+#[test]
+fn snapshot_masks_sensitive_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.password("hunter2".to_string());
+    let snapshot = builder.snapshot();
+
+    assert_eq!(snapshot.user, None);
+    assert_eq!(snapshot.password, Some("<redacted>".to_string()));
+    assert_eq!(
+        snapshot.to_string(),
+        "[LoremBuilder]\n# user = unset\npassword = <redacted>"
+    );
+}
+
+#[test]
+fn custom_field_types_are_masked() {
+    let builder = IpsumBuilder::default()
+        .key(vec![1, 2])
+        .pin("1234".to_string());
+
+    assert_eq!(
+        format!("{:?}", builder),
+        "IpsumBuilder { key: Some(\"<sensitive>\"), pin: \"<sensitive>\" }"
+    );
+    assert_eq!(
+        builder.to_string(),
+        "IpsumBuilder { key: <sensitive>, pin: <sensitive> }"
+    );
+    assert_eq!(builder.snapshot().pin, Some("<redacted>".to_string()));
+    assert_eq!(builder.build().unwrap().pin, "1234");
+}
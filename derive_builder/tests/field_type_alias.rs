@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::HashMap;
+
+type Meters = f64;
+type Tags = Vec<String>;
+type Table<V> = HashMap<String, V>;
+type MaybePort = Option<u16>;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(into))]
+struct Lorem {
+    distance: Meters,
+    #[builder(setter(each(name = "tag", into)), default)]
+    tags: Tags,
+    #[builder(default)]
+    table: Table<u8>,
+    /// `strip_option` cannot see the `Option` behind the alias, so the setter takes the
+    /// alias itself.
+    #[builder(setter(strip_option), default)]
+    port: MaybePort,
+}
+
+#[test]
+fn aliased_field_types() {
+    let mut table = Table::new();
+    table.insert("sit".to_string(), 1);
+
+    let x = LoremBuilder::default()
+        .distance(1.5)
+        .tag("ipsum")
+        .tag("dolor")
+        .table(table.clone())
+        .port(Some(80))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            distance: 1.5,
+            tags: vec!["ipsum".to_string(), "dolor".to_string()],
+            table,
+            port: Some(80),
+        }
+    );
+}
+
+#[test]
+fn aliased_field_types_are_required() {
+    let error = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(&error.to_string(), "`distance` must be initialized");
+}
@@ -14,6 +14,18 @@ struct Example {
     last_name: String,
 }
 
+/// `serde` attributes on the target struct's fields are only valid on the target struct,
+/// so this only compiles if they are not copied to the builder fields or setters.
+#[derive(Builder, serde::Serialize)]
+#[builder(setter(into))]
+struct Renamed {
+    #[serde(rename = "x")]
+    horizontal: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    label: Option<String>,
+}
+
 #[test]
 fn serialize_builder() {
     assert_eq!(
@@ -27,3 +39,9 @@ fn serialize_builder() {
         r#"{"firstName":"Jane","middleName":"Alice","familyName":"Doe"}"#
     );
 }
+
+#[test]
+fn serde_field_attrs_not_forwarded() {
+    let renamed = RenamedBuilder::default().horizontal(3).build().unwrap();
+    assert_eq!(serde_json::to_string(&renamed).unwrap(), r#"{"x":3}"#);
+}
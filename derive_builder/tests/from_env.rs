@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::env;
+
+/// Not parseable from a string, so it must be skipped.
+#[derive(Debug, PartialEq, Clone, Default)]
+struct Handle(u8);
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(from_env)]
+struct Config {
+    host: String,
+    port: u16,
+    #[builder(default = "false")]
+    verbose: bool,
+    #[builder(setter(strip_option), default)]
+    workers: Option<u32>,
+    #[builder(env_skip, default)]
+    handle: Handle,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(from_env)]
+struct Limits {
+    #[builder(default)]
+    threads: Option<u32>,
+}
+
+// Each test uses its own prefix since tests run in parallel within one process.
+
+#[test]
+fn reads_all_vars() {
+    env::set_var("ALL_HOST", "localhost");
+    env::set_var("ALL_PORT", "8080");
+    env::set_var("ALL_VERBOSE", "true");
+    env::set_var("ALL_WORKERS", "4");
+
+    let config = ConfigBuilder::from_env("ALL").unwrap().build().unwrap();
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost".to_string(),
+            port: 8080,
+            verbose: true,
+            workers: Some(4),
+            handle: Handle(0),
+        }
+    );
+}
+
+#[test]
+fn optional_vars_may_be_absent() {
+    env::set_var("OPT_HOST", "localhost");
+    env::set_var("OPT_PORT", "80");
+
+    let config = ConfigBuilder::from_env("OPT")
+        .unwrap()
+        .handle(Handle(1))
+        .build()
+        .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost".to_string(),
+            port: 80,
+            verbose: false,
+            workers: None,
+            handle: Handle(1),
+        }
+    );
+}
+
+#[test]
+fn required_var_missing() {
+    env::set_var("MISSING_HOST", "localhost");
+
+    let error = ConfigBuilder::from_env("MISSING").err().unwrap();
+    assert_eq!(error.var_name(), "MISSING_PORT");
+    assert_eq!(
+        &error.to_string(),
+        "Environment variable MISSING_PORT: not present"
+    );
+}
+
+#[test]
+fn parse_error() {
+    env::set_var("BAD_HOST", "localhost");
+    env::set_var("BAD_PORT", "eighty");
+
+    let error = ConfigBuilder::from_env("BAD").err().unwrap();
+    assert_eq!(error.var_name(), "BAD_PORT");
+    assert_eq!(error.message(), "invalid digit found in string");
+}
+
+#[test]
+fn option_fields_parse_the_inner_type() {
+    env::set_var("LIMITS_THREADS", "8");
+    let limits = LimitsBuilder::from_env("LIMITS").unwrap().build().unwrap();
+    assert_eq!(limits, Limits { threads: Some(8) });
+
+    let limits = LimitsBuilder::from_env("NO_LIMITS")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(limits, Limits { threads: None });
+}
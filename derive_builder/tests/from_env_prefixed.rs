@@ -0,0 +1,88 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use derive_builder::EnvError;
+use std::env;
+
+/// Not parseable from a string, so it must be skipped.
+#[derive(Debug, PartialEq, Clone, Default)]
+struct Handle(u8);
+
+// The prefix is fixed per struct, and tests run in parallel within one process, so each test
+// reads its own struct.
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_from_env_prefixed(env_prefix = "PREFIXED_ALL"))]
+struct All {
+    host: String,
+    #[builder(setter(strip_option), default)]
+    workers: Option<u32>,
+    #[builder(env_skip, default)]
+    handle: Handle,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_from_env_prefixed(env_prefix = "PREFIXED_MISSING"))]
+struct Missing {
+    host: String,
+    #[builder(default)]
+    verbose: bool,
+    port: u16,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_from_env_prefixed(env_prefix = "PREFIXED_INVALID"))]
+struct Invalid {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn reads_the_prefixed_vars() {
+    env::set_var("PREFIXED_ALL_HOST", "localhost");
+    env::set_var("PREFIXED_ALL_WORKERS", "4");
+
+    let x = AllBuilder::from_env().unwrap().build().unwrap();
+    assert_eq!(
+        x,
+        All {
+            host: "localhost".to_string(),
+            workers: Some(4),
+            handle: Handle(0),
+        }
+    );
+}
+
+#[test]
+fn lists_all_missing_vars() {
+    match MissingBuilder::from_env() {
+        Err(EnvError::Missing(ref vars)) => {
+            assert_eq!(vars, &["PREFIXED_MISSING_HOST", "PREFIXED_MISSING_PORT"])
+        }
+        Err(other) => panic!("unexpected error {}", other),
+        Ok(_) => panic!("`host` and `port` are required"),
+    }
+
+    env::set_var("PREFIXED_MISSING_PORT", "80");
+    let error = MissingBuilder::from_env().err().unwrap();
+    assert_eq!(
+        &error.to_string(),
+        "Missing environment variables: PREFIXED_MISSING_HOST"
+    );
+}
+
+#[test]
+fn reports_invalid_values() {
+    env::set_var("PREFIXED_INVALID_PORT", "http");
+
+    match InvalidBuilder::from_env() {
+        Err(EnvError::Invalid(ref error)) => {
+            assert_eq!(error.var_name(), "PREFIXED_INVALID_PORT");
+            assert_eq!(error.message(), "invalid digit found in string");
+        }
+        Err(other) => panic!("unexpected error {}", other),
+        Ok(_) => panic!("`port` is not a number"),
+    }
+}
@@ -26,7 +26,7 @@ where
 #[test]
 fn error_if_uninitialized() {
     let error = GenericBuilder::<String>::default().build().unwrap_err();
-    assert_eq!(&error.to_string(), "`ipsum` must be initialized");
+    assert_eq!(&error.to_string(), "`ipsum`, `dolor` must be initialized");
 }
 
 #[test]
@@ -40,7 +40,7 @@ fn generic_builder() {
     assert_eq!(
         x,
         Generic {
-            ipsum: "Generic".into(),
+            ipsum: "Generic",
             dolor: true,
         }
     );
@@ -57,3 +57,30 @@ fn generic_reference_builder() {
 
     assert_eq!(x, GenericReference { bar: Some(&BAR) });
 }
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(try_setter, setter(into))]
+struct ValueParam<VALUE: Clone> {
+    ipsum: VALUE,
+    #[builder(setter(each(name = "dolor_item", into)))]
+    dolor: Vec<String>,
+}
+
+#[test]
+fn generic_parameter_named_value() {
+    let x = ValueParamBuilder::default()
+        .ipsum(1u8)
+        .dolor_item("dolor")
+        .build()
+        .unwrap();
+    assert_eq!(
+        x,
+        ValueParam {
+            ipsum: 1u8,
+            dolor: vec!["dolor".to_string()],
+        }
+    );
+
+    let mut builder = ValueParamBuilder::<u8>::default();
+    assert!(builder.try_ipsum(1000u16).is_err());
+}
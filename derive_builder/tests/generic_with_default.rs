@@ -36,3 +36,26 @@ fn create_with_string() {
     assert_eq!(ri.name, "ri");
     assert!(ri.active);
 }
+
+#[derive(Debug, PartialEq, Builder)]
+pub struct Declared<T: Default> {
+    #[builder(default = "T::default()")]
+    pub value: T,
+}
+
+/// `T::default()` adds a `T: Default` bound to the build method.
+#[derive(Debug, PartialEq, Builder)]
+pub struct Undeclared<T> {
+    #[builder(default = "T::default()")]
+    pub value: T,
+    pub other: T,
+}
+
+#[test]
+fn default_of_type_parameter() {
+    let x: Declared<String> = DeclaredBuilder::default().build().unwrap();
+    assert_eq!(x.value, "");
+
+    let y = UndeclaredBuilder::default().other(1u8).build().unwrap();
+    assert_eq!(y, Undeclared { value: 0, other: 1 });
+}
@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_builder_on_original)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into))]
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+    #[builder(setter(strip_option), default)]
+    amet: Option<u8>,
+    #[builder(field(type = "Vec<u8>", build = "self.consectetur.clone()"))]
+    consectetur: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_builder_on_original, pattern = "owned")]
+struct Generic<T> {
+    value: T,
+    #[builder(setter(lazy = "|| 1"))]
+    count: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(impl_builder_on_original, lazy_build)]
+struct Cached {
+    ipsum: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct NotClone;
+
+#[test]
+fn to_builder_clones_fields() {
+    let lorem = LoremBuilder::default()
+        .ipsum(1)
+        .dolor("dolor")
+        .amet(2)
+        .consectetur(vec![3])
+        .build()
+        .unwrap();
+
+    let mut builder = lorem.to_builder();
+    builder.ipsum(4);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            ipsum: 4,
+            dolor: "dolor".to_string(),
+            sit: false,
+            amet: Some(2),
+            consectetur: vec![3],
+        }
+    );
+    assert_eq!(lorem.ipsum, 1);
+}
+
+#[test]
+fn skipped_fields_are_reset() {
+    let lorem = Lorem {
+        ipsum: 1,
+        dolor: "dolor".to_string(),
+        sit: true,
+        amet: None,
+        consectetur: vec![],
+    };
+
+    assert!(!lorem.to_builder().build().unwrap().sit);
+}
+
+#[test]
+fn generic_to_builder() {
+    let generic = Generic {
+        value: "value".to_string(),
+        count: 2,
+    };
+
+    assert_eq!(generic.to_builder().build().unwrap(), generic);
+}
+
+#[test]
+fn lazy_build_cache_is_empty() {
+    let cached = Cached { ipsum: 1 };
+    let mut builder = cached.to_builder();
+
+    assert_eq!(*builder.build().unwrap(), cached);
+    builder.ipsum(2);
+    assert_eq!(builder.build().unwrap().ipsum, 2);
+}
+
+#[test]
+fn field_types_need_not_be_clone_without_to_builder() {
+    let generic = GenericBuilder::default().value(NotClone).build().unwrap();
+
+    assert_eq!(
+        generic,
+        Generic {
+            value: NotClone,
+            count: 1,
+        }
+    );
+}
@@ -0,0 +1,68 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Deliberately does not implement `PartialEq`.
+#[derive(Debug, Clone)]
+struct Handle(u8);
+
+#[derive(Debug, Builder)]
+#[builder(impl_diff)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(diff_presence_only)]
+    handle: Handle,
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_diff)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn identical_builders() {
+    let mut a = LoremBuilder::default();
+    a.ipsum(1).handle(Handle(1));
+    let mut b = LoremBuilder::default();
+    b.ipsum(1).handle(Handle(2));
+
+    let diff = a.diff(&b);
+    assert_eq!(diff, LoremBuilderDiff::default());
+    assert!(!diff.has_changes());
+}
+
+#[test]
+fn differing_builders() {
+    let mut a = LoremBuilder::default();
+    a.ipsum(1).dolor("a".to_string());
+    let mut b = LoremBuilder::default();
+    b.ipsum(2).handle(Handle(0));
+
+    let diff = a.diff(&b);
+    assert_eq!(
+        diff,
+        LoremBuilderDiff {
+            ipsum: Some(false),
+            dolor: Some(true),
+            handle: Some(true),
+        }
+    );
+    assert!(diff.has_changes());
+}
+
+#[test]
+fn generic_builders() {
+    let mut a = GenericBuilder::default();
+    a.value(vec![1]);
+    let mut b = GenericBuilder::default();
+    b.value(vec![1]);
+
+    assert!(!a.diff(&b).has_changes());
+    b.value(vec![2]);
+    assert_eq!(a.diff(&b).value, Some(false));
+}
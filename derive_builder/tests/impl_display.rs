@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, Builder)]
+#[builder(impl_display)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    sit: Vec<u8>,
+    amet: String,
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_display(show_values = false))]
+struct Secret {
+    user: String,
+    password: String,
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_display)]
+struct Generic<T> {
+    value: T,
+    count: u8,
+}
+
+#[test]
+fn shows_values_and_unset_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(42).dolor("dolor".to_string()).sit(vec![1, 2]);
+
+    assert_eq!(
+        builder.to_string(),
+        "LoremBuilder { ipsum: 42, dolor: dolor, sit: set, amet: unset }"
+    );
+}
+
+#[test]
+fn hides_values() {
+    let mut builder = SecretBuilder::default();
+    builder.password("hunter2".to_string());
+
+    assert_eq!(
+        builder.to_string(),
+        "SecretBuilder { user: unset, password: set }"
+    );
+}
+
+#[test]
+fn generic_values_show_as_set() {
+    let mut builder = GenericBuilder::default();
+    builder.value(1u32).count(2);
+
+    assert_eq!(
+        builder.to_string(),
+        "GenericBuilder { value: set, count: 2 }"
+    );
+}
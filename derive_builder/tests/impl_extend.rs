@@ -0,0 +1,141 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use derive_builder::FieldParseError;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_extend(parse))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into, strip_option), default)]
+    dolor_sit: Option<String>,
+    #[builder(setter(skip))]
+    amet: bool,
+    #[builder(setter(normalize = "trim"), default)]
+    consectetur: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_extend, pattern = "owned")]
+struct Owned {
+    ipsum: u32,
+    #[builder(pattern = "immutable")]
+    dolor: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_extend)]
+struct Generic<T> {
+    ipsum: T,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_extend)]
+#[allow(non_snake_case)]
+struct Unusual {
+    _1: u32,
+    __: u32,
+    self_: u32,
+    r#type: u32,
+}
+
+#[test]
+fn extend() {
+    let mut builder = LoremBuilder::default();
+    builder.extend(vec![
+        LoremField::Ipsum(1),
+        LoremField::DolorSit("dolor".to_string()),
+        LoremField::Consectetur("  consectetur ".to_string()),
+    ]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            ipsum: 1,
+            dolor_sit: Some("dolor".to_string()),
+            amet: false,
+            consectetur: "consectetur".to_string(),
+        }
+    );
+}
+
+#[test]
+fn extend_owned() {
+    let mut builder = OwnedBuilder::default();
+    builder.extend(vec![OwnedField::Ipsum(1), OwnedField::Dolor(vec![2])]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Owned {
+            ipsum: 1,
+            dolor: vec![2],
+        }
+    );
+}
+
+#[test]
+fn parse_from_str() {
+    let lines = ["ipsum=42", "dolor_sit=dolor"];
+    let mut builder = LoremBuilder::default();
+    builder.extend(lines.iter().map(|line| {
+        let mut parts = line.splitn(2, '=');
+        let name = parts.next().unwrap();
+        LoremField::parse_from_str(name, parts.next().unwrap()).unwrap()
+    }));
+
+    let x = builder.build().unwrap();
+    assert_eq!(x.ipsum, 42);
+    assert_eq!(x.dolor_sit, Some("dolor".to_string()));
+}
+
+#[test]
+fn parse_from_str_errors() {
+    let err = LoremField::parse_from_str("ipsum", "-1").err().unwrap();
+    assert_eq!(err.field_name(), "ipsum");
+    assert_eq!(
+        err.to_string(),
+        "Field ipsum: invalid digit found in string"
+    );
+
+    let err = LoremField::parse_from_str("amet", "true").err().unwrap();
+    assert_eq!(
+        err,
+        FieldParseError::new("amet".to_string(), "unknown field".to_string())
+    );
+}
+
+#[test]
+fn extend_generic() {
+    let mut builder = GenericBuilder::<String>::default();
+    builder.extend(vec![GenericField::Ipsum("ipsum".to_string())]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Generic {
+            ipsum: "ipsum".to_string(),
+        }
+    );
+}
+
+#[test]
+fn extend_unusual_names() {
+    let mut builder = UnusualBuilder::default();
+    builder.extend(vec![
+        UnusualField::Field1(1),
+        UnusualField::Field(2),
+        UnusualField::FieldSelf(3),
+        UnusualField::Type(4),
+    ]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Unusual {
+            _1: 1,
+            __: 2,
+            self_: 3,
+            r#type: 4,
+        }
+    );
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_from_parts, build_fn(validate = "Self::validate"))]
+struct Lorem {
+    #[builder(setter(into))]
+    name: String,
+    port: u16,
+    #[builder(default = "true")]
+    secure: bool,
+    #[builder(setter(strip_option))]
+    timeout: Option<u32>,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.port {
+            Some(0) => Err("port must not be 0".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_from_parts, pattern = "owned")]
+struct Ipsum<T: Clone> {
+    value: T,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_from_parts, default)]
+struct Dolor {
+    sit: u32,
+}
+
+impl Default for Dolor {
+    fn default() -> Self {
+        Dolor { sit: 7 }
+    }
+}
+
+#[test]
+fn from_required_fields() {
+    let x = Lorem::from(("localhost".to_string(), 8080, 30));
+
+    assert_eq!(
+        x,
+        Lorem {
+            name: "localhost".to_string(),
+            port: 8080,
+            secure: true,
+            timeout: Some(30),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "port must not be 0")]
+fn from_invalid_parts() {
+    let _ = Lorem::from(("localhost".to_string(), 0, 30));
+}
+
+#[test]
+fn from_generic_parts() {
+    let x: Ipsum<Vec<u8>> = (vec![1, 2],).into();
+
+    assert_eq!(x, Ipsum { value: vec![1, 2] });
+}
+
+#[test]
+fn from_no_parts() {
+    assert_eq!(Dolor::from(()), Dolor { sit: 7 });
+}
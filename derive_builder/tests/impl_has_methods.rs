@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_has_methods)]
+struct Lorem {
+    ipsum: String,
+    #[builder(default)]
+    dolor: u32,
+    #[builder(setter(custom), default)]
+    sit: bool,
+    #[builder(setter(skip))]
+    amet: u8,
+    #[builder(field(type = "Vec<u8>", build = "self.consectetur.clone()"))]
+    consectetur: Vec<u8>,
+}
+
+impl LoremBuilder {
+    fn sit(&mut self) -> &mut Self {
+        self.sit = Some(true);
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", impl_has_methods(prefix = "is_set_"))]
+struct Ipsum {
+    #[builder(setter(strip_option), default)]
+    dolor: Option<u32>,
+}
+
+#[test]
+fn unset_fields() {
+    let builder = LoremBuilder::default();
+
+    assert!(!builder.has_required_ipsum());
+    assert!(!builder.has_dolor());
+    assert!(!builder.has_sit());
+}
+
+#[test]
+fn set_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum("ipsum".to_string()).dolor(0).sit();
+
+    assert!(builder.has_required_ipsum());
+    assert!(builder.has_dolor());
+    assert!(builder.has_sit());
+}
+
+#[test]
+fn custom_prefix() {
+    let builder = IpsumBuilder::default();
+    assert!(!builder.is_set_dolor());
+
+    let builder = builder.dolor(1);
+    assert!(builder.is_set_dolor());
+}
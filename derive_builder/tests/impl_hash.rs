@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Builder)]
+#[builder(impl_hash, derive(PartialEq, Eq))]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_hash, derive(PartialEq, Eq))]
+struct Generic<T> {
+    value: T,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn equal_builders_hash_equal() {
+    let mut a = LoremBuilder::default();
+    a.ipsum(1).dolor("dolor".to_string());
+    let mut b = LoremBuilder::default();
+    b.ipsum(1).dolor("dolor".to_string());
+
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn unset_field_hashes_differently() {
+    let mut a = LoremBuilder::default();
+    a.ipsum(1);
+    let mut b = LoremBuilder::default();
+    b.ipsum(1).dolor(String::new());
+
+    assert_ne!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn builders_in_set() {
+    let mut set = HashSet::new();
+    set.insert(LoremBuilder::default().ipsum(1).clone());
+    set.insert(LoremBuilder::default().ipsum(1).clone());
+    set.insert(LoremBuilder::default().ipsum(2).clone());
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(LoremBuilder::default().ipsum(2)));
+}
+
+#[test]
+fn generic_builder() {
+    let mut set = HashSet::new();
+    set.insert(GenericBuilder::default().value(1u8).clone());
+    set.insert(GenericBuilder::default().value(1u8).clone());
+
+    assert_eq!(set.len(), 1);
+}
@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_parts)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into))]
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_parts, lazy_build)]
+struct Generic<T: Clone> {
+    value: T,
+}
+
+#[test]
+fn into_parts() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(1);
+
+    let LoremBuilderParts(ipsum, dolor) = builder.into_parts();
+    assert_eq!(ipsum, Some(1));
+    assert_eq!(dolor, None);
+}
+
+#[test]
+fn from_parts() {
+    let parts = LoremBuilderParts(Some(1), Some("dolor".to_string()));
+    let x = LoremBuilder::from_parts(parts).build().unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+            sit: false,
+        }
+    );
+}
+
+#[test]
+fn generic_round_trip() {
+    let mut builder = GenericBuilder::default();
+    builder.value("value");
+
+    let mut builder = GenericBuilder::from_parts(builder.into_parts());
+    assert_eq!(*builder.build().unwrap(), Generic { value: "value" });
+}
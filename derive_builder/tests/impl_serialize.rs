@@ -0,0 +1,74 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+extern crate serde;
+extern crate serde_json;
+
+use std::time::Instant;
+
+#[derive(Debug, Clone, Builder)]
+#[builder(impl_serialize, setter(into))]
+#[allow(dead_code)]
+struct Lorem {
+    ipsum: String,
+    #[builder(default)]
+    dolor: Option<u32>,
+    #[builder(field(sensitive))]
+    password: String,
+    #[builder(serialize_skip, default = "Instant::now()")]
+    started: Instant,
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_serialize, field(default))]
+#[allow(dead_code)]
+struct Ipsum<T: Default> {
+    sit: T,
+    #[builder(field(sensitive))]
+    amet: u8,
+}
+
+#[test]
+fn unset_fields_are_skipped() {
+    let builder = LoremBuilder::default();
+
+    assert_eq!(serde_json::to_string(&builder).unwrap(), "{}");
+}
+
+#[test]
+fn set_fields_are_serialized() {
+    let mut builder = LoremBuilder::default();
+    builder
+        .ipsum("ipsum")
+        .dolor(Some(1))
+        .started(Instant::now());
+
+    assert_eq!(
+        serde_json::to_string(&builder).unwrap(),
+        r#"{"ipsum":"ipsum","dolor":1}"#
+    );
+}
+
+#[test]
+fn sensitive_fields_are_null() {
+    let mut builder = LoremBuilder::default();
+    builder.password("hunter2");
+
+    assert_eq!(
+        serde_json::to_string(&builder).unwrap(),
+        r#"{"password":null}"#
+    );
+}
+
+#[test]
+fn fields_without_option() {
+    let builder = IpsumBuilder::<Vec<u8>>::default();
+
+    assert_eq!(
+        serde_json::to_string(&builder).unwrap(),
+        r#"{"sit":[],"amet":null}"#
+    );
+}
@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Deliberately does not implement `Debug`.
+#[derive(Clone)]
+struct Token(String);
+
+#[derive(Builder)]
+#[builder(impl_snapshot)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(field(sensitive))]
+    token: Token,
+    #[builder(setter(skip))]
+    skipped: bool,
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_snapshot, pattern = "owned")]
+struct Ipsum<T> {
+    value: T,
+    #[builder(setter(lazy = "|| 1"))]
+    r#type: u8,
+}
+
+#[test]
+fn empty_builder() {
+    assert_eq!(
+        LoremBuilder::default().snapshot(),
+        LoremBuilderSnapshot::default()
+    );
+}
+
+#[test]
+fn set_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(42).token(Token("hunter2".to_string()));
+
+    assert_eq!(
+        builder.snapshot(),
+        LoremBuilderSnapshot {
+            ipsum: Some("42".to_string()),
+            dolor: None,
+            token: Some("<redacted>".to_string()),
+        }
+    );
+}
+
+#[test]
+fn display() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(42).dolor("dolor".to_string());
+
+    assert_eq!(
+        builder.snapshot().to_string(),
+        "[LoremBuilder]\nipsum = 42\ndolor = \"dolor\"\n# token = unset"
+    );
+}
+
+#[test]
+fn generic_and_lazy_fields() {
+    let builder = IpsumBuilder::default().value(vec![1, 2]).r#type(3);
+    let snapshot = builder.snapshot();
+
+    assert_eq!(snapshot.value, Some("[1, 2]".to_string()));
+    assert_eq!(snapshot.r#type, Some("<set>".to_string()));
+    assert_eq!(
+        snapshot.to_string(),
+        "[IpsumBuilder]\nvalue = [1, 2]\ntype = <set>"
+    );
+}
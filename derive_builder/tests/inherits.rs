@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(inheritable)]
+pub struct Base {
+    #[builder(setter(into))]
+    host: String,
+    #[builder(default = "80")]
+    port: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(pattern = "owned", inheritable)]
+struct OwnedBase {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(inherits = "BaseBuilder")]
+struct Server {
+    base: Base,
+    workers: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "immutable", inherits = "BaseBuilder")]
+struct ImmutableServer {
+    base: Base,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", inherits = "OwnedBaseBuilder")]
+struct Named {
+    base: OwnedBase,
+    #[builder(default)]
+    id: u32,
+}
+
+#[test]
+fn forwards_setters() {
+    let x = ServerBuilder::default()
+        .host("localhost")
+        .port(8080)
+        .workers(4)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Server {
+            base: Base {
+                host: "localhost".to_string(),
+                port: 8080,
+            },
+            workers: 4,
+        }
+    );
+}
+
+#[test]
+fn uses_inherited_defaults() {
+    let x = ServerBuilder::default()
+        .host("localhost")
+        .workers(1)
+        .build()
+        .unwrap();
+
+    assert_eq!(x.base.port, 80);
+}
+
+#[test]
+fn reports_inherited_errors() {
+    let err = ServerBuilder::default().workers(1).build().unwrap_err();
+
+    assert_eq!(err.to_string(), "`host` must be initialized");
+}
+
+#[test]
+fn forwards_from_immutable_builder() {
+    let builder = ImmutableServerBuilder::default().host("localhost");
+    let x = builder.port(8080).build().unwrap();
+
+    assert_eq!(x.base.port, 8080);
+    assert_eq!(builder.build().unwrap().base.port, 80);
+}
+
+#[test]
+fn forwards_to_owned_builder() {
+    let x = NamedBuilder::default()
+        .name("lorem".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Named {
+            base: OwnedBase {
+                name: "lorem".to_string()
+            },
+            id: 0,
+        }
+    );
+}
+
+#[test]
+fn inherits_builder_methods() {
+    fn target<B: derive_builder::BuilderMethods<Target = Base>>(_: &B) {}
+
+    target(&BaseBuilder::default());
+}
@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(into_builder)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into))]
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+    #[builder(setter(strip_option), default)]
+    amet: Option<u8>,
+    #[builder(field(type = "Vec<u8>", build = "self.consectetur.clone()"))]
+    consectetur: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(into_builder, lazy_build)]
+struct Generic<T: Clone> {
+    value: T,
+}
+
+#[test]
+fn round_trip() {
+    let lorem = LoremBuilder::default()
+        .ipsum(1)
+        .dolor("dolor")
+        .amet(2)
+        .consectetur(vec![3])
+        .build()
+        .unwrap();
+
+    let mut builder = LoremBuilder::from(lorem);
+    builder.ipsum(4);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            ipsum: 4,
+            dolor: "dolor".to_string(),
+            sit: false,
+            amet: Some(2),
+            consectetur: vec![3],
+        }
+    );
+}
+
+#[test]
+fn skipped_fields_are_reset() {
+    let lorem = Lorem {
+        ipsum: 1,
+        dolor: "dolor".to_string(),
+        sit: true,
+        amet: None,
+        consectetur: vec![],
+    };
+
+    assert!(!LoremBuilder::from(lorem).build().unwrap().sit);
+}
+
+#[test]
+fn generic_round_trip() {
+    let mut builder = GenericBuilder::from(Generic { value: "ipsum" });
+    builder.value("dolor");
+
+    assert_eq!(*builder.build().unwrap(), Generic { value: "dolor" });
+}
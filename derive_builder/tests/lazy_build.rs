@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(lazy_build, build_fn(validate = "Self::validate"))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default, setter(each = "dolor_item"))]
+    dolor: Vec<u32>,
+    #[builder(default, setter(custom))]
+    sit: u32,
+}
+
+impl LoremBuilder {
+    fn sit(&mut self, value: u32) -> &mut Self {
+        self.sit = Some(value);
+        self.clear_build_cache();
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self.ipsum {
+            Some(0) => Err("ipsum must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(lazy_build, pattern = "immutable")]
+struct Generic<T: Clone> {
+    value: T,
+}
+
+#[test]
+fn returns_cached_value() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(1);
+
+    let first = builder.build().unwrap();
+    let second = builder.build().unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(
+        *first,
+        Lorem {
+            ipsum: 1,
+            dolor: vec![],
+            sit: 0,
+        }
+    );
+}
+
+#[test]
+fn setters_invalidate_cache() {
+    let mut builder = LoremBuilder::default();
+    let first = builder.ipsum(1).build().unwrap();
+
+    let second = builder.ipsum(2).build().unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+    assert_eq!(second.ipsum, 2);
+
+    let third = builder.dolor_item(3).build().unwrap();
+    assert!(!Arc::ptr_eq(&second, &third));
+    assert_eq!(third.dolor, vec![3]);
+}
+
+#[test]
+fn custom_setters_clear_cache() {
+    let mut builder = LoremBuilder::default();
+    let first = builder.ipsum(1).build().unwrap();
+
+    let second = builder.sit(2).build().unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+    assert_eq!(second.sit, 2);
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let mut builder = LoremBuilder::default();
+    assert_eq!(
+        &builder.build().unwrap_err().to_string(),
+        "`ipsum` must be initialized"
+    );
+    assert_eq!(
+        &builder.ipsum(0).build().unwrap_err().to_string(),
+        "ipsum must not be zero"
+    );
+    assert_eq!(builder.ipsum(4).build().unwrap().ipsum, 4);
+}
+
+#[test]
+fn generic_immutable() {
+    let mut builder = GenericBuilder::default().value("a".to_string());
+    let first = builder.build().unwrap();
+    assert!(Arc::ptr_eq(&first, &builder.build().unwrap()));
+
+    let mut builder = builder.value("b".to_string());
+    assert_eq!(builder.build().unwrap().value, "b");
+}
@@ -20,3 +20,42 @@ fn builder_test() {
 
     assert_eq!(x, Lorem { ipsum: "ipsum" });
 }
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Dolor<'a, 'b: 'a> {
+    sit: &'a str,
+    #[builder(setter(strip_option), default)]
+    amet: Option<&'b [u8]>,
+    #[builder(default)]
+    consectetur: Vec<&'a str>,
+}
+
+#[test]
+fn borrows_local_values() {
+    let name = String::from("ipsum");
+    let x = LoremBuilder::default().ipsum(&name).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum" });
+}
+
+#[test]
+fn several_lifetimes() {
+    let sit = String::from("sit");
+    let amet = vec![1, 2];
+    let x = DolorBuilder::default()
+        .sit(&sit)
+        .amet(&amet)
+        .consectetur(vec![&sit[..1]])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Dolor {
+            sit: "sit",
+            amet: Some(&[1, 2]),
+            consectetur: vec!["s"],
+        }
+    );
+}
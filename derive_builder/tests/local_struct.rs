@@ -0,0 +1,91 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[test]
+fn struct_in_function() {
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(
+        impl_display,
+        impl_hash,
+        impl_diff,
+        impl_parts,
+        into_builder,
+        field_names
+    )]
+    struct Lorem {
+        ipsum: u32,
+        #[builder(setter(into), default = "\"dolor\".to_string()")]
+        dolor: String,
+        #[builder(setter(each(name = "sit_item")), default)]
+        sit: Vec<u8>,
+    }
+
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .sit_item(2)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+            sit: vec![2],
+        }
+    );
+    assert_eq!(
+        LoremBuilder::default().build().unwrap_err().to_string(),
+        "`ipsum` must be initialized"
+    );
+}
+
+#[test]
+fn generic_struct_in_function() {
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(pattern = "owned")]
+    struct Generic<T> {
+        value: T,
+    }
+
+    let x = GenericBuilder::default().value("value").build().unwrap();
+    assert_eq!(x, Generic { value: "value" });
+}
+
+#[test]
+fn struct_in_function_shadowing_prelude() {
+    #[allow(dead_code)]
+    struct Some;
+    #[allow(dead_code)]
+    struct None;
+    #[allow(dead_code)]
+    struct Ok;
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(try_setter, setter(into))]
+    struct Lorem {
+        ipsum: u8,
+        #[builder(default)]
+        dolor: Option<String>,
+        #[builder(setter(each(name = "sit_item")), default)]
+        sit: Vec<u8>,
+    }
+
+    let x = LoremBuilder::default()
+        .try_ipsum(1u16)
+        .unwrap()
+        .sit_item(2)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: Option::None,
+            sit: vec![2],
+        }
+    );
+}
@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default)]
+    dolor: u32,
+    sit: String,
+    amet: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(error(first_only)))]
+struct FirstOnly {
+    ipsum: u32,
+    sit: String,
+}
+
+#[test]
+fn reports_all_missing_fields() {
+    let error = LoremBuilder::default().ipsum(1).build().unwrap_err();
+    match error {
+        LoremBuilderError::UninitializedFields(ref fields) => {
+            assert_eq!(fields, &["sit", "amet"]);
+        }
+        ref other => panic!("unexpected error {:?}", other),
+    }
+    assert_eq!(&error.to_string(), "`sit`, `amet` must be initialized");
+}
+
+#[test]
+fn builds_when_all_set() {
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .sit("sit".to_string())
+        .amet(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: 0,
+            sit: "sit".to_string(),
+            amet: true,
+        }
+    );
+}
+
+#[test]
+fn first_only_short_circuits() {
+    let error = FirstOnlyBuilder::default().build().unwrap_err();
+    match error {
+        FirstOnlyBuilderError::UninitializedField(field) => assert_eq!(field, "ipsum"),
+        ref other => panic!("unexpected error {:?}", other),
+    }
+}
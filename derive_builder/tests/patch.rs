@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(patch)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into, strip_option), default)]
+    dolor_sit: Option<String>,
+    #[builder(setter(skip))]
+    amet: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(patch, impl_extend, pattern = "owned")]
+struct Owned<T: Clone> {
+    ipsum: T,
+    #[builder(pattern = "immutable")]
+    dolor: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(patch, impl_extend)]
+struct Generic<T, U: Default> {
+    ipsum: T,
+    #[builder(setter(skip))]
+    amet: U,
+}
+
+#[test]
+fn apply_patches() {
+    let mut builder = LoremBuilder::default();
+    builder.apply(vec![
+        LoremPatch::Ipsum(1),
+        LoremPatch::DolorSit("dolor".to_string()),
+        LoremPatch::Ipsum(2),
+    ]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            ipsum: 2,
+            dolor_sit: Some("dolor".to_string()),
+            amet: false,
+        }
+    );
+}
+
+#[test]
+fn apply_patches_to_owned_builder() {
+    let mut builder = OwnedBuilder::default();
+    builder.apply(vec![OwnedPatch::Ipsum("ipsum"), OwnedPatch::Dolor(vec![1])]);
+    builder.extend(vec![OwnedField::Dolor(vec![2])]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Owned {
+            ipsum: "ipsum",
+            dolor: vec![2],
+        }
+    );
+}
+
+#[test]
+fn apply_patches_to_generic_builder() {
+    let mut builder = GenericBuilder::<String, bool>::default();
+    builder.apply(vec![GenericPatch::Ipsum("ipsum".to_string())]);
+    builder.extend(vec![GenericField::Ipsum("lorem".to_string())]);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Generic {
+            ipsum: "lorem".to_string(),
+            amet: false,
+        }
+    );
+}
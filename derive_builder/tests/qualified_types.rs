@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+pub trait Shape {
+    type Out;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Square;
+
+impl Shape for Square {
+    type Out = u32;
+}
+
+impl Shape for Lorem {
+    type Out = String;
+}
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+pub struct Lorem {
+    #[builder(setter(into))]
+    ipsum: <Square as Shape>::Out,
+    #[builder(setter(into))]
+    dolor: <Self as Shape>::Out,
+    #[builder(default)]
+    sit: Vec<Self>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+pub struct Amet<T: Shape> {
+    consectetur: <T as Shape>::Out,
+    adipiscing: T::Out,
+    #[builder(setter(strip_option), default)]
+    elit: Option<Box<Self>>,
+}
+
+#[test]
+fn qualified_path_types() {
+    let x = LoremBuilder::default()
+        .ipsum(1u32)
+        .dolor("dolor")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+            sit: vec![],
+        }
+    );
+}
+
+#[test]
+fn self_names_the_struct() {
+    let inner = LoremBuilder::default()
+        .ipsum(1u32)
+        .dolor("inner")
+        .build()
+        .unwrap();
+    let x = LoremBuilder::default()
+        .ipsum(2u32)
+        .dolor("outer")
+        .sit(vec![inner.clone()])
+        .build()
+        .unwrap();
+
+    assert_eq!(x.sit, vec![inner]);
+}
+
+#[test]
+fn associated_types_of_type_parameters() {
+    let inner = AmetBuilder::<Square>::default()
+        .consectetur(1)
+        .adipiscing(2)
+        .build()
+        .unwrap();
+    let x = AmetBuilder::default()
+        .consectetur(3)
+        .adipiscing(4)
+        .elit(Box::new(inner))
+        .build()
+        .unwrap();
+
+    assert_eq!(x.consectetur, 3);
+    assert_eq!(x.adipiscing, 4);
+    assert_eq!(
+        x.elit,
+        Some(Box::new(Amet {
+            consectetur: 1,
+            adipiscing: 2,
+            elit: None,
+        }))
+    );
+}
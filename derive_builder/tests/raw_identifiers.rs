@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder, Clone)]
+#[builder(try_setter)]
+struct Lorem {
+    r#type: String,
+    r#match: u8,
+    #[builder(setter(prefix = "with"))]
+    r#fn: bool,
+}
+
+#[test]
+fn raw_identifier_setters() {
+    let x = LoremBuilder::default()
+        .r#type("ipsum".to_string())
+        .try_match(3u16)
+        .unwrap()
+        .with_fn(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            r#type: "ipsum".to_string(),
+            r#match: 3,
+            r#fn: true,
+        }
+    );
+}
+
+#[test]
+fn raw_identifier_error() {
+    let error = LoremBuilder::default().build().unwrap_err();
+    assert_eq!(
+        &error.to_string(),
+        "`type`, `match`, `fn` must be initialized"
+    );
+}
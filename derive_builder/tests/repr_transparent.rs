@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate derive_builder;
+
+use std::mem::{align_of, size_of};
+
+#[derive(Debug, Clone)]
+struct Bar([u8; 3]);
+
+#[derive(Debug, Builder)]
+struct Lorem {
+    ipsum: Bar,
+}
+
+#[derive(Debug, Builder)]
+struct Dolor<T> {
+    sit: T,
+    #[builder(setter(skip))]
+    amet: u64,
+}
+
+#[derive(Debug, Builder)]
+struct Consectetur {
+    adipiscing: u8,
+    elit: u8,
+}
+
+#[test]
+fn single_field_builder_has_layout_of_field() {
+    assert_eq!(size_of::<LoremBuilder>(), size_of::<Option<Bar>>());
+    assert_eq!(align_of::<LoremBuilder>(), align_of::<Option<Bar>>());
+    assert_eq!(size_of::<DolorBuilder<u16>>(), size_of::<Option<u16>>());
+    assert_eq!(align_of::<DolorBuilder<u16>>(), align_of::<Option<u16>>());
+}
+
+#[test]
+fn multi_field_builder() {
+    let x = ConsecteturBuilder::default()
+        .adipiscing(1)
+        .elit(2)
+        .build()
+        .unwrap();
+    assert_eq!((x.adipiscing, x.elit), (1, 2));
+}
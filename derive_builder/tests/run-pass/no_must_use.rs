@@ -0,0 +1,15 @@
+#![deny(unused_must_use)]
+
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(no_must_use, pattern = "owned")]
+pub struct Lorem {
+    ipsum: u32,
+}
+
+fn main() {
+    // Without `no_must_use`, discarding the returned builder would be denied.
+    LoremBuilder::default().ipsum(42);
+}
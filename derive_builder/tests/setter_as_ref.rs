@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(into))]
+struct Lorem {
+    #[builder(setter(as_ref))]
+    ipsum: String,
+    #[builder(setter(as_ref, name = "set_dolor"))]
+    dolor: std::string::String,
+    sit: u64,
+}
+
+#[test]
+fn accepts_str() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum")
+        .set_dolor("dolor")
+        .sit(1u32)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: "dolor".to_string(),
+            sit: 1,
+        }
+    );
+}
+
+#[test]
+fn accepts_string_and_string_ref() {
+    let borrowed = String::from("ipsum");
+    let x = LoremBuilder::default()
+        .ipsum(&borrowed)
+        .set_dolor(String::from("dolor"))
+        .sit(1u32)
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, borrowed);
+    assert_eq!(x.dolor, "dolor");
+}
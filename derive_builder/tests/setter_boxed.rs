@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(boxed))]
+    ipsum: Box<u32>,
+    #[builder(setter(boxed))]
+    dolor: Rc<String>,
+    #[builder(setter(boxed))]
+    sit: std::sync::Arc<Vec<u8>>,
+    #[builder(setter(boxed, strip_option), default)]
+    amet: Option<Box<u64>>,
+}
+
+#[test]
+fn wraps_values() {
+    let x = LoremBuilder::default()
+        .ipsum(1u8)
+        .dolor("dolor")
+        .sit(vec![1, 2])
+        .amet(7u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: Box::new(1),
+            dolor: Rc::new("dolor".to_string()),
+            sit: Arc::new(vec![1, 2]),
+            amet: Some(Box::new(7)),
+        }
+    );
+}
+
+#[test]
+fn optional_pointer_defaults() {
+    let x = LoremBuilder::default()
+        .ipsum(1u32)
+        .dolor(String::new())
+        .sit(Vec::new())
+        .build()
+        .unwrap();
+
+    assert_eq!(x.amet, None);
+}
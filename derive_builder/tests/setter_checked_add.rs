@@ -0,0 +1,73 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(derive(Debug))]
+struct Lorem {
+    #[builder(setter(checked_add))]
+    ipsum: u8,
+    #[builder(setter(checked_add), default)]
+    dolor: i64,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", field(default))]
+struct Ipsum {
+    #[builder(setter(checked_add))]
+    r#type: u8,
+}
+
+#[test]
+fn adding_to_unset_field_sets_it() {
+    let mut builder = LoremBuilder::default();
+    builder.try_add_ipsum(2).unwrap();
+    builder.saturating_add_dolor(-3);
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            ipsum: 2,
+            dolor: -3
+        }
+    );
+}
+
+#[test]
+fn adding_composes_with_setter() {
+    let mut builder = LoremBuilder::default();
+    builder
+        .ipsum(1)
+        .try_add_ipsum(2)
+        .unwrap()
+        .try_add_ipsum(3)
+        .unwrap();
+
+    assert_eq!(builder.build().unwrap().ipsum, 6);
+}
+
+#[test]
+fn overflow() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(250);
+
+    let error = builder.try_add_ipsum(10).unwrap_err();
+    assert_eq!(error.field_name(), "ipsum");
+    assert_eq!(error.to_string(), "Adding to `ipsum` overflowed");
+    assert_eq!(builder.build().unwrap().ipsum, 250);
+
+    builder.saturating_add_ipsum(10);
+    assert_eq!(builder.build().unwrap().ipsum, 255);
+}
+
+#[test]
+fn owned_builder_without_options() {
+    let x = IpsumBuilder::default()
+        .try_add_type(1)
+        .unwrap()
+        .saturating_add_type(255)
+        .build();
+
+    assert_eq!(x, Ipsum { r#type: 255 });
+}
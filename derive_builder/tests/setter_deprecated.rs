@@ -0,0 +1,30 @@
+#![allow(deprecated)]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(try_setter, setter(prefix = "with", keep_bare))]
+struct Lorem {
+    #[builder(setter(deprecated = "use `dolor` instead"))]
+    ipsum: u32,
+    #[builder(default)]
+    dolor: u32,
+}
+
+#[test]
+fn deprecated_setters_still_set_the_field() {
+    let x = LoremBuilder::default().with_ipsum(1).build().unwrap();
+    let y = LoremBuilder::default().ipsum(1).build().unwrap();
+    let z = LoremBuilder::default()
+        .try_with_ipsum(1u8)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 1, dolor: 0 });
+    assert_eq!(x, y);
+    assert_eq!(x, z);
+}
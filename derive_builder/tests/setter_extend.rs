@@ -45,6 +45,41 @@ struct Dolor {
     bazes: HashMap<String, i32>,
 }
 
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Amet {
+    #[builder(setter(each(name = "add_item", remove = "remove_item")))]
+    items: Vec<String>,
+    #[builder(default, setter(strip_option, each(remove = "remove_tag")))]
+    tags: Option<Vec<u8>>,
+}
+
+fn lowercase(s: &str) -> String {
+    s.to_lowercase()
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Consectetur {
+    #[builder(setter(each(name = "feature", into, dedup)))]
+    features: Vec<String>,
+    #[builder(default, setter(strip_option, each(name = "tag", dedup = "lowercase")))]
+    tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Elit {
+    #[builder(default, setter(strip_option, each(name = "arg", into)))]
+    args: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(field(default))]
+struct Adipiscing {
+    #[builder(setter(each(name = "dependency", dedup)))]
+    dependencies: Vec<u8>,
+}
+
 #[test]
 fn extend_field() {
     let x = LoremBuilder::default()
@@ -158,3 +193,103 @@ fn extend_field_enabled() {
     let x = SitBuilder::default().foo(1).foo(2).build().unwrap();
     assert_eq!(x, Sit { foos: vec![1, 2] });
 }
+
+#[test]
+fn remove_items() {
+    let x = AmetBuilder::default()
+        .add_item("a".into())
+        .add_item("b".into())
+        .add_item("a".into())
+        .remove_item(&"a".to_string())
+        .tags(vec![1, 2, 1])
+        .remove_tag(&1)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Amet {
+            items: vec!["b".into()],
+            tags: Some(vec![2]),
+        }
+    );
+}
+
+#[test]
+fn remove_from_uninitialized_field() {
+    let x = AmetBuilder::default()
+        .remove_item(&"a".to_string())
+        .remove_tag(&1)
+        .items(vec![])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Amet {
+            items: vec![],
+            tags: None,
+        }
+    );
+}
+
+#[test]
+fn extend_optional_field_into() {
+    let x = ElitBuilder::default()
+        .arg("--verbose")
+        .arg(String::from("--quiet"))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Elit {
+            args: Some(vec!["--verbose".into(), "--quiet".into()]),
+        }
+    );
+}
+
+#[test]
+fn dedup_skips_present_elements() {
+    let x = ConsecteturBuilder::default()
+        .feature("a")
+        .feature("b")
+        .feature("a")
+        .tag("Foo".into())
+        .tag("foo".into())
+        .tag("bar".into())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Consectetur {
+            features: vec!["a".into(), "b".into()],
+            tags: Some(vec!["Foo".into(), "bar".into()]),
+        }
+    );
+}
+
+#[test]
+fn dedup_does_not_apply_to_collection_setter() {
+    let x = ConsecteturBuilder::default()
+        .features(vec!["a".into(), "a".into()])
+        .feature("a")
+        .build()
+        .unwrap();
+
+    assert_eq!(x.features, vec!["a".to_string(), "a".to_string()]);
+}
+
+#[test]
+fn dedup_on_builder_field_without_option() {
+    let mut builder = AdipiscingBuilder::default();
+    builder.dependency(1).dependency(2).dependency(1);
+
+    assert_eq!(
+        builder.build(),
+        Adipiscing {
+            dependencies: vec![1, 2],
+        }
+    );
+}
@@ -15,6 +15,15 @@ struct Ipsum {
     foo: u32,
 }
 
+#[derive(Debug, PartialEq, Builder, Clone)]
+struct Dolor<T, U: Clone> {
+    #[builder(setter(into))]
+    value: T,
+    #[builder(setter(into))]
+    values: Vec<U>,
+    count: usize,
+}
+
 #[test]
 fn generic_field() {
     let x = LoremBuilder::default().foo("foo").build().unwrap();
@@ -33,3 +42,36 @@ fn generic_struct() {
 
     assert_eq!(x, Ipsum { foo: 42u32 });
 }
+
+#[test]
+fn generic_type_parameters() {
+    let x: Dolor<String, u64> = DolorBuilder::default()
+        .value("sit")
+        .values(vec![1u64, 2])
+        .count(2)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Dolor {
+            value: "sit".to_string(),
+            values: vec![1, 2],
+            count: 2,
+        }
+    );
+}
+
+#[test]
+fn generic_type_parameter_from_slice() {
+    let values: &[u8] = &[3, 4];
+    let x = DolorBuilder::<u64, u8>::default()
+        .value(7u32)
+        .values(values)
+        .count(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(x.value, 7);
+    assert_eq!(x.values, vec![3, 4]);
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::BTreeSet;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(into_iter, each = "ipsum_item"))]
+    ipsum: Vec<String>,
+    #[builder(setter(into_iter), default)]
+    dolor: BTreeSet<u64>,
+    #[builder(setter(into_iter, strip_option), default)]
+    sit: Option<Vec<u32>>,
+}
+
+#[test]
+fn collects_converted_items() {
+    let x = LoremBuilder::default()
+        .ipsum(["a", "b"])
+        .dolor(vec![3u8, 1, 2])
+        .sit(vec![7u16])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: vec!["a".to_string(), "b".to_string()],
+            dolor: vec![1, 2, 3].into_iter().collect(),
+            sit: Some(vec![7]),
+        }
+    );
+}
+
+#[test]
+fn accepts_iterators() {
+    let x = LoremBuilder::default()
+        .ipsum((1..=3).map(|i| i.to_string()))
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, vec!["1", "2", "3"]);
+    assert_eq!(x.sit, None);
+}
+
+#[test]
+fn accepts_slices() {
+    let names = ["a".to_string(), "b".to_string()];
+    let x = LoremBuilder::default().ipsum(&names[..]).build().unwrap();
+
+    assert_eq!(x.ipsum, names);
+}
+
+#[test]
+fn each_adds_to_replaced_collection() {
+    let x = LoremBuilder::default()
+        .ipsum_item("a".to_string())
+        .ipsum(vec!["b"])
+        .ipsum_item("c".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, vec!["b", "c"]);
+}
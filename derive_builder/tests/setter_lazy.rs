@@ -0,0 +1,131 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn expensive_default() -> String {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    "computed".to_string()
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", impl_diff, impl_display, into_builder)]
+struct Lorem {
+    #[builder(setter(into, lazy = "|| \"computed\".to_string()"))]
+    ipsum: String,
+    #[builder(setter(strip_option, lazy = "|| Some(42)"))]
+    dolor: Option<u32>,
+    sit: bool,
+}
+
+#[test]
+fn lazy_default_is_called_by_build() {
+    let x = LoremBuilder::default().sit(true).build().unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "computed".to_string(),
+            dolor: Some(42),
+            sit: true,
+        }
+    );
+}
+
+#[test]
+fn set_value_skips_lazy_default() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum")
+        .dolor(1)
+        .sit(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: Some(1),
+            sit: false,
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Ipsum {
+    #[builder(setter(lazy = "expensive_default"))]
+    dolor: String,
+}
+
+#[test]
+fn lazy_default_is_only_called_if_unset() {
+    let builder = IpsumBuilder::default();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    let x = builder.build().unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(x.dolor, "computed");
+
+    let y = IpsumBuilder::default()
+        .dolor("dolor".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(y.dolor, "dolor");
+}
+
+#[test]
+fn lazy_field_is_not_required() {
+    let error = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(&error.to_string(), "`sit` must be initialized");
+}
+
+#[test]
+fn lazy_field_in_other_features() {
+    let set = LoremBuilder::default().ipsum("ipsum").sit(true);
+    assert_eq!(
+        set.to_string(),
+        "LoremBuilder { ipsum: set, dolor: unset, sit: true }"
+    );
+    assert!(set.diff(&LoremBuilder::default().sit(true)).ipsum.is_some());
+
+    let x = Lorem {
+        ipsum: "ipsum".to_string(),
+        dolor: None,
+        sit: true,
+    };
+    let y = LoremBuilder::from(x).build().unwrap();
+    assert_eq!(y.ipsum, "ipsum");
+    assert_eq!(y.dolor, None);
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", setter(into))]
+struct Amet {
+    #[builder(setter(lazy = "|| vec![1, 2]"))]
+    consectetur: Vec<u8>,
+}
+
+#[test]
+fn struct_level_setter_options() {
+    assert_eq!(
+        AmetBuilder::default().build().unwrap(),
+        Amet {
+            consectetur: vec![1, 2],
+        }
+    );
+    assert_eq!(
+        AmetBuilder::default()
+            .consectetur([3u8])
+            .build()
+            .unwrap()
+            .consectetur,
+        vec![3]
+    );
+}
@@ -0,0 +1,88 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+mod units {
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct Kilograms(pub f32);
+
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct Tagged<T>(pub T);
+
+    #[derive(Debug, PartialEq, Clone, Newtype)]
+    pub struct Meters(f64);
+
+    #[derive(Debug, PartialEq, Clone, Newtype)]
+    pub struct Labelled<T: Clone>(T);
+
+    impl Meters {
+        pub fn get(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl<T: Clone> Labelled<T> {
+        pub fn get(&self) -> T {
+            self.0.clone()
+        }
+    }
+}
+
+use units::{Kilograms, Labelled, Meters, Tagged};
+
+#[derive(Debug, PartialEq, Builder)]
+struct Parcel {
+    #[builder(setter(newtype_inner = "f32"))]
+    weight: Kilograms,
+    #[builder(setter(newtype_inner = "String"))]
+    label: units::Tagged<String>,
+}
+
+#[test]
+fn wraps_inner_value() {
+    let x = ParcelBuilder::default()
+        .weight(2.5f32)
+        .label("fragile")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Parcel {
+            weight: Kilograms(2.5),
+            label: Tagged("fragile".to_string()),
+        }
+    );
+}
+
+#[test]
+fn accepts_into_inner() {
+    let x = ParcelBuilder::default()
+        .weight(3u8)
+        .label(String::from("heavy"))
+        .build()
+        .unwrap();
+
+    assert_eq!(x.weight, Kilograms(3.0));
+}
+
+#[derive(Debug, Builder)]
+struct Route<T: Clone> {
+    #[builder(setter(newtype_inner))]
+    length: Meters,
+    #[builder(setter(newtype_inner))]
+    name: Labelled<T>,
+}
+
+#[test]
+fn derived_inner_type() {
+    let x: Route<String> = RouteBuilder::default()
+        .length(1200u32)
+        .name("ridge")
+        .build()
+        .unwrap();
+
+    assert_eq!(x.length.get(), 1200.0);
+    assert_eq!(x.name.get(), "ridge");
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+fn collapse_spaces(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(normalize = "trim"))]
+    trimmed: String,
+    #[builder(setter(into, normalize = "lowercase"))]
+    lower: String,
+    #[builder(setter(as_ref, normalize = "uppercase"))]
+    upper: String,
+    #[builder(setter(into, normalize = "collapse_spaces"))]
+    custom: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(try_setter, setter(into))]
+struct Ipsum {
+    #[builder(setter(normalize = "trim"))]
+    name: String,
+}
+
+#[test]
+fn normalizes_values() {
+    let x = LoremBuilder::default()
+        .trimmed("  trimmed\n".to_string())
+        .lower("LoWeR")
+        .upper("upper")
+        .custom(" a   b  c ")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            trimmed: "trimmed".to_string(),
+            lower: "lower".to_string(),
+            upper: "UPPER".to_string(),
+            custom: "a b c".to_string(),
+        }
+    );
+}
+
+#[test]
+fn try_setter_normalizes_values() {
+    let x = IpsumBuilder::default()
+        .try_name(" name ")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Ipsum {
+            name: "name".to_string(),
+        }
+    );
+}
@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use derive_builder::AlreadySetError;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(once))]
+    ipsum: u32,
+    #[builder(setter(once(panic), into))]
+    dolor: String,
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", derive(Clone))]
+struct Owned {
+    #[builder(setter(once, strip_option))]
+    ipsum: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(try_setter)]
+struct Ipsum {
+    #[builder(setter(once(panic)))]
+    dolor: u8,
+}
+
+#[test]
+fn first_set_succeeds() {
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .unwrap()
+        .dolor("a")
+        .sit(true)
+        .sit(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 1,
+            dolor: "a".to_string(),
+            sit: false,
+        }
+    );
+}
+
+#[test]
+fn second_set_errors() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(1).unwrap();
+
+    let error: AlreadySetError = builder.ipsum(2).err().unwrap();
+    assert_eq!(error.field_name(), "ipsum");
+    assert_eq!(&error.to_string(), "`ipsum` is already set");
+
+    let x = builder.dolor("a").sit(true).build().unwrap();
+    assert_eq!(x.ipsum, 1);
+}
+
+#[test]
+#[should_panic(expected = "`dolor` is already set")]
+fn second_set_panics() {
+    let mut builder = LoremBuilder::default();
+    builder.dolor("a").dolor("b");
+}
+
+#[test]
+fn owned_pattern() {
+    let builder = OwnedBuilder::default().ipsum(1).unwrap();
+    assert!(builder.clone().ipsum(2).is_err());
+    assert_eq!(builder.build().unwrap(), Owned { ipsum: Some(1) });
+}
+
+#[test]
+#[should_panic(expected = "`dolor` is already set")]
+fn try_setter_panics_on_second_set() {
+    let mut builder = IpsumBuilder::default();
+    builder.try_dolor(1u32).unwrap();
+    let _ = builder.try_dolor(2u32);
+}
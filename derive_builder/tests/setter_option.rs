@@ -0,0 +1,88 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(option_setter, into))]
+    ipsum: Option<String>,
+    #[builder(setter(option_setter(prefix = "or_")), default = "Some(7)")]
+    dolor: Option<u32>,
+    #[builder(setter(option_setter, strip_option = false))]
+    sit: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Ipsum {
+    #[builder(setter(option_setter))]
+    amet: Option<Vec<u8>>,
+}
+
+#[test]
+fn primary_setter_strips_option() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum")
+        .dolor(1)
+        .sit(Some(2))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: Some("ipsum".to_string()),
+            dolor: Some(1),
+            sit: Some(2),
+        }
+    );
+}
+
+#[test]
+fn maybe_setter_passes_option_through() {
+    let x = LoremBuilder::default()
+        .maybe_ipsum(Some("ipsum".to_string()))
+        .or_dolor(None)
+        .maybe_sit(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: Some("ipsum".to_string()),
+            dolor: None,
+            sit: None,
+        }
+    );
+}
+
+#[test]
+fn maybe_setter_clears_field() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum")
+        .maybe_ipsum(None)
+        .sit(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, None);
+    assert_eq!(x.dolor, Some(7));
+}
+
+#[test]
+fn owned_pattern() {
+    let x = IpsumBuilder::default()
+        .amet(vec![1])
+        .maybe_amet(Some(vec![2, 3]))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Ipsum {
+            amet: Some(vec![2, 3]),
+        }
+    );
+}
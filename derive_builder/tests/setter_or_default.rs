@@ -0,0 +1,63 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Deliberately does not implement `Default`.
+#[derive(Debug, PartialEq, Clone)]
+struct NotDefaultable(u8);
+
+#[derive(Debug, PartialEq, Builder, Clone)]
+struct Lorem {
+    required: NotDefaultable,
+    #[builder(setter(or_default))]
+    ipsum: Vec<u8>,
+    #[builder(setter(skip, or_default))]
+    dolor: String,
+    #[builder(setter(strip_option, or_default))]
+    sit: Option<u32>,
+}
+
+#[test]
+fn falls_back_to_default() {
+    let x = LoremBuilder::default()
+        .required(NotDefaultable(1))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            required: NotDefaultable(1),
+            ipsum: vec![],
+            dolor: String::new(),
+            sit: None,
+        }
+    );
+}
+
+#[test]
+fn uses_set_values() {
+    let x = LoremBuilder::default()
+        .required(NotDefaultable(1))
+        .ipsum(vec![2, 3])
+        .sit(4)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            required: NotDefaultable(1),
+            ipsum: vec![2, 3],
+            dolor: String::new(),
+            sit: Some(4),
+        }
+    );
+}
+
+#[test]
+fn required_field_still_errors() {
+    let error = LoremBuilder::default().build().unwrap_err();
+    assert_eq!(&error.to_string(), "`required` must be initialized");
+}
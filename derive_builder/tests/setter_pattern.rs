@@ -105,3 +105,32 @@ fn owned_override() {
     let new = owned_setter(old, 42);
     assert_eq!(new.owned_override, Some(42));
 }
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "immutable")]
+struct Amet<T> {
+    consectetur: T,
+    adipiscing: u32,
+}
+
+#[test]
+fn immutable_branching() {
+    let base = AmetBuilder::default().consectetur("consectetur".to_string());
+    let a = base.adipiscing(1);
+    let b = base.adipiscing(2);
+
+    // the build method must borrow the builder
+    let build: fn(&AmetBuilder<String>) -> Result<Amet<String>, AmetBuilderError> =
+        AmetBuilder::build;
+
+    assert_eq!(
+        build(&a).unwrap(),
+        Amet {
+            consectetur: "consectetur".to_string(),
+            adipiscing: 1,
+        }
+    );
+    assert_eq!(b.build().unwrap().adipiscing, 2);
+    assert_eq!(a.build().unwrap().adipiscing, 1);
+    assert!(base.build().is_err());
+}
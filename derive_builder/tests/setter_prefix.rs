@@ -11,6 +11,15 @@ struct Lorem {
     pub dolor: &'static str,
 }
 
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(setter(prefix = "with", keep_bare))]
+struct Sit {
+    #[builder(setter(into))]
+    name: String,
+    #[builder(setter(prefix = "set"))]
+    amet: u32,
+}
+
 #[test]
 fn prefixed_setters() {
     let x = LoremBuilder::default()
@@ -27,3 +36,113 @@ fn prefixed_setters() {
         }
     );
 }
+
+#[test]
+fn prefixed_and_bare_setters() {
+    let prefixed = SitBuilder::default()
+        .with_name("sit")
+        .set_amet(1)
+        .build()
+        .unwrap();
+    let bare = SitBuilder::default().name("sit").amet(1).build().unwrap();
+
+    assert_eq!(prefixed, bare);
+    assert_eq!(
+        bare,
+        Sit {
+            name: "sit".into(),
+            amet: 1,
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Getters {
+    ipsum: u32,
+}
+
+impl Getters {
+    fn ipsum(&self) -> u32 {
+        self.ipsum
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(prefix = "with"))]
+struct BuilderGetters {
+    ipsum: u32,
+}
+
+impl BuilderGetters {
+    fn ipsum(&self) -> u32 {
+        self.ipsum
+    }
+}
+
+impl BuilderGettersBuilder {
+    fn ipsum(&self) -> Option<u32> {
+        self.ipsum
+    }
+}
+
+#[test]
+fn struct_method_named_like_field() {
+    let x = GettersBuilder::default().ipsum(1).build().unwrap();
+    assert_eq!(x.ipsum(), 1);
+}
+
+#[test]
+fn builder_method_named_like_field() {
+    let mut builder = BuilderGettersBuilder::default();
+    builder.with_ipsum(1);
+
+    assert_eq!(builder.ipsum(), Some(1));
+    assert_eq!(builder.build().unwrap().ipsum(), 1);
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(prefix = "with", suffix = "value"))]
+struct Amet {
+    ipsum: u32,
+    #[builder(setter(prefix = "", suffix = ""))]
+    dolor: u32,
+    #[builder(setter(prefix = "set"))]
+    r#type: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Consectetur {
+    #[builder(setter(suffix = "value", keep_bare))]
+    ipsum: u32,
+}
+
+#[test]
+fn prefixed_and_suffixed_setters() {
+    let x = AmetBuilder::default()
+        .with_ipsum_value(1)
+        .with_dolor_value(2)
+        .set_type_value(3)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Amet {
+            ipsum: 1,
+            dolor: 2,
+            r#type: 3,
+        }
+    );
+}
+
+#[test]
+fn suffixed_and_bare_setters() {
+    let suffixed = ConsecteturBuilder::default()
+        .ipsum_value(1)
+        .build()
+        .unwrap();
+    let bare = ConsecteturBuilder::default().ipsum(1).build().unwrap();
+
+    assert_eq!(suffixed, bare);
+    assert_eq!(bare, Consectetur { ipsum: 1 });
+}
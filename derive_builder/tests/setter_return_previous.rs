@@ -0,0 +1,32 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(return_previous))]
+    state: u32,
+    #[builder(setter(into, return_previous))]
+    name: String,
+    other: bool,
+}
+
+#[test]
+fn returns_previous_value() {
+    let mut builder = LoremBuilder::default();
+    assert_eq!(builder.state(1), None);
+    assert_eq!(builder.state(2), Some(1));
+    assert_eq!(builder.name("first"), None);
+    assert_eq!(builder.name("second"), Some("first".to_string()));
+
+    builder.other(true);
+    assert_eq!(
+        builder.build().unwrap(),
+        Lorem {
+            state: 2,
+            name: "second".to_string(),
+            other: true,
+        }
+    );
+}
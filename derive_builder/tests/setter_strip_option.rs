@@ -24,6 +24,14 @@ struct Ipsum {
     strip_opt_with_default: Option<String>,
 }
 
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Dolor {
+    #[builder(setter(strip_option))]
+    tri_state: Option<Option<bool>>,
+    #[builder(setter(into, strip_option), default)]
+    tri_state_into: Option<Option<String>>,
+}
+
 #[test]
 fn generic_field() {
     let x = LoremBuilder::default()
@@ -62,3 +70,35 @@ fn generic_struct() {
         }
     );
 }
+
+#[test]
+fn strips_one_layer_of_nested_option() {
+    let x = DolorBuilder::default()
+        .tri_state(Some(true))
+        .tri_state_into(Some("sit".to_string()))
+        .build()
+        .unwrap();
+    assert_eq!(
+        x,
+        Dolor {
+            tri_state: Some(Some(true)),
+            tri_state_into: Some(Some("sit".to_string())),
+        }
+    );
+
+    let x = DolorBuilder::default()
+        .tri_state(None)
+        .tri_state_into(None)
+        .build()
+        .unwrap();
+    assert_eq!(
+        x,
+        Dolor {
+            tri_state: Some(None),
+            tri_state_into: Some(None),
+        }
+    );
+
+    let x = DolorBuilder::default().tri_state(None).build().unwrap();
+    assert_eq!(x.tri_state_into, None);
+}
@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(try_setter, setter(into))]
+struct Lorem {
+    #[builder(setter(transform = "|x: String| x.to_uppercase()"))]
+    ipsum: String,
+    #[builder(setter(transform = "|(x, y): (i32, i32)| Point { x, y }"))]
+    dolor: Point,
+    #[builder(setter(strip_option, transform = "|s: &str| s.len()"), default)]
+    sit: Option<usize>,
+}
+
+#[test]
+fn transforms_values() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum".to_string())
+        .dolor((1, 2))
+        .sit("sit")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "IPSUM".to_string(),
+            dolor: Point { x: 1, y: 2 },
+            sit: Some(3),
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Ipsum {
+    #[builder(setter(transform = "|items: Vec<u8>| items.into_iter().map(u32::from).sum()"))]
+    total: u32,
+}
+
+#[test]
+fn owned_pattern() {
+    let x = IpsumBuilder::default()
+        .total(vec![1, 2, 3])
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Ipsum { total: 6 });
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::num::TryFromIntError;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(derive(Debug))]
+struct Lorem {
+    #[builder(setter(try_into))]
+    ipsum: u8,
+    #[builder(setter(try_into, strip_option), default)]
+    dolor: Option<u16>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", derive(Debug))]
+struct Ipsum {
+    #[builder(setter(try_into))]
+    port: u16,
+}
+
+#[test]
+fn converts_the_value() {
+    let x = LoremBuilder::default()
+        .ipsum(42u64)
+        .unwrap()
+        .dolor(7i32)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 42,
+            dolor: Some(7),
+        }
+    );
+}
+
+#[test]
+fn returns_the_conversion_error() {
+    let mut builder = LoremBuilder::default();
+    let error: TryFromIntError = builder.ipsum(256u32).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "out of range integral type conversion attempted"
+    );
+    assert!(builder.dolor(-1i64).is_err());
+    assert_eq!(
+        &builder.build().unwrap_err().to_string(),
+        "`ipsum` must be initialized"
+    );
+}
+
+#[test]
+fn owned_pattern() {
+    let x = IpsumBuilder::default()
+        .port(8080u32)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(x, Ipsum { port: 8080 });
+
+    assert!(IpsumBuilder::default().port(-1).is_err());
+}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+pub type ExistingPort = u16;
+
+mod ports {
+    pub type Port = u16;
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(type_alias = "LoremPort"))]
+    port: u16,
+    #[builder(setter(type_alias = "crate::ExistingPort"))]
+    fallback: u16,
+    #[builder(setter(type_alias = "ports::Port", into))]
+    admin: u16,
+    #[builder(setter(type_alias = "LoremHost", strip_option))]
+    host: Option<String>,
+}
+
+#[test]
+fn emits_alias() {
+    let port: LoremPort = 8080;
+    let host: LoremHost = "localhost".to_string();
+    let x = LoremBuilder::default()
+        .port(port)
+        .fallback(8081 as ExistingPort)
+        .admin(82u8)
+        .host(host)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            port: 8080,
+            fallback: 8081,
+            admin: 82,
+            host: Some("localhost".to_string()),
+        }
+    );
+}
+
+#[test]
+fn setter_accepts_alias_type() {
+    fn set(builder: &mut LoremBuilder, value: LoremPort) {
+        builder.port(value);
+    }
+
+    let mut builder = LoremBuilder::default();
+    set(&mut builder, 80);
+    assert_eq!(builder.port, Some(80));
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(derive(Debug))]
+struct Lorem {
+    #[builder(setter(
+        validate = r#"|v: &i32| if *v < 0 { Err("ipsum must not be negative".to_string()) } else { Ok(()) }"#
+    ))]
+    ipsum: i32,
+    #[builder(setter(into, strip_option, validate = "check_dolor"), default)]
+    dolor: Option<String>,
+    amet: bool,
+}
+
+fn check_dolor(dolor: &str) -> Result<(), String> {
+    if dolor.is_empty() {
+        Err("dolor must not be empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Owned {
+    #[builder(setter(
+        validate = "|v: &u8| if *v == 0 { Err(String::from(\"zero\")) } else { Ok(()) }"
+    ))]
+    ipsum: u8,
+}
+
+#[test]
+fn invalid_value_returns_err() {
+    let mut builder = LoremBuilder::default();
+
+    let err = builder.ipsum(-1).unwrap_err();
+    assert_eq!(err.to_string(), "ipsum must not be negative");
+    assert_eq!(builder.ipsum, None);
+
+    let err = builder.dolor("").unwrap_err();
+    assert_eq!(err.to_string(), "dolor must not be empty");
+    assert_eq!(builder.dolor, None);
+}
+
+#[test]
+fn valid_value_chains() {
+    let x = LoremBuilder::default()
+        .ipsum(42)
+        .unwrap()
+        .dolor("dolor")
+        .unwrap()
+        .amet(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 42,
+            dolor: Some("dolor".to_string()),
+            amet: true,
+        }
+    );
+}
+
+#[test]
+fn owned_pattern() {
+    assert!(OwnedBuilder::default().ipsum(0).is_err());
+    assert_eq!(
+        OwnedBuilder::default().ipsum(1).unwrap().build().unwrap(),
+        Owned { ipsum: 1 }
+    );
+}
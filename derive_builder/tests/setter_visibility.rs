@@ -61,3 +61,64 @@ fn public_setters_foreign_module() {
 
     assert_eq!(&error.to_string(), "`private` must be initialized");
 }
+
+pub mod outer {
+    pub mod inner {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Dolor {
+            pub(super) restricted: u8,
+            pub(crate) in_crate: u8,
+            #[builder(setter(into), public)]
+            pub(super) overridden: u16,
+            unrestricted: u8,
+        }
+    }
+
+    #[test]
+    fn setters_follow_restricted_field_vis() {
+        let x = inner::DolorBuilder::default()
+            .restricted(1)
+            .in_crate(2)
+            .overridden(3u8)
+            .unrestricted(4)
+            .build()
+            .unwrap();
+
+        assert_eq!(x.restricted, 1);
+        assert_eq!(x.overridden, 3);
+    }
+}
+
+#[test]
+fn restricted_setter_vis_overridable() {
+    let mut builder = outer::inner::DolorBuilder::default();
+    builder.in_crate(2).overridden(3u8).unrestricted(4);
+    assert!(builder.build().is_err());
+}
+
+mod bar {
+    #[derive(Debug, PartialEq, Default, Builder, Clone)]
+    #[builder(setters_visibility_from_struct, setter(into))]
+    pub(crate) struct Dolor {
+        sit: String,
+        #[builder(public)]
+        amet: String,
+    }
+
+    #[test]
+    fn setters_with_struct_visibility() {
+        let x = DolorBuilder::default()
+            .sit("sit")
+            .amet("amet")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            x,
+            Dolor {
+                sit: "sit".into(),
+                amet: "amet".into(),
+            }
+        );
+    }
+}
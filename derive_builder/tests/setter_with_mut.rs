@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+struct Config {
+    verbose: bool,
+    level: u8,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(setter(with_mut, each = "ipsum_item"), default)]
+    ipsum: Vec<String>,
+    #[builder(setter(with_mut), default = "Config { verbose: false, level: 3 }")]
+    dolor: Config,
+    #[builder(setter(with_mut, or_default))]
+    sit: Config,
+}
+
+#[test]
+fn starts_from_default() {
+    let x = LoremBuilder::default()
+        .with_ipsum(|v| v.push("a".to_string()))
+        .with_dolor(|config| config.verbose = true)
+        .with_sit(|config| config.level += 1)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: vec!["a".to_string()],
+            dolor: Config {
+                verbose: true,
+                level: 3,
+            },
+            sit: Config {
+                verbose: false,
+                level: 1,
+            },
+        }
+    );
+}
+
+#[test]
+fn modifies_set_value() {
+    let x = LoremBuilder::default()
+        .ipsum(vec!["a".to_string()])
+        .ipsum_item("b".to_string())
+        .with_ipsum(|v| v.retain(|item| item != "a"))
+        .with_ipsum(|v| v.push("c".to_string()))
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, vec!["b", "c"]);
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Ipsum {
+    #[builder(setter(with_mut), default)]
+    dolor: Vec<u8>,
+}
+
+#[test]
+fn owned_pattern() {
+    let x = IpsumBuilder::default()
+        .with_dolor(|v| v.extend([1, 2].iter()))
+        .build()
+        .unwrap();
+
+    assert_eq!(x.dolor, vec![1, 2]);
+}
@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate derive_builder;
+
+use std::mem;
+
+#[derive(Debug, Builder)]
+#[builder(struct_attr(repr(C)), struct_attr(repr(align(64))))]
+pub struct Lorem {
+    ipsum: u8,
+    dolor: u32,
+}
+
+#[derive(Debug, Builder)]
+#[builder(struct_attr(repr(C), derive(PartialEq)))]
+pub struct Sit {
+    amet: u8,
+}
+
+#[derive(Debug, Builder)]
+#[builder(struct_attr(doc = concat!("Builds a ", "`Dolor`."), derive(PartialEq)))]
+pub struct Dolor {
+    sit: u8,
+}
+
+#[derive(Debug, Builder)]
+#[repr(C)]
+pub struct Amet {
+    consectetur: u8,
+    adipiscing: u32,
+    elit: u8,
+}
+
+#[test]
+fn repr_of_struct_is_not_forwarded() {
+    // With `repr(C)` the fields keep their order, so each `u8` is padded to the alignment of
+    // the `u32`.
+    assert_eq!(mem::size_of::<Amet>(), 12);
+    // The builder fields are laid out freely, and would take 16 bytes with `repr(C)`.
+    assert!(mem::size_of::<AmetBuilder>() < 16);
+
+    let amet = AmetBuilder::default()
+        .consectetur(1)
+        .adipiscing(2)
+        .elit(3)
+        .build()
+        .unwrap();
+    assert_eq!(amet.consectetur, 1);
+    assert_eq!(amet.adipiscing, 2);
+    assert_eq!(amet.elit, 3);
+}
+
+#[test]
+fn accumulates_attributes() {
+    assert_eq!(mem::align_of::<LoremBuilder>(), 64);
+    assert_eq!(mem::size_of::<LoremBuilder>(), 64);
+}
+
+#[test]
+fn forwards_several_attributes_at_once() {
+    let mut a = SitBuilder::default();
+    a.amet(1);
+    let mut b = SitBuilder::default();
+    b.amet(1);
+    assert!(a == b);
+    assert_eq!(a.build().unwrap().amet, 1);
+}
+
+#[test]
+fn forwards_attributes_which_are_not_meta() {
+    let mut a = DolorBuilder::default();
+    a.sit(1);
+    assert!(a == a.clone());
+    assert_eq!(a.build().unwrap().sit, 1);
+}
+
+#[test]
+fn builds() {
+    let lorem = LoremBuilder::default().ipsum(1).dolor(2).build().unwrap();
+    assert_eq!(lorem.ipsum, 1);
+    assert_eq!(lorem.dolor, 2);
+}
@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Clone)]
+struct NotDefaultable(String);
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(default, build_fn(unchecked))]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    sit: bool,
+    amet: Vec<u8>,
+    consectetur: Option<u16>,
+    adipiscing: u64,
+    elit: char,
+    sed: i8,
+    #[builder(setter(skip))]
+    eiusmod: NotDefaultable,
+    #[builder(setter(skip))]
+    tempor: u32,
+    #[builder(setter(skip), default = "7")]
+    incididunt: u32,
+    #[builder(setter(skip), field(map = "|x: u32| x + 1"))]
+    labore: u32,
+}
+
+impl Default for Lorem {
+    fn default() -> Self {
+        Lorem {
+            ipsum: 1,
+            dolor: "dolor".to_string(),
+            sit: true,
+            amet: vec![2],
+            consectetur: Some(3),
+            adipiscing: 4,
+            elit: 'e',
+            sed: -5,
+            eiusmod: NotDefaultable("eiusmod".to_string()),
+            tempor: 6,
+            incididunt: 0,
+            labore: 8,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(default, lazy_build)]
+struct Cached {
+    ipsum: u32,
+    #[builder(setter(skip))]
+    dolor: NotDefaultable,
+}
+
+impl Default for Cached {
+    fn default() -> Self {
+        Cached {
+            ipsum: 1,
+            dolor: NotDefaultable("dolor".to_string()),
+        }
+    }
+}
+
+#[test]
+fn unset_fields_take_struct_default() {
+    let x = LoremBuilder::default().ipsum(42).build().unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: 42,
+            incididunt: 7,
+            labore: 9,
+            ..Lorem::default()
+        }
+    );
+}
+
+#[test]
+fn unchecked_build_takes_struct_default() {
+    let x = unsafe { LoremBuilder::default().sed(1).build_unchecked() };
+
+    assert_eq!(x.sed, 1);
+    assert_eq!(x.eiusmod, NotDefaultable("eiusmod".to_string()));
+    assert_eq!(x.tempor, 6);
+}
+
+#[test]
+fn lazy_build_takes_struct_default() {
+    let mut builder = CachedBuilder::default();
+
+    assert_eq!(
+        *builder.ipsum(2).build().unwrap(),
+        Cached {
+            ipsum: 2,
+            dolor: NotDefaultable("dolor".to_string()),
+        }
+    );
+}
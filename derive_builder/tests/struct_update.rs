@@ -0,0 +1,118 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(struct_update, setter(into))]
+struct Lorem {
+    ipsum: String,
+    dolor: u32,
+    #[builder(default)]
+    sit: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(struct_update, pattern = "owned")]
+struct Ipsum {
+    #[builder(setter(lazy = "|| 7"))]
+    dolor: u32,
+    #[builder(setter(custom), field(type = "Vec<u8>", build = "self.amet.len()"))]
+    amet: usize,
+}
+
+impl IpsumBuilder {
+    fn amet(mut self, amet: Vec<u8>) -> Self {
+        self.amet = amet;
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(struct_update, field(default))]
+struct Dolor {
+    sit: u8,
+}
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+#[builder(struct_update, lazy_build)]
+struct Sit {
+    amet: u8,
+}
+
+#[test]
+fn fills_unset_fields() {
+    let mut base = LoremBuilder::default();
+    base.ipsum("base").dolor(1u32).sit(vec![1]);
+    let mut builder = LoremBuilder::default();
+    builder.dolor(2u32);
+
+    assert_eq!(
+        builder.with_base(base).build().unwrap(),
+        Lorem {
+            ipsum: "base".to_string(),
+            dolor: 2,
+            sit: vec![1],
+        }
+    );
+}
+
+#[test]
+fn unset_in_both() {
+    let mut base = LoremBuilder::default();
+    base.ipsum("base");
+
+    let builder = LoremBuilder::default().with_base(base);
+    assert_eq!(
+        &builder.build().unwrap_err().to_string(),
+        "`dolor` must be initialized"
+    );
+}
+
+#[test]
+fn lazy_and_custom_fields() {
+    let base = IpsumBuilder::default().dolor(1);
+    let builder = IpsumBuilder::default().amet(vec![1, 2]);
+    let base_amet = IpsumBuilder::default().amet(vec![1, 2, 3]);
+
+    assert_eq!(
+        builder
+            .with_base(base)
+            .with_base(base_amet)
+            .build()
+            .unwrap(),
+        Ipsum { dolor: 1, amet: 2 }
+    );
+    assert_eq!(
+        IpsumBuilder::default()
+            .with_base(IpsumBuilder::default())
+            .build()
+            .unwrap(),
+        Ipsum { dolor: 7, amet: 0 }
+    );
+}
+
+#[test]
+fn fields_without_option_keep_their_value() {
+    let base = DolorBuilder::default().sit(1).clone();
+
+    assert_eq!(
+        DolorBuilder::default().with_base(base).build(),
+        Dolor { sit: 0 }
+    );
+}
+
+#[test]
+fn clears_build_cache() {
+    let mut builder = SitBuilder::default();
+    assert!(builder.build().is_err());
+    builder.amet(1);
+    let first = builder.build().unwrap();
+
+    let mut base = SitBuilder::default();
+    base.amet(2);
+    let mut builder = SitBuilder::default().with_base(base);
+
+    assert_eq!(*first, Sit { amet: 1 });
+    assert_eq!(*builder.build().unwrap(), Sit { amet: 2 });
+}
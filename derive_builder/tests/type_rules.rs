@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+fn trim_to_owned(value: String) -> String {
+    value.trim().to_owned()
+}
+
+fn shout(value: String) -> String {
+    value.to_uppercase()
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(
+    setter(into),
+    on(type = "String", into = false, transform = "trim_to_owned"),
+    on(
+        type = "Vec<u8>",
+        transform = "|v: Vec<u8>| v.into_iter().rev().collect()"
+    )
+)]
+struct Lorem {
+    ipsum: String,
+    #[builder(setter(into), field(map = "shout"))]
+    dolor: String,
+    sit: Vec<u8>,
+    amet: u32,
+}
+
+// Clippy mistakes the second rule for a duplicate of the first.
+#[allow(clippy::duplicated_attributes)]
+#[derive(Debug, PartialEq, Builder)]
+#[builder(on(type = "String", into, transform = "trim_to_owned"))]
+#[builder(on(type = "String", transform = "shout"))]
+struct Ipsum {
+    #[builder(default = r#"" default ".to_string()"#)]
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: String,
+}
+
+#[test]
+fn rules_apply_to_matching_types() {
+    let x = LoremBuilder::default()
+        .ipsum(" ipsum ".to_string())
+        .dolor(" dolor ")
+        .sit(vec![1, 2])
+        .amet(3u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        x,
+        Lorem {
+            ipsum: "ipsum".to_string(),
+            dolor: " DOLOR ".to_string(),
+            sit: vec![2, 1],
+            amet: 3,
+        }
+    );
+}
+
+#[test]
+fn later_rules_take_precedence() {
+    let x = IpsumBuilder::default().build().unwrap();
+    assert_eq!(x.dolor, " DEFAULT ");
+    assert_eq!(x.sit, "");
+
+    let y = IpsumBuilder::default().dolor("dolor").build().unwrap();
+    assert_eq!(y.dolor, "DOLOR");
+}
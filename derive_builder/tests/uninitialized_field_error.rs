@@ -0,0 +1,27 @@
+#[macro_use]
+extern crate pretty_assertions;
+extern crate derive_builder;
+
+use std::error::Error;
+
+use derive_builder::UninitializedFieldError;
+
+#[test]
+fn field_name() {
+    let error = UninitializedFieldError::new("foo");
+    assert_eq!(error.field_name(), "foo");
+    assert_eq!(UninitializedFieldError::from("foo").field_name(), "foo");
+}
+
+#[test]
+fn display() {
+    let error = UninitializedFieldError::new("foo");
+    assert_eq!(&error.to_string(), "`foo` must be initialized");
+}
+
+#[test]
+fn implements_error() {
+    let error: Box<dyn Error> = Box::new(UninitializedFieldError::new("foo"));
+    assert!(error.source().is_none());
+    assert_eq!(&error.to_string(), "`foo` must be initialized");
+}
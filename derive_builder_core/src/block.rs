@@ -16,6 +16,14 @@ impl BlockContents {
     pub fn is_empty(&self) -> bool {
         self.0.stmts.is_empty()
     }
+
+    /// The expression if the block consists of nothing else.
+    pub fn as_expr(&self) -> Option<&syn::Expr> {
+        match self.0.stmts.as_slice() {
+            [syn::Stmt::Expr(expr)] => Some(expr),
+            _ => None,
+        }
+    }
 }
 
 impl ToTokens for BlockContents {
@@ -27,6 +35,11 @@ impl ToTokens for BlockContents {
 impl TryFrom<&'_ LitStr> for BlockContents {
     type Error = syn::Error;
 
+    /// Parse the contents of `s` as the statements of a block.
+    ///
+    /// All tokens, and therefore parse errors, are spanned to the whole literal: on stable
+    /// compilers a procedural macro cannot address a range within a string literal, so the
+    /// failing token cannot be pointed at.
     fn try_from(s: &LitStr) -> Result<Self, Self::Error> {
         let mut block_str = s.value();
         block_str.insert(0, '{');
@@ -96,4 +109,13 @@ mod test {
         let expr = parse("42").unwrap();
         assert_eq!(quote!(#expr).to_string(), quote!({ 42 }).to_string());
     }
+
+    #[test]
+    fn block_enum_variant() {
+        let expr = parse("Status::Code(404)").unwrap();
+        assert_eq!(
+            quote!(#expr).to_string(),
+            quote!({ Status::Code(404) }).to_string()
+        );
+    }
 }
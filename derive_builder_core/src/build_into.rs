@@ -0,0 +1,378 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+
+use BuilderFieldType;
+
+/// Build method writing into an existing instance of the target struct, implementing
+/// `quote::ToTokens`.
+///
+/// Each field set in the builder is cloned into the field of the target with `clone_from`,
+/// which can reuse the target's allocations. Unset fields keep the value of the target, unless
+/// they are required, in which case nothing is written and an error is returned.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuildInto, BuildIntoField, BuilderFieldType};
+/// # fn main() {
+/// #    let mut build_into = default_build_into!();
+/// #    build_into.push_field(BuildIntoField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        required: true,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#build_into).to_string(), quote!(
+/// impl FooBuilder {
+///     pub fn build_into(&self, target: &mut Foo) -> Result<(), FooBuilderError>
+///     where
+///         String: Clone,
+///     {
+///         if self.foo.is_none() {
+///             return Err(Into::into(UninitializedFieldError::from("foo")));
+///         }
+///         if let Some(ref value) = self.foo {
+///             target.foo.clone_from(value);
+///         }
+///         Ok(())
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuildInto<'a> {
+    /// Enables code generation for the method.
+    pub enabled: bool,
+    /// Name of the method, e.g. `build_into`.
+    pub ident: syn::Ident,
+    /// Visibility of the method, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Type returned in case of a missing required field or a failed validation.
+    pub error_ty: syn::Path,
+    /// Validation function with signature `&FooBuilder -> Result<(), String>`
+    /// to call before writing into the target.
+    pub validate_fn: Option<&'a syn::Path>,
+    /// Check all required fields up front and report every missing one in a single
+    /// `UninitializedFields` error, rather than failing on the first.
+    pub report_all_missing: bool,
+    /// Fields which must be set for the method to succeed.
+    pub required_fields: Vec<syn::Ident>,
+    /// Statements writing each field of the builder into the target.
+    pub assignments: Vec<TokenStream>,
+    /// Bounds of the method, requiring the written field types to implement `Clone`.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A target field written by `BuildInto`.
+#[derive(Debug, Clone)]
+pub struct BuildIntoField<'a> {
+    /// Name of the target field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Whether `build_into` fails if this field is unset.
+    pub required: bool,
+}
+
+impl<'a> ToTokens for BuildInto<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let target_ident = self.target_ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let error_ty = &self.error_ty;
+        let validate_fn = self
+            .validate_fn
+            .as_ref()
+            .map(|vfn| quote_spanned!(vfn.span() => #vfn(&self)?;));
+        let required = &self.required_fields;
+        let names = required.iter().map(|ident| ident.unraw().to_string());
+        let check_missing = if self.report_all_missing && !required.is_empty() {
+            quote!(
+                let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+                #(
+                    if self.#required.is_none() {
+                        __missing_fields.push(#names);
+                    }
+                )*
+                if !__missing_fields.is_empty() {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        #error_ty::UninitializedFields(__missing_fields)
+                    );
+                }
+            )
+        } else {
+            quote!(#(
+                if self.#required.is_none() {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::export::core::convert::Into::into(
+                            ::derive_builder::UninitializedFieldError::from(#names)
+                        )
+                    );
+                }
+            )*)
+        };
+        let bounds = if self.bounds.is_empty() {
+            quote!()
+        } else {
+            let bounds = &self.bounds;
+            quote!(where #(#bounds),*)
+        };
+        let assignments = &self.assignments;
+        let target_param = if assignments.is_empty() {
+            quote!(_target)
+        } else {
+            quote!(target)
+        };
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                /// Build into an existing value, cloning each field set in this builder into
+                /// it. Unset fields keep the value they have in `target`.
+                ///
+                /// # Errors
+                ///
+                /// If a required field is unset or the validation fails, in which case
+                /// `target` is left unchanged.
+                #vis fn #ident(&self, #target_param: &mut #target_ident #ty_generics)
+                    -> ::derive_builder::export::core::result::Result<(), #error_ty>
+                    #bounds
+                {
+                    #validate_fn
+                    #check_missing
+                    #(#assignments)*
+                    ::derive_builder::export::core::result::Result::Ok(())
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuildInto<'a> {
+    /// Add another target field to write into.
+    pub fn push_field(&mut self, field: BuildIntoField) -> &mut Self {
+        // Fields are only checked by `Options::check_build_into` if this is enabled.
+        if !self.enabled {
+            return self;
+        }
+
+        let ident = field.field_ident;
+        match field.field_type {
+            BuilderFieldType::Optional(ty) => {
+                if field.required {
+                    self.required_fields.push(ident.clone());
+                }
+                self.assignments.push(quote!(
+                    if let ::derive_builder::export::core::option::Option::Some(ref value) = self.#ident {
+                        ::derive_builder::export::core::clone::Clone::clone_from(&mut target.#ident, value);
+                    }
+                ));
+                self.bounds
+                    .push(parse_quote!(#ty: ::derive_builder::export::core::clone::Clone));
+            }
+            // The builder field holds the value itself.
+            BuilderFieldType::Precise(ty) => {
+                self.assignments.push(quote!(
+                    ::derive_builder::export::core::clone::Clone::clone_from(&mut target.#ident, &self.#ident);
+                ));
+                self.bounds
+                    .push(parse_quote!(#ty: ::derive_builder::export::core::clone::Clone));
+            }
+            // Fields without a setter are never set.
+            BuilderFieldType::Phantom(_) => {}
+            // Rejected by `Options::check_build_into`.
+            BuilderFieldType::Lazy(_) => {
+                unreachable!("`build_into` cannot call the closure of a lazy setter")
+            }
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_build_into {
+    () => {
+        BuildInto {
+            enabled: true,
+            ident: syn::Ident::new("build_into", ::proc_macro2::Span::call_site()),
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            error_ty: parse_quote!(FooBuilderError),
+            validate_fn: None,
+            report_all_missing: false,
+            required_fields: vec![],
+            assignments: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn disabled() {
+        let mut build_into = default_build_into!();
+        build_into.enabled = false;
+
+        assert_eq!(quote!(#build_into).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new("bar", Span::call_site());
+        let marker = syn::Ident::new("marker", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let number: syn::Type = parse_quote!(u32);
+        let mut build_into = default_build_into!();
+        build_into.push_field(BuildIntoField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            required: true,
+        });
+        build_into.push_field(BuildIntoField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&number),
+            required: false,
+        });
+        build_into.push_field(BuildIntoField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&number),
+            required: false,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl FooBuilder {
+                /// Build into an existing value, cloning each field set in this builder into
+                /// it. Unset fields keep the value they have in `target`.
+                ///
+                /// # Errors
+                ///
+                /// If a required field is unset or the validation fails, in which case
+                /// `target` is left unchanged.
+                pub fn build_into(&self, target: &mut Foo)
+                    -> ::derive_builder::export::core::result::Result<(), FooBuilderError>
+                    where
+                        String: ::derive_builder::export::core::clone::Clone,
+                        u32: ::derive_builder::export::core::clone::Clone
+                {
+                    if self.foo.is_none() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::export::core::convert::Into::into(
+                                ::derive_builder::UninitializedFieldError::from("foo")
+                            )
+                        );
+                    }
+                    if let ::derive_builder::export::core::option::Option::Some(ref value) = self.foo {
+                        ::derive_builder::export::core::clone::Clone::clone_from(&mut target.foo, value);
+                    }
+                    ::derive_builder::export::core::clone::Clone::clone_from(&mut target.bar, &self.bar);
+                    ::derive_builder::export::core::result::Result::Ok(())
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#build_into).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn validate_and_report_all_missing() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let validate_path: syn::Path = parse_quote!(IpsumBuilder::validate);
+        let mut build_into = default_build_into!();
+        build_into.validate_fn = Some(&validate_path);
+        build_into.report_all_missing = true;
+        build_into.push_field(BuildIntoField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            required: true,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl FooBuilder {
+                /// Build into an existing value, cloning each field set in this builder into
+                /// it. Unset fields keep the value they have in `target`.
+                ///
+                /// # Errors
+                ///
+                /// If a required field is unset or the validation fails, in which case
+                /// `target` is left unchanged.
+                pub fn build_into(&self, target: &mut Foo)
+                    -> ::derive_builder::export::core::result::Result<(), FooBuilderError>
+                    where String: ::derive_builder::export::core::clone::Clone
+                {
+                    IpsumBuilder::validate(&self)?;
+                    let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+                    if self.foo.is_none() {
+                        __missing_fields.push("foo");
+                    }
+                    if !__missing_fields.is_empty() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            FooBuilderError::UninitializedFields(__missing_fields)
+                        );
+                    }
+                    if let ::derive_builder::export::core::option::Option::Some(ref value) = self.foo {
+                        ::derive_builder::export::core::clone::Clone::clone_from(&mut target.foo, value);
+                    }
+                    ::derive_builder::export::core::result::Result::Ok(())
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#build_into).to_string(), expected.to_string());
+    }
+}
@@ -2,11 +2,15 @@ use std::borrow::Cow;
 
 use doc_comment_from;
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, TokenStreamExt};
+use quote::{format_ident, ToTokens, TokenStreamExt};
 use syn;
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
+use BuildFnInto;
+use BuildFnReceiver;
 use BuilderPattern;
 use Initializer;
+use BUILD_CACHE_FIELD_NAME;
 use DEFAULT_STRUCT_NAME;
 
 use crate::DefaultExpression;
@@ -30,6 +34,7 @@ use crate::DefaultExpression;
 /// #    let build_method = default_build_method!();
 /// #
 /// #    assert_eq!(quote!(#build_method).to_string(), quote!(
+/// #[must_use = "the built value or its error should be handled"]
 /// pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
 ///     Ok(Foo {
 ///         foo: self.foo,
@@ -48,6 +53,9 @@ pub struct BuildMethod<'a> {
     pub visibility: Cow<'a, syn::Visibility>,
     /// How the build method takes and returns `self` (e.g. mutably).
     pub pattern: BuilderPattern,
+    /// Receiver of the build method overriding the one implied by `pattern`, e.g. to consume
+    /// a builder with mutable setters.
+    pub receiver: Option<BuildFnReceiver>,
     /// Type of the target field.
     ///
     /// The corresonding builder field will be `Option<field_type>`.
@@ -58,15 +66,58 @@ pub struct BuildMethod<'a> {
     pub error_ty: syn::Path,
     /// Field initializers for the target type.
     pub initializers: Vec<TokenStream>,
+    /// Bind the value of each field to a local before building the target type, so that
+    /// computed fields can read the other fields, see `#[builder(field(computed = "..."))]`.
+    ///
+    /// The initializers are `let` statements in this case, which must be pushed in the order
+    /// the fields depend on each other.
+    pub bind_fields: bool,
+    /// Initializers of the target type from the locals, if `bind_fields` is set.
+    pub bound_initializers: Vec<TokenStream>,
+    /// Also emit an `unsafe` variant of the build method, suffixed `_unchecked`, which assumes
+    /// every required field is set and skips validation.
+    pub unchecked: bool,
+    /// Field initializers for the unchecked build method.
+    pub unchecked_initializers: Vec<TokenStream>,
+    /// Also emit a variant of the build method, suffixed `_expect`, which returns the target
+    /// type itself, panicking if a required field is unset, and skips validation.
+    pub expect: bool,
+    /// Field initializers for the expecting build method.
+    pub expect_initializers: Vec<TokenStream>,
     /// Doc-comment of the builder struct.
     pub doc_comment: Option<syn::Attribute>,
     /// Default value for the whole struct.
     ///
     /// This will be in scope for all initializers as `__default`.
     pub default_struct: Option<&'a DefaultExpression>,
+    /// Whether some fields have no initializer and are moved from the default struct with
+    /// struct update syntax, see `Initializer::is_default_struct_field`.
+    pub default_struct_update: bool,
     /// Validation function with signature `&FooBuilder -> Result<(), String>`
     /// to call before the macro-provided struct buildout.
     pub validate_fn: Option<&'a syn::Path>,
+    /// Whether the build method is marked `#[must_use]`.
+    pub must_use: bool,
+    /// Additional bounds the build method needs, e.g. `T: Default` for fields which
+    /// fall back to their type's default value.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Cache the built value in the builder and return it as an `Arc` on subsequent calls.
+    ///
+    /// The build method takes `&mut self` in this case, and setters clear the cache.
+    pub lazy: bool,
+    /// Check all `required_fields` up front and report every missing one in a single
+    /// `UninitializedFields` error, rather than failing on the first.
+    pub report_all_missing: bool,
+    /// Fields which must be set for the build to succeed.
+    pub required_fields: Vec<&'a syn::Ident>,
+    /// Return the target type itself rather than a `Result`, for builders whose fields always
+    /// hold a value, see `#[builder(field(default))]`.
+    pub infallible: bool,
+    /// Convert the built struct into another type, see `#[builder(build_fn(into))]`.
+    pub into: Option<&'a BuildFnInto>,
+    /// The full return type of the build method, into which the `Result` it would otherwise
+    /// return is converted with `?`, see `#[builder(build_fn(result_type = "..."))]`.
+    pub result_type: Option<&'a syn::Type>,
 }
 
 impl<'a> ToTokens for BuildMethod<'a> {
@@ -76,9 +127,10 @@ impl<'a> ToTokens for BuildMethod<'a> {
         let target_ty = &self.target_ty;
         let target_ty_generics = &self.target_ty_generics;
         let initializers = &self.initializers;
-        let self_param = match self.pattern {
-            BuilderPattern::Owned => quote!(self),
-            BuilderPattern::Mutable | BuilderPattern::Immutable => quote!(&self),
+        let self_param = if self.lazy {
+            quote!(&mut self)
+        } else {
+            self.self_param()
         };
         let doc_comment = &self.doc_comment;
         let default_struct = self.default_struct.as_ref().map(|default_expr| {
@@ -90,18 +142,153 @@ impl<'a> ToTokens for BuildMethod<'a> {
             .as_ref()
             .map(|vfn| quote_spanned!(vfn.span() => #vfn(&self)?;));
         let error_ty = &self.error_ty;
+        let where_clause = if self.bounds.is_empty() {
+            quote!()
+        } else {
+            let bounds = &self.bounds;
+            quote!(where #(#bounds),*)
+        };
+        let check_missing = if self.report_all_missing && !self.required_fields.is_empty() {
+            let idents = &self.required_fields;
+            let names = idents.iter().map(|ident| ident.unraw().to_string());
+            quote!(
+                let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+                #(
+                    if self.#idents.is_none() {
+                        __missing_fields.push(#names);
+                    }
+                )*
+                if !__missing_fields.is_empty() {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        #error_ty::UninitializedFields(__missing_fields)
+                    );
+                }
+            )
+        } else {
+            quote!()
+        };
+        let target = quote!(#target_ty #target_ty_generics);
+        let built = self.struct_expr(initializers);
+        let (into_params, built_ty, built) = match self.into {
+            None => (quote!(), target, built),
+            Some(BuildFnInto::Explicit(ty)) => (
+                quote!(),
+                quote!(#ty),
+                quote!(::derive_builder::export::core::convert::From::from(#built)),
+            ),
+            Some(BuildFnInto::Inferred) => (
+                quote!(<__BuilderInto: ::derive_builder::export::core::convert::From<#target>>),
+                quote!(__BuilderInto),
+                quote!(::derive_builder::export::core::convert::From::from(#built)),
+            ),
+        };
+        let must_use = if self.must_use && self.infallible {
+            quote!(#[must_use])
+        } else if self.must_use {
+            quote!(#[must_use = "the built value or its error should be handled"])
+        } else {
+            quote!()
+        };
 
-        if self.enabled {
+        if self.enabled && self.lazy {
+            let cache = syn::Ident::new(BUILD_CACHE_FIELD_NAME, Span::call_site());
+            let (result_ty, body) = self.with_result_type(
+                quote!(::derive_builder::export::core::result::Result<
+                    ::derive_builder::export::core::sync::Arc<#target_ty #target_ty_generics>,
+                    #error_ty
+                >),
+                quote!(
+                    if let ::derive_builder::export::core::option::Option::Some(ref cached) = self.#cache {
+                        return ::derive_builder::export::core::result::Result::Ok(
+                            ::derive_builder::export::core::sync::Arc::clone(cached)
+                        );
+                    }
+                    #validate_fn
+                    #check_missing
+                    #default_struct
+                    let built = ::derive_builder::export::core::sync::Arc::new(#built);
+                    self.#cache = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::sync::Arc::clone(&built)
+                    );
+                    ::derive_builder::export::core::result::Result::Ok(built)
+                ),
+            );
+            tokens.append_all(quote!(
+                #doc_comment
+                #must_use
+                #vis fn #ident(#self_param) -> #result_ty
+                    #where_clause
+                {
+                    #body
+                }
+            ))
+        } else if self.enabled && self.infallible {
             tokens.append_all(quote!(
                 #doc_comment
-                #vis fn #ident(#self_param)
-                    -> ::derive_builder::export::core::result::Result<#target_ty #target_ty_generics, #error_ty>
+                #must_use
+                #vis fn #ident #into_params(#self_param) -> #built_ty
+                    #where_clause
                 {
+                    #default_struct
+                    #built
+                }
+            ))
+        } else if self.enabled {
+            let (result_ty, body) = self.with_result_type(
+                quote!(::derive_builder::export::core::result::Result<#built_ty, #error_ty>),
+                quote!(
                     #validate_fn
+                    #check_missing
                     #default_struct
-                    Ok(#target_ty {
-                        #(#initializers)*
-                    })
+                    ::derive_builder::export::core::result::Result::Ok(#built)
+                ),
+            );
+            tokens.append_all(quote!(
+                #doc_comment
+                #must_use
+                #vis fn #ident #into_params(#self_param) -> #result_ty
+                    #where_clause
+                {
+                    #body
+                }
+            ))
+        }
+
+        let self_param = self.self_param();
+        let must_use = if self.must_use {
+            quote!(#[must_use])
+        } else {
+            quote!()
+        };
+
+        if self.enabled && self.unchecked {
+            let unchecked_ident = format_ident!("{}_unchecked", ident);
+            let doc_comment = doc_comment_from(self.unchecked_doc());
+            let built = self.struct_expr(&self.unchecked_initializers);
+            tokens.append_all(quote!(
+                #doc_comment
+                #must_use
+                #vis unsafe fn #unchecked_ident(#self_param) -> #target_ty #target_ty_generics
+                    #where_clause
+                {
+                    #default_struct
+                    #built
+                }
+            ))
+        }
+
+        if self.enabled && self.expect {
+            let expect_ident = format_ident!("{}_expect", ident);
+            let doc_comment = doc_comment_from(self.expect_doc());
+            let built = self.struct_expr(&self.expect_initializers);
+            tokens.append_all(quote!(
+                #doc_comment
+                #must_use
+                #vis fn #expect_ident(#self_param) -> #target_ty #target_ty_generics
+                    #where_clause
+                {
+                    #default_struct
+                    #built
                 }
             ))
         }
@@ -120,8 +307,174 @@ impl<'a> BuildMethod<'a> {
     ///
     /// For each struct field this must be called with the appropriate
     /// initializer.
+    ///
+    /// Fields which are only ever set from the default struct get no initializer, and are
+    /// filled with struct update syntax instead.
     pub fn push_initializer(&mut self, init: Initializer) -> &mut Self {
+        if self.bind_fields {
+            let field = init.field_ident;
+            let binding = init.binding_ident();
+            self.bound_initializers.push(quote!(#field: #binding,));
+            self.initializers.push(init.binding());
+            if self.unchecked {
+                let init = Initializer {
+                    unchecked: true,
+                    ..init.clone()
+                };
+                self.unchecked_initializers.push(init.binding());
+            }
+            if self.expect {
+                let init = Initializer {
+                    expect: true,
+                    ..init
+                };
+                self.expect_initializers.push(init.binding());
+            }
+            return self;
+        }
+
+        if init.is_default_struct_field() {
+            self.default_struct_update = true;
+            return self;
+        }
+
         self.initializers.push(quote!(#init));
+        if self.unchecked {
+            let init = Initializer {
+                unchecked: true,
+                ..init.clone()
+            };
+            self.unchecked_initializers.push(quote!(#init));
+        }
+        if self.expect {
+            let init = Initializer {
+                expect: true,
+                ..init
+            };
+            self.expect_initializers.push(quote!(#init));
+        }
+        self
+    }
+
+    /// The expression building the target type with the given initializers.
+    fn struct_expr(&self, initializers: &[TokenStream]) -> TokenStream {
+        let target_ty = &self.target_ty;
+        let default_struct_update = if self.default_struct_update {
+            let ident = syn::Ident::new(DEFAULT_STRUCT_NAME, Span::call_site());
+            quote!(..#ident)
+        } else {
+            quote!()
+        };
+
+        if self.bind_fields {
+            let bound_initializers = &self.bound_initializers;
+            quote!({
+                #(#initializers)*
+                #target_ty {
+                    #(#bound_initializers)*
+                    #default_struct_update
+                }
+            })
+        } else {
+            quote!(#target_ty {
+                #(#initializers)*
+                #default_struct_update
+            })
+        }
+    }
+
+    /// The return type and body of the build method, given the `Result` it returns and the
+    /// body returning it.
+    ///
+    /// With a custom `result_type`, the body moves into a closure whose error is converted
+    /// with `?`.
+    fn with_result_type(
+        &self,
+        result_ty: TokenStream,
+        body: TokenStream,
+    ) -> (TokenStream, TokenStream) {
+        match self.result_type {
+            Some(custom) => {
+                // The closure of a lazy build method writes the cache through `self`.
+                let mutability = if self.lazy { quote!(mut) } else { quote!() };
+                (
+                    quote!(#custom),
+                    quote!(
+                        let #mutability __build = move || -> #result_ty { #body };
+                        ::derive_builder::export::core::result::Result::Ok(__build()?)
+                    ),
+                )
+            }
+            None => (result_ty, body),
+        }
+    }
+
+    /// How the build method takes `self`, ignoring the `&mut self` of lazy build methods.
+    fn self_param(&self) -> TokenStream {
+        let receiver = self
+            .receiver
+            .unwrap_or_else(|| BuildFnReceiver::from_pattern(self.pattern));
+        match receiver {
+            BuildFnReceiver::Owned => quote!(self),
+            BuildFnReceiver::Ref => quote!(&self),
+            BuildFnReceiver::RefMut => quote!(&mut self),
+        }
+    }
+
+    /// The doc comment of the unchecked build method, listing what its caller must uphold.
+    fn unchecked_doc(&self) -> String {
+        let mut doc = format!(
+            "Builds a new `{}` without checking that required fields are set.\n\n# Safety\n\n",
+            self.target_ty
+        );
+        if self.required_fields.is_empty() {
+            doc.push_str("This struct has no required fields, so this is always safe to call.\n");
+        } else {
+            doc.push_str("The caller must ensure that the following fields are set:\n\n");
+            for field in &self.required_fields {
+                doc.push_str(&format!("* `{}`\n", field.unraw()));
+            }
+        }
+        if self.validate_fn.is_some() {
+            doc.push_str(
+                "\nThe validation function is not called, so the caller must also ensure that \
+                 the builder would pass it.\n",
+            );
+        }
+        doc
+    }
+
+    /// The doc comment of the expecting build method, listing the fields it panics without.
+    fn expect_doc(&self) -> String {
+        let mut doc = format!(
+            "Builds a new `{}`, panicking if a required field is unset.\n\n# Panics\n\n",
+            self.target_ty
+        );
+        if self.required_fields.is_empty() {
+            doc.push_str("This struct has no required fields, so this never panics.\n");
+        } else {
+            doc.push_str("Panics unless the following fields are set:\n\n");
+            for field in &self.required_fields {
+                doc.push_str(&format!("* `{}`\n", field.unraw()));
+            }
+        }
+        if self.validate_fn.is_some() {
+            doc.push_str(
+                "\nThe validation function is not called, so the built value may not pass it.\n",
+            );
+        }
+        doc
+    }
+
+    /// Add a field which must be set for the build to succeed.
+    pub fn push_required_field(&mut self, ident: &'a syn::Ident) -> &mut Self {
+        self.required_fields.push(ident);
+        self
+    }
+
+    /// Add a bound to the where-clause of the build method.
+    pub fn push_bound(&mut self, bound: syn::WherePredicate) -> &mut Self {
+        self.bounds.push(bound);
         self
     }
 }
@@ -142,13 +495,29 @@ macro_rules! default_build_method {
             ident: &syn::Ident::new("build", ::proc_macro2::Span::call_site()),
             visibility: ::std::borrow::Cow::Owned(syn::parse_quote!(pub)),
             pattern: BuilderPattern::Mutable,
+            receiver: None,
             target_ty: &syn::Ident::new("Foo", ::proc_macro2::Span::call_site()),
             target_ty_generics: None,
             error_ty: syn::parse_quote!(FooBuilderError),
             initializers: vec![quote!(foo: self.foo,)],
+            bind_fields: false,
+            bound_initializers: vec![],
+            unchecked: false,
+            unchecked_initializers: vec![],
+            expect: false,
+            expect_initializers: vec![],
             doc_comment: None,
             default_struct: None,
+            default_struct_update: false,
             validate_fn: None,
+            must_use: true,
+            bounds: vec![],
+            lazy: false,
+            report_all_missing: false,
+            required_fields: vec![],
+            infallible: false,
+            into: None,
+            result_type: None,
         }
     };
 }
@@ -157,6 +526,8 @@ macro_rules! default_build_method {
 mod tests {
     #[allow(unused_imports)]
     use super::*;
+    use default_initializer;
+    use FieldConversion;
 
     #[test]
     fn std() {
@@ -166,8 +537,9 @@ mod tests {
         assert_eq!(
             quote!(#build_method).to_string(),
             quote!(
+                #[must_use = "the built value or its error should be handled"]
                 pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
-                    Ok(Foo {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
                         foo: self.foo,
                     })
                 }
@@ -187,9 +559,217 @@ mod tests {
         assert_eq!(
             quote!(#build_method).to_string(),
             quote!(
+                #[must_use = "the built value or its error should be handled"]
                 pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
                     let __default: Foo = { Default::default() };
-                    Ok(Foo {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn default_struct_update() {
+        let mut build_method = default_build_method!();
+        let alt_default =
+            DefaultExpression::explicit::<syn::Expr>(parse_quote!(Default::default()));
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        build_method.default_struct = Some(&alt_default);
+        build_method.push_initializer(Initializer {
+            field_ident: &bar,
+            field_enabled: false,
+            use_default_struct: true,
+            ..default_initializer!()
+        });
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    let __default: Foo = { Default::default() };
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                        ..__default
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn bind_fields() {
+        let mut build_method = default_build_method!();
+        let ty: syn::Type = parse_quote!(u32);
+        build_method.initializers.clear();
+        build_method.bind_fields = true;
+        build_method.push_initializer(Initializer {
+            field_type: &ty,
+            conversion: FieldConversion::Move,
+            ..default_initializer!()
+        });
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok({
+                        let __field_foo: u32 = self.foo;
+                        Foo {
+                            foo: __field_foo,
+                        }
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn no_must_use() {
+        let mut build_method = default_build_method!();
+        build_method.must_use = false;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let mut build_method = default_build_method!();
+        build_method.push_bound(parse_quote!(Vec<u8>: Default));
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError>
+                where
+                    Vec<u8>: Default
+                {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn into() {
+        let explicit = BuildFnInto::Explicit(Box::new(parse_quote!(Arc<Foo>)));
+        let mut build_method = default_build_method!();
+        build_method.into = Some(&explicit);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Arc<Foo>, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(
+                        ::derive_builder::export::core::convert::From::from(Foo {
+                            foo: self.foo,
+                        })
+                    )
+                }
+            )
+            .to_string()
+        );
+
+        let inferred = BuildFnInto::Inferred;
+        build_method.into = Some(&inferred);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build<__BuilderInto: ::derive_builder::export::core::convert::From<Foo>>(&self)
+                    -> ::derive_builder::export::core::result::Result<__BuilderInto, FooBuilderError>
+                {
+                    ::derive_builder::export::core::result::Result::Ok(
+                        ::derive_builder::export::core::convert::From::from(Foo {
+                            foo: self.foo,
+                        })
+                    )
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn result_type() {
+        let result_type: syn::Type = parse_quote!(anyhow::Result<Foo>);
+        let mut build_method = default_build_method!();
+        build_method.result_type = Some(&result_type);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> anyhow::Result<Foo> {
+                    let __build = move || -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                        ::derive_builder::export::core::result::Result::Ok(Foo {
+                            foo: self.foo,
+                        })
+                    };
+                    ::derive_builder::export::core::result::Result::Ok(__build()?)
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn receiver() {
+        let mut build_method = default_build_method!();
+        build_method.receiver = Some(BuildFnReceiver::Owned);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+            )
+            .to_string()
+        );
+
+        build_method.receiver = Some(BuildFnReceiver::RefMut);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&mut self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
                         foo: self.foo,
                     })
                 }
@@ -198,6 +778,195 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lazy() {
+        let mut build_method = default_build_method!();
+        build_method.lazy = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&mut self) -> ::derive_builder::export::core::result::Result<
+                    ::derive_builder::export::core::sync::Arc<Foo>,
+                    FooBuilderError
+                > {
+                    if let ::derive_builder::export::core::option::Option::Some(ref cached) = self.__build_cache {
+                        return ::derive_builder::export::core::result::Result::Ok(::derive_builder::export::core::sync::Arc::clone(cached));
+                    }
+                    let built = ::derive_builder::export::core::sync::Arc::new(Foo {
+                        foo: self.foo,
+                    });
+                    self.__build_cache = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::sync::Arc::clone(&built)
+                    );
+                    ::derive_builder::export::core::result::Result::Ok(built)
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn report_all_missing() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new_raw("bar", Span::call_site());
+        let mut build_method = default_build_method!();
+        build_method.report_all_missing = true;
+        build_method.push_required_field(&foo);
+        build_method.push_required_field(&bar);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+                    if self.foo.is_none() {
+                        __missing_fields.push("foo");
+                    }
+                    if self.r#bar.is_none() {
+                        __missing_fields.push("bar");
+                    }
+                    if !__missing_fields.is_empty() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            FooBuilderError::UninitializedFields(__missing_fields)
+                        );
+                    }
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn first_only() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let mut build_method = default_build_method!();
+        build_method.push_required_field(&foo);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn infallible() {
+        let mut build_method = default_build_method!();
+        build_method.infallible = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use]
+                pub fn build(&self) -> Foo {
+                    Foo {
+                        foo: self.foo,
+                    }
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn unchecked() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let mut build_method = default_build_method!();
+        build_method.unchecked = true;
+        build_method.push_required_field(&foo);
+        build_method
+            .unchecked_initializers
+            .push(quote!(foo: self.foo,));
+
+        let expected_doc = doc_comment_from(
+            "Builds a new `Foo` without checking that required fields are set.\n\n\
+             # Safety\n\n\
+             The caller must ensure that the following fields are set:\n\n\
+             * `foo`\n"
+                .to_string(),
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+
+                #expected_doc
+                #[must_use]
+                pub unsafe fn build_unchecked(&self) -> Foo {
+                    Foo {
+                        foo: self.foo,
+                    }
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn expect() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let mut build_method = default_build_method!();
+        build_method.expect = true;
+        build_method.push_required_field(&foo);
+        build_method
+            .expect_initializers
+            .push(quote!(foo: self.foo,));
+
+        let expected_doc = doc_comment_from(
+            "Builds a new `Foo`, panicking if a required field is unset.\n\n\
+             # Panics\n\n\
+             Panics unless the following fields are set:\n\n\
+             * `foo`\n"
+                .to_string(),
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#build_method).to_string(),
+            quote!(
+                #[must_use = "the built value or its error should be handled"]
+                pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
+                        foo: self.foo,
+                    })
+                }
+
+                #expected_doc
+                #[must_use]
+                pub fn build_expect(&self) -> Foo {
+                    Foo {
+                        foo: self.foo,
+                    }
+                }
+            )
+            .to_string()
+        );
+    }
+
     #[test]
     fn skip() {
         let mut build_method = default_build_method!();
@@ -217,8 +986,9 @@ mod tests {
         assert_eq!(
             quote!(#build_method).to_string(),
             quote!(
+                #[must_use = "the built value or its error should be handled"]
                 pub fn finish(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
-                    Ok(Foo {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
                         foo: self.foo,
                     })
                 }
@@ -238,10 +1008,11 @@ mod tests {
         assert_eq!(
             quote!(#build_method).to_string(),
             quote!(
+                #[must_use = "the built value or its error should be handled"]
                 pub fn build(&self) -> ::derive_builder::export::core::result::Result<Foo, FooBuilderError> {
                     IpsumBuilder::validate(&self)?;
 
-                    Ok(Foo {
+                    ::derive_builder::export::core::result::Result::Ok(Foo {
                         foo: self.foo,
                     })
                 }
@@ -1,7 +1,9 @@
 use std::borrow::Cow;
 
-use proc_macro2::TokenStream;
+use allow_lints;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::{self, Path, TraitBound, TraitBoundModifier, TypeParamBound};
 
@@ -10,7 +12,10 @@ use BuildMethod;
 use BuilderField;
 use BuilderPattern;
 use DeprecationNotes;
+use FromEnv;
+use InheritedSetters;
 use Setter;
+use BUILD_CACHE_FIELD_NAME;
 
 /// Builder, implementing `quote::ToTokens`.
 ///
@@ -34,11 +39,10 @@ use Setter;
 /// #       quote!(#builder).to_string(),
 /// #       {
 /// #           let mut result = quote!();
-/// #           #[cfg(not(feature = "clippy"))]
-/// #           result.append_all(quote!(#[allow(clippy::all)]));
 /// #
 /// #           result.append_all(quote!(
 /// #[derive(Clone)]
+/// #[must_use]
 /// pub struct FooBuilder {
 ///     foo: u32,
 /// }
@@ -58,8 +62,6 @@ use Setter;
 /// #[cfg(not(no_std))]
 /// impl std::error::Error for FooBuilderError {}
 /// #           ));
-/// #           #[cfg(not(feature = "clippy"))]
-/// #           result.append_all(quote!(#[allow(clippy::all)]));
 /// #
 /// #           result.append_all(quote!(
 ///
@@ -93,7 +95,7 @@ pub struct Builder<'a> {
     /// Pattern of this builder struct.
     pub pattern: BuilderPattern,
     /// Traits to automatically derive on the builder type.
-    pub derives: &'a [Path],
+    pub derives: Vec<&'a Path>,
     /// Attributes to include on the builder `struct` declaration.
     pub struct_attrs: &'a [syn::Attribute],
     /// Attributes to include on the builder's inherent `impl` block.
@@ -102,12 +104,24 @@ pub struct Builder<'a> {
     ///
     /// Note that the name of `create_empty` can be overridden; see the `create_empty` field for more.
     pub impl_default: bool,
+    /// Initializers of the builder fields which `impl Default` sets from the target struct's
+    /// `Default::default()` value, e.g. `foo: Some(value.foo),`; see `#[builder(default_struct)]`.
+    ///
+    /// When empty, `impl Default` leaves every field unset.
+    pub default_struct_initializers: Vec<TokenStream>,
     /// The identifier of the inherent method that creates a builder with all fields set to
     /// `None` or `PhantomData`.
     ///
     /// This method will be invoked by `impl Default` for the builder, but it is also accessible
     /// to `impl` blocks on the builder that expose custom constructors.
     pub create_empty: syn::Ident,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// The identifier of the inherent method on the target struct returning a default builder,
+    /// e.g. `Foo::builder()`. Only emitted along with `impl Default` for the builder.
+    pub builder_method: Option<&'a syn::Ident>,
+    /// Visibility of the target struct, which `builder_method` shares.
+    pub target_visibility: &'a syn::Visibility,
     /// Type parameters and lifetimes attached to this builder's struct
     /// definition.
     pub generics: Option<&'a syn::Generics>,
@@ -123,6 +137,8 @@ pub struct Builder<'a> {
     pub field_initializers: Vec<TokenStream>,
     /// Functions of the builder struct, e.g. `fn bar() -> { unimplemented!() }`
     pub functions: Vec<TokenStream>,
+    /// Type aliases emitted next to the builder struct, e.g. `pub type FooPort = u16;`
+    pub type_aliases: Vec<TokenStream>,
     /// Whether or not a generated error type is required.
     ///
     /// This would be `false` in the case where an already-existing error is to be used.
@@ -138,6 +154,18 @@ pub struct Builder<'a> {
     pub deprecation_notes: DeprecationNotes,
     /// Whether or not a libstd is used.
     pub std: bool,
+    /// Whether or not the generated code may allocate, which `no_std(static_str_errors)` rules
+    /// out.
+    ///
+    /// Without it, the generated error stores validation messages as `&'static str`.
+    pub alloc: bool,
+    /// Whether the builder struct is marked `#[must_use]`.
+    pub must_use: bool,
+    /// Whether the builder struct is marked `#[repr(transparent)]`, because it wraps a single
+    /// field besides `PhantomData`.
+    pub transparent: bool,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
 }
 
 impl<'a> ToTokens for Builder<'a> {
@@ -156,6 +184,7 @@ impl<'a> ToTokens for Builder<'a> {
             let builder_field_initializers = &self.field_initializers;
             let create_empty = &self.create_empty;
             let functions = &self.functions;
+            let type_aliases = &self.type_aliases;
 
             // Create the comma-separated set of derived traits for the builder
             let derive_attr = {
@@ -165,7 +194,7 @@ impl<'a> ToTokens for Builder<'a> {
                 if self.must_derive_clone {
                     traits.push(&clone_trait);
                 }
-                traits.extend(self.derives);
+                traits.extend(self.derives.iter().cloned());
 
                 if traits.is_empty() {
                     quote!()
@@ -178,10 +207,25 @@ impl<'a> ToTokens for Builder<'a> {
             let impl_attrs = self.impl_attrs;
 
             let builder_doc_comment = &self.doc_comment;
+            let must_use = if self.must_use {
+                quote!(#[must_use])
+            } else {
+                quote!()
+            };
+            let repr = if self.transparent {
+                quote!(#[repr(transparent)])
+            } else {
+                quote!()
+            };
             let deprecation_notes = &self.deprecation_notes.as_item();
+            // With a custom constructor, the empty builder is only for the user's own impls.
+            let create_empty_vis = if self.impl_default {
+                quote!(#builder_vis)
+            } else {
+                quote!()
+            };
 
-            #[cfg(not(feature = "clippy"))]
-            tokens.append_all(quote!(#[allow(clippy::all)]));
+            tokens.append_all(allow_lints(self.allowed_lints));
 
             // struct_attrs MUST come after derive_attr, otherwise attributes for a derived
             // trait will appear before its derivation. As of rustc 1.59.0 this is a compiler
@@ -190,13 +234,16 @@ impl<'a> ToTokens for Builder<'a> {
                 #derive_attr
                 #(#struct_attrs)*
                 #builder_doc_comment
+                #must_use
+                #repr
                 #builder_vis struct #builder_ident #struct_generics #where_clause {
                     #(#builder_fields)*
                 }
+
+                #(#type_aliases)*
             ));
 
-            #[cfg(not(feature = "clippy"))]
-            tokens.append_all(quote!(#[allow(clippy::all)]));
+            tokens.append_all(allow_lints(self.allowed_lints));
 
             tokens.append_all(quote!(
                 #(#impl_attrs)*
@@ -206,7 +253,10 @@ impl<'a> ToTokens for Builder<'a> {
                     #deprecation_notes
 
                     /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                    fn #create_empty() -> Self {
+                    ///
+                    /// Unlike the build method, this ignores default values; use it as a
+                    /// blank-slate constructor in tests.
+                    #create_empty_vis fn #create_empty() -> Self {
                         Self {
                             #(#builder_field_initializers)*
                         }
@@ -214,47 +264,130 @@ impl<'a> ToTokens for Builder<'a> {
                 }
             ));
 
+            let allow_attr = allow_lints(self.allowed_lints);
+
             if self.impl_default {
+                let mut default_generics = self.generics.cloned().unwrap_or_default();
+                let default_body = if self.default_struct_initializers.is_empty() {
+                    quote!(Self::#create_empty())
+                } else {
+                    let target_ident = self.target_ident;
+                    let initializers = &self.default_struct_initializers;
+                    // Builder fields without a value in the struct are left empty.
+                    let other_fields = if initializers.len() < builder_field_initializers.len() {
+                        quote!(..Self::#create_empty())
+                    } else {
+                        quote!()
+                    };
+                    if !default_generics.params.is_empty() {
+                        default_generics.make_where_clause().predicates.push(parse_quote!(
+                            #target_ident #ty_generics: ::derive_builder::export::core::default::Default
+                        ));
+                    }
+
+                    quote!(
+                        let value: #target_ident #ty_generics = ::derive_builder::export::core::default::Default::default();
+                        Self {
+                            #(#initializers)*
+                            #other_fields
+                        }
+                    )
+                };
+                let default_where_clause = &default_generics.where_clause;
+
                 tokens.append_all(quote!(
-                    impl #impl_generics ::derive_builder::export::core::default::Default for #builder_ident #ty_generics #where_clause {
+                    #allow_attr
+                    impl #impl_generics ::derive_builder::export::core::default::Default for #builder_ident #ty_generics #default_where_clause {
                         fn default() -> Self {
-                            Self::#create_empty()
+                            #default_body
                         }
                     }
                 ));
+
+                if let Some(builder_method) = self.builder_method {
+                    let target_ident = self.target_ident;
+                    let target_vis = self.target_visibility;
+                    let doc = format!("Create an empty [`{}`].", builder_ident);
+
+                    tokens.append_all(quote!(
+                        #allow_attr
+                        #[allow(dead_code)]
+                        impl #impl_generics #target_ident #ty_generics #default_where_clause {
+                            #[doc = #doc]
+                            #target_vis fn #builder_method() -> #builder_ident #ty_generics {
+                                ::derive_builder::export::core::default::Default::default()
+                            }
+                        }
+                    ));
+                }
             }
 
             if self.generate_error {
                 let builder_error_ident = format_ident!("{}Error", builder_ident);
                 let builder_error_doc = format!("Error type for {}", builder_ident);
+                // Without an allocator, validation errors can only carry static messages.
+                let validation_error_ty = if self.alloc {
+                    quote!(::derive_builder::export::core::string::String)
+                } else {
+                    quote!(&'static str)
+                };
+
+                // Reporting every missing field at once needs a `Vec` to hold them.
+                let (uninitialized_fields_variant, uninitialized_fields_display) = if self.alloc {
+                    (
+                        quote!(
+                            /// Uninitialized fields, all reported at once
+                            UninitializedFields(::derive_builder::export::core::vec::Vec<&'static str>),
+                        ),
+                        quote!(
+                            Self::UninitializedFields(ref fields) => {
+                                for (i, field) in fields.iter().enumerate() {
+                                    if i > 0 {
+                                        write!(f, ", ")?;
+                                    }
+                                    write!(f, "`{}`", field)?;
+                                }
+                                write!(f, " must be initialized")
+                            }
+                        ),
+                    )
+                } else {
+                    (quote!(), quote!())
+                };
 
                 tokens.append_all(quote!(
+                    #allow_attr
                     #[doc=#builder_error_doc]
                     #[derive(Debug)]
                     #[non_exhaustive]
                     #builder_vis enum #builder_error_ident {
                         /// Uninitialized field
                         UninitializedField(&'static str),
+                        #uninitialized_fields_variant
                         /// Custom validation error
-                        ValidationError(::derive_builder::export::core::string::String),
+                        ValidationError(#validation_error_ty),
                     }
 
+                    #allow_attr
                     impl ::derive_builder::export::core::convert::From<::derive_builder::UninitializedFieldError> for #builder_error_ident {
                         fn from(s: ::derive_builder::UninitializedFieldError) -> Self {
                             Self::UninitializedField(s.field_name())
                         }
                     }
 
-                    impl ::derive_builder::export::core::convert::From<::derive_builder::export::core::string::String> for #builder_error_ident {
-                        fn from(s: ::derive_builder::export::core::string::String) -> Self {
+                    #allow_attr
+                    impl ::derive_builder::export::core::convert::From<#validation_error_ty> for #builder_error_ident {
+                        fn from(s: #validation_error_ty) -> Self {
                             Self::ValidationError(s)
                         }
                     }
 
+                    #allow_attr
                     impl ::derive_builder::export::core::fmt::Display for #builder_error_ident {
                         fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
                             match self {
                                 Self::UninitializedField(ref field) => write!(f, "`{}` must be initialized", field),
+                                #uninitialized_fields_display
                                 Self::ValidationError(ref error) => write!(f, "{}", error),
                             }
                         }
@@ -263,6 +396,7 @@ impl<'a> ToTokens for Builder<'a> {
 
                 if self.std {
                     tokens.append_all(quote!(
+                        #allow_attr
                         impl std::error::Error for #builder_error_ident {}
                     ));
                 }
@@ -285,6 +419,132 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Set a field from the target struct's default value in `impl Default` for a
+    /// `default_struct` builder.
+    pub fn push_default_struct_field(&mut self, ident: &syn::Ident) -> &mut Self {
+        self.default_struct_initializers.push(quote!(
+            #ident: ::derive_builder::export::core::option::Option::Some(value.#ident),
+        ));
+        self
+    }
+
+    /// Add the field in which a lazy build method caches its result, and the
+    /// `clear_build_cache` method for changes the setters do not see.
+    pub fn push_build_cache(&mut self, ty: syn::Type) -> &mut Self {
+        let ident = syn::Ident::new(BUILD_CACHE_FIELD_NAME, Span::call_site());
+        let vis = &self.visibility;
+        self.fields.push(quote!(
+            #ident: ::derive_builder::export::core::option::Option<#ty>,
+        ));
+        self.field_initializers.push(quote!(
+            #ident: ::derive_builder::export::core::option::Option::None,
+        ));
+        self.functions.push(quote!(
+            /// Forget the value cached by the build method, so that the next build builds a
+            /// fresh value. The generated setters do this themselves; call it after changing a
+            /// field in any other way, e.g. in a custom setter.
+            #vis fn clear_build_cache(&mut self) {
+                self.#ident = ::derive_builder::export::core::option::Option::None;
+            }
+        ));
+        self
+    }
+
+    /// Add the `FIELD_NAMES` associated constant, listing the names of the target struct's
+    /// fields in declaration order.
+    pub fn push_field_names(&mut self, names: Vec<String>) -> &mut Self {
+        let vis = &self.visibility;
+        self.functions.push(quote!(
+            /// Names of the fields of the built struct, in declaration order.
+            #vis const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+        ));
+        self
+    }
+
+    /// Add the `with_base` method, moving the value of each of `field_idents` from another
+    /// builder unless the field is set, and clearing the cache of a lazy build method if
+    /// `has_build_cache`.
+    pub fn push_struct_update(
+        &mut self,
+        field_idents: &[&syn::Ident],
+        has_build_cache: bool,
+    ) -> &mut Self {
+        let vis = &self.visibility;
+        let clear_cache = if has_build_cache {
+            let cache = syn::Ident::new(BUILD_CACHE_FIELD_NAME, Span::call_site());
+            quote!(self.#cache = ::derive_builder::export::core::option::Option::None;)
+        } else {
+            quote!()
+        };
+        let (self_param, update) = if field_idents.is_empty() && !has_build_cache {
+            (quote!(self), quote!(let _ = base;))
+        } else {
+            (
+                quote!(mut self),
+                quote!(#(
+                    if self.#field_idents.is_none() {
+                        self.#field_idents = base.#field_idents;
+                    }
+                )*),
+            )
+        };
+        self.functions.push(quote!(
+            /// Fill the fields which are unset in this builder with those of `base`, like struct
+            /// update syntax `..base`.
+            ///
+            /// The fields set in this builder take precedence over those of `base`. Fields
+            /// which are not stored as an `Option`, such as those with a custom builder type,
+            /// keep the value of this builder.
+            #vis fn with_base(#self_param, base: Self) -> Self {
+                #update
+                #clear_cache
+                self
+            }
+        ));
+        self
+    }
+
+    /// Add a method telling whether the builder field `field_ident` is set.
+    pub fn push_has_method(&mut self, ident: syn::Ident, field_ident: &syn::Ident) -> &mut Self {
+        let vis = &self.visibility;
+        let doc = format!("Whether `{}` is set.", field_ident.unraw());
+        self.functions.push(quote!(
+            #[doc = #doc]
+            #[inline]
+            #vis fn #ident(&self) -> bool {
+                self.#field_ident.is_some()
+            }
+        ));
+        self
+    }
+
+    /// Add a type alias used as the parameter type of a setter.
+    pub fn push_type_alias(
+        &mut self,
+        vis: &syn::Visibility,
+        ident: &syn::Ident,
+        ty: &syn::Type,
+    ) -> &mut Self {
+        let doc = format!("Parameter type of the `{}` setters.", self.ident);
+        self.type_aliases.push(quote!(
+            #[doc = #doc]
+            #vis type #ident = #ty;
+        ));
+        self
+    }
+
+    /// Add the `from_env` constructor to the builder.
+    pub fn push_from_env(&mut self, f: FromEnv) -> &mut Self {
+        self.functions.push(quote!(#f));
+        self
+    }
+
+    /// Add the forwarders for the setters of an inherited builder.
+    pub fn push_inherited_setters(&mut self, f: InheritedSetters) -> &mut Self {
+        self.functions.push(quote!(#f));
+        self
+    }
+
     /// Add a setter function to the builder
     pub fn push_setter_fn(&mut self, f: Setter) -> &mut Self {
         self.functions.push(quote!(#f));
@@ -302,28 +562,37 @@ impl<'a> Builder<'a> {
     /// `Clone` impl. This is the same as how the built-in derives for
     /// `Clone`, `Default`, `PartialEq`, and other traits work.
     fn compute_impl_bounds(&self) -> syn::Generics {
-        if let Some(type_gen) = self.generics {
-            let mut generics = type_gen.clone();
-
-            if !self.pattern.requires_clone() || type_gen.type_params().next().is_none() {
-                return generics;
-            }
+        impl_bounds(self.generics, self.pattern)
+    }
+}
 
-            let clone_bound = TypeParamBound::Trait(TraitBound {
-                paren_token: None,
-                modifier: TraitBoundModifier::None,
-                lifetimes: None,
-                path: syn::parse_quote!(::derive_builder::export::core::clone::Clone),
-            });
+/// The generics of an impl block calling the setters of a builder with `pattern`, see
+/// `Builder::compute_impl_bounds`.
+pub(crate) fn impl_bounds(
+    generics: Option<&syn::Generics>,
+    pattern: BuilderPattern,
+) -> syn::Generics {
+    if let Some(type_gen) = generics {
+        let mut generics = type_gen.clone();
+
+        if !pattern.requires_clone() || type_gen.type_params().next().is_none() {
+            return generics;
+        }
 
-            for typ in generics.type_params_mut() {
-                typ.bounds.push(clone_bound.clone());
-            }
+        let clone_bound = TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path: syn::parse_quote!(::derive_builder::export::core::clone::Clone),
+        });
 
-            generics
-        } else {
-            Default::default()
+        for typ in generics.type_params_mut() {
+            typ.bounds.push(clone_bound.clone());
         }
+
+        generics
+    } else {
+        Default::default()
     }
 }
 
@@ -337,21 +606,33 @@ macro_rules! default_builder {
             enabled: true,
             ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
             pattern: Default::default(),
-            derives: &vec![],
-            struct_attrs: &vec![],
-            impl_attrs: &vec![],
+            derives: vec![],
+            struct_attrs: &[],
+            impl_attrs: &[],
             impl_default: true,
+            default_struct_initializers: vec![],
             create_empty: syn::Ident::new("create_empty", ::proc_macro2::Span::call_site()),
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_method: None,
+            target_visibility: Box::leak(Box::new(parse_quote!(pub))),
             generics: None,
             visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
             fields: vec![quote!(foo: u32,)],
             field_initializers: vec![quote!(foo: ::derive_builder::export::core::default::Default::default(), )],
             functions: vec![quote!(fn bar() -> { unimplemented!() })],
+            type_aliases: vec![],
             generate_error: true,
             must_derive_clone: true,
             doc_comment: None,
             deprecation_notes: DeprecationNotes::default(),
             std: true,
+            alloc: true,
+            must_use: true,
+            transparent: false,
+            allowed_lints: &[],
         }
     };
 }
@@ -371,6 +652,8 @@ mod tests {
             pub enum FooBuilderError {
                 /// Uninitialized field
                 UninitializedField(&'static str),
+                /// Uninitialized fields, all reported at once
+                UninitializedFields(::derive_builder::export::core::vec::Vec<&'static str>),
                 /// Custom validation error
                 ValidationError(::derive_builder::export::core::string::String),
             }
@@ -391,6 +674,15 @@ mod tests {
                 fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
                     match self {
                         Self::UninitializedField(ref field) => write!(f, "`{}` must be initialized", field),
+                        Self::UninitializedFields(ref fields) => {
+                            for (i, field) in fields.iter().enumerate() {
+                                if i > 0 {
+                                    write!(f, ", ")?;
+                                }
+                                write!(f, "`{}`", field)?;
+                            }
+                            write!(f, " must be initialized")
+                        }
                         Self::ValidationError(ref error) => write!(f, "{}", error),
                     }
                 }
@@ -409,19 +701,14 @@ mod tests {
             {
                 let mut result = quote!();
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[derive(Clone)]
+                    #[must_use]
                     pub struct FooBuilder {
                         foo: u32,
                     }
                 ));
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[allow(dead_code)]
                     impl FooBuilder {
@@ -430,7 +717,10 @@ mod tests {
                         }
 
                         /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                        fn create_empty() -> Self {
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
                             Self {
                                 foo: ::derive_builder::export::core::default::Default::default(),
                             }
@@ -462,19 +752,14 @@ mod tests {
             {
                 let mut result = quote!();
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[derive(Clone)]
+                    #[must_use]
                     pub struct FooBuilder {
                         foo: u32,
                     }
                 ));
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[allow(dead_code)]
                     impl FooBuilder {
@@ -483,7 +768,10 @@ mod tests {
                         }
 
                         /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                        fn empty() -> Self {
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn empty() -> Self {
                             Self {
                                 foo: ::derive_builder::export::core::default::Default::default(),
                             }
@@ -522,19 +810,14 @@ mod tests {
             {
                 let mut result = quote!();
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[derive(Clone)]
+                    #[must_use]
                     pub struct FooBuilder<'a, T: Debug> where T: PartialEq {
                         foo: u32,
                     }
                 ));
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[allow(dead_code)]
                     impl<'a, T: Debug + ::derive_builder::export::core::clone::Clone> FooBuilder<'a, T> where T: PartialEq {
@@ -543,7 +826,10 @@ mod tests {
                         }
 
                         /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                        fn create_empty() -> Self {
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
                             Self {
                                 foo: ::derive_builder::export::core::default::Default::default(),
                             }
@@ -564,6 +850,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn const_generic() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Lorem<T: Debug, const N: usize = 4> { }
+        };
+        let generics = ast.generics;
+        let mut builder = default_builder!();
+        builder.generics = Some(&generics);
+
+        assert_eq!(
+            quote!(#builder).to_string(),
+            {
+                let mut result = quote!();
+
+                result.append_all(quote!(
+                    #[derive(Clone)]
+                    #[must_use]
+                    pub struct FooBuilder<T: Debug, const N: usize> {
+                        foo: u32,
+                    }
+                ));
+
+                result.append_all(quote!(
+                    #[allow(dead_code)]
+                    impl<T: Debug + ::derive_builder::export::core::clone::Clone, const N: usize> FooBuilder<T, N> {
+                        fn bar() -> {
+                            unimplemented!()
+                        }
+
+                        /// Create an empty builder, with all fields set to `None` or `PhantomData`.
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
+                            Self {
+                                foo: ::derive_builder::export::core::default::Default::default(),
+                            }
+                        }
+                    }
+
+                    impl<T: Debug + ::derive_builder::export::core::clone::Clone, const N: usize> ::derive_builder::export::core::default::Default for FooBuilder<T, N> {
+                        fn default() -> Self {
+                            Self::create_empty()
+                        }
+                    }
+                ));
+
+                add_generated_error(&mut result);
+
+                result
+            }.to_string()
+        );
+    }
+
+    #[test]
+    fn builder_method() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Lorem<T: Debug> { }
+        };
+        let generics = ast.generics;
+        let builder_method = syn::Ident::new("new_builder", proc_macro2::Span::call_site());
+        let target_visibility: syn::Visibility = parse_quote!(pub(crate));
+        let mut builder = default_builder!();
+        builder.generics = Some(&generics);
+        builder.builder_method = Some(&builder_method);
+        builder.target_visibility = &target_visibility;
+
+        assert_eq!(
+            quote!(#builder).to_string(),
+            {
+                let mut result = quote!();
+
+                result.append_all(quote!(
+                    #[derive(Clone)]
+                    #[must_use]
+                    pub struct FooBuilder<T: Debug> {
+                        foo: u32,
+                    }
+                ));
+
+                result.append_all(quote!(
+                    #[allow(dead_code)]
+                    impl<T: Debug + ::derive_builder::export::core::clone::Clone> FooBuilder<T> {
+                        fn bar() -> {
+                            unimplemented!()
+                        }
+
+                        /// Create an empty builder, with all fields set to `None` or `PhantomData`.
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
+                            Self {
+                                foo: ::derive_builder::export::core::default::Default::default(),
+                            }
+                        }
+                    }
+
+                    impl<T: Debug + ::derive_builder::export::core::clone::Clone> ::derive_builder::export::core::default::Default for FooBuilder<T> {
+                        fn default() -> Self {
+                            Self::create_empty()
+                        }
+                    }
+                ));
+
+                result.append_all(quote!(
+                    #[allow(dead_code)]
+                    impl<T: Debug + ::derive_builder::export::core::clone::Clone> Foo<T> {
+                        #[doc = "Create an empty [`FooBuilder`]."]
+                        pub(crate) fn new_builder() -> FooBuilder<T> {
+                            ::derive_builder::export::core::default::Default::default()
+                        }
+                    }
+                ));
+
+                add_generated_error(&mut result);
+
+                result
+            }.to_string()
+        );
+    }
+
     // This test depends on the exact formatting of the `stringify`'d code,
     // so we don't automatically format the test
     #[rustfmt::skip]
@@ -582,19 +990,14 @@ mod tests {
             {
                 let mut result = quote!();
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[derive(Clone)]
+                    #[must_use]
                     pub struct FooBuilder<'a, T: 'a + Default> where T: PartialEq {
                         foo: u32,
                     }
                 ));
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[allow(dead_code)]
                     impl<'a, T: 'a + Default + ::derive_builder::export::core::clone::Clone> FooBuilder<'a, T>
@@ -604,9 +1007,12 @@ mod tests {
                         fn bar() -> {
                             unimplemented!()
                         }
-                        
+
                         /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                        fn create_empty() -> Self {
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
                             Self {
                                 foo: ::derive_builder::export::core::default::Default::default(),
                             }
@@ -646,18 +1052,13 @@ mod tests {
             {
                 let mut result = quote!();
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
+                    #[must_use]
                     pub struct FooBuilder<'a, T: Debug> where T: PartialEq {
                         foo: u32,
                     }
                 ));
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[allow(dead_code)]
                     impl<'a, T: Debug> FooBuilder<'a, T> where T: PartialEq {
@@ -666,7 +1067,10 @@ mod tests {
                         }
 
                         /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                        fn create_empty() -> Self {
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
                             Self {
                                 foo: ::derive_builder::export::core::default::Default::default(),
                             }
@@ -688,6 +1092,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn no_std_static_str_errors() {
+        let mut builder = default_builder!();
+        builder.std = false;
+        builder.alloc = false;
+        let tokens = quote!(#builder).to_string();
+
+        assert!(tokens.contains(&quote!(ValidationError(&'static str)).to_string()));
+        assert!(!tokens.contains("String"));
+        assert!(!tokens.contains("std :: error :: Error"));
+    }
+
+    #[test]
+    fn field_names() {
+        let mut builder = default_builder!();
+        builder.functions.clear();
+        builder.push_field_names(vec!["foo".to_string(), "bar".to_string()]);
+        let tokens = quote!(#builder).to_string();
+
+        assert!(tokens.contains(
+            &quote!(
+                /// Names of the fields of the built struct, in declaration order.
+                pub const FIELD_NAMES: &'static [&'static str] = &["foo", "bar"];
+            )
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn struct_update() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let mut builder = default_builder!();
+        builder.functions.clear();
+        builder.push_struct_update(&[&foo], false);
+        let tokens = quote!(#builder).to_string();
+
+        assert!(tokens.contains(
+            &quote!(
+                /// Fill the fields which are unset in this builder with those of `base`, like struct
+                /// update syntax `..base`.
+                ///
+                /// The fields set in this builder take precedence over those of `base`. Fields
+                /// which are not stored as an `Option`, such as those with a custom builder type,
+                /// keep the value of this builder.
+                pub fn with_base(mut self, base: Self) -> Self {
+                    if self.foo.is_none() {
+                        self.foo = base.foo;
+                    }
+                    self
+                }
+            )
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn default_struct() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let generics: syn::Generics = parse_quote!(<T>);
+        let mut builder = default_builder!();
+        builder.generics = Some(&generics);
+        builder
+            .field_initializers
+            .push(quote!(__build_cache: None,));
+        builder.push_default_struct_field(&foo);
+        let tokens = quote!(#builder).to_string();
+        assert!(tokens.contains(
+            &quote!(
+                impl<T: ::derive_builder::export::core::clone::Clone>
+                    ::derive_builder::export::core::default::Default for FooBuilder<T>
+                where
+                    Foo<T>: ::derive_builder::export::core::default::Default
+            )
+            .to_string()
+        ));
+        assert!(tokens.contains(
+            &quote!(
+                fn default() -> Self {
+                    let value: Foo<T> = ::derive_builder::export::core::default::Default::default();
+                    Self {
+                        foo: ::derive_builder::export::core::option::Option::Some(value.foo),
+                        ..Self::create_empty()
+                    }
+                }
+            )
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn has_method() {
+        let mut builder = default_builder!();
+        builder.functions.clear();
+        builder.push_has_method(
+            syn::Ident::new("has_foo", Span::call_site()),
+            &syn::Ident::new("foo", Span::call_site()),
+        );
+        let tokens = quote!(#builder).to_string();
+
+        assert!(tokens.contains(
+            &quote!(
+                #[doc = "Whether `foo` is set."]
+                #[inline]
+                pub fn has_foo(&self) -> bool {
+                    self.foo.is_some()
+                }
+            )
+            .to_string()
+        ));
+    }
+
     #[test]
     fn disabled() {
         let mut builder = default_builder!();
@@ -698,28 +1213,23 @@ mod tests {
 
     #[test]
     fn add_derives() {
-        let derives = vec![parse_quote!(Serialize)];
+        let derives: Vec<Path> = vec![parse_quote!(Serialize)];
         let mut builder = default_builder!();
-        builder.derives = &derives;
+        builder.derives = derives.iter().collect();
 
         assert_eq!(
             quote!(#builder).to_string(),
             {
                 let mut result = quote!();
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[derive(Clone, Serialize)]
+                    #[must_use]
                     pub struct FooBuilder {
                         foo: u32,
                     }
                 ));
 
-                #[cfg(not(feature = "clippy"))]
-                result.append_all(quote!(#[allow(clippy::all)]));
-
                 result.append_all(quote!(
                     #[allow(dead_code)]
                     impl FooBuilder {
@@ -728,7 +1238,10 @@ mod tests {
                         }
 
                         /// Create an empty builder, with all fields set to `None` or `PhantomData`.
-                        fn create_empty() -> Self {
+                        ///
+                        /// Unlike the build method, this ignores default values; use it as a
+                        /// blank-slate constructor in tests.
+                        pub fn create_empty() -> Self {
                             Self {
                                 foo: ::derive_builder::export::core::default::Default::default(),
                             }
@@ -68,6 +68,9 @@ pub enum BuilderFieldType<'a> {
     Optional(&'a syn::Type),
     /// The corresponding builder field will be just this type
     Precise(&'a syn::Type),
+    /// The corresponding builder field will be `Option<Box<dyn FnOnce() -> field_type>>`, called
+    /// by the build method, see `#[builder(setter(lazy = "..."))]`.
+    Lazy(&'a syn::Type),
     /// The corresponding builder field will be a PhantomData
     ///
     /// We do this if if the field is disabled.  We mustn't just completely omit the field from the builder:
@@ -85,11 +88,12 @@ impl<'a> BuilderFieldType<'a> {
     ///
     /// Return value:
     ///  * `.0`: type of the argument to the setter function
-    ///          (before application of `strip_option`, `into`)
-    ///  * `.1`: whether the builder field is `Option<type>` rather than just `type`
-    pub fn setter_type_info(&'a self) -> (&'a syn::Type, bool) {
-        match self {
-            BuilderFieldType::Optional(ty) => (ty, true),
+    ///    (before application of `strip_option`, `into`)
+    ///  * `.1`: whether the builder field is `Option<type>`, or an `Option` of a closure
+    ///    returning `type`, rather than just `type`
+    pub fn setter_type_info(&self) -> (&'a syn::Type, bool) {
+        match *self {
+            BuilderFieldType::Optional(ty) | BuilderFieldType::Lazy(ty) => (ty, true),
             BuilderFieldType::Precise(ty) => (ty, false),
             BuilderFieldType::Phantom(_ty) => panic!("phantom fields should never have setters"),
         }
@@ -103,6 +107,15 @@ impl<'a> ToTokens for BuilderFieldType<'a> {
                 ::derive_builder::export::core::option::Option<#ty>
             )),
             BuilderFieldType::Precise(ty) => ty.to_tokens(tokens),
+            // The edition 2015 parses `dyn ::path` as a path, but not `dyn 'static + ::path`,
+            // which is the same type.
+            BuilderFieldType::Lazy(ty) => tokens.append_all(quote!(
+                ::derive_builder::export::core::option::Option<
+                    ::derive_builder::export::core::boxed::Box<
+                        dyn 'static + ::derive_builder::export::core::ops::FnOnce() -> #ty
+                    >
+                >
+            )),
             BuilderFieldType::Phantom(ty) => tokens.append_all(quote!(
                 ::derive_builder::export::core::marker::PhantomData<#ty>
             )),
@@ -178,4 +191,18 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn borrowed_field() {
+        let mut field = default_builder_field!();
+        field.field_type = BuilderFieldType::Optional(Box::leak(Box::new(parse_quote!(&'a str))));
+
+        assert_eq!(
+            quote!(#field).to_string(),
+            quote!(
+                #[some_attr] pub foo: ::derive_builder::export::core::option::Option<&'a str>,
+            )
+            .to_string()
+        );
+    }
 }
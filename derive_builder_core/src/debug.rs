@@ -0,0 +1,221 @@
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+use BuilderFieldType;
+
+/// Implementation of `Debug` for a builder with sensitive fields, implementing
+/// `quote::ToTokens`.
+///
+/// Replaces `#[builder(derive(Debug))]` when a field is marked
+/// `#[builder(field(sensitive))]`, printing the builder fields like the derived
+/// implementation, except that the values of sensitive fields print as `"<sensitive>"`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderDebug, BuilderFieldType, DebugField};
+/// # fn main() {
+/// #    let mut debug = default_builder_debug!();
+/// #    debug.push_field(DebugField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        sensitive: true,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#debug).to_string(), quote!(
+/// impl Debug for FooBuilder {
+///     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+///         f.debug_struct("FooBuilder")
+///             .field("foo", &self.foo.as_ref().map(|_| "<sensitive>"))
+///             .finish()
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderDebug<'a> {
+    /// Enables code generation for the `Debug` implementation.
+    pub enabled: bool,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Calls adding each builder field to the `DebugStruct`, in declaration order.
+    pub fields: Vec<TokenStream>,
+    /// Bounds requiring each printed field type to implement `Debug`.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field printed by `BuilderDebug`.
+#[derive(Debug, Clone)]
+pub struct DebugField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Print `"<sensitive>"` instead of the value, see `#[builder(field(sensitive))]`.
+    pub sensitive: bool,
+}
+
+impl<'a> ToTokens for BuilderDebug<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let mut predicates: Vec<&syn::WherePredicate> = where_clause
+            .and_then(|clause| clause)
+            .map(|clause| clause.predicates.iter().collect())
+            .unwrap_or_default();
+        predicates.extend(&self.bounds);
+        let where_clause = if predicates.is_empty() {
+            quote!()
+        } else {
+            quote!(where #(#predicates),*)
+        };
+        let name = builder_ident.unraw().to_string();
+        let fields = &self.fields;
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::export::core::fmt::Debug
+                for #builder_ident #ty_generics
+                #where_clause
+            {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    f.debug_struct(#name)
+                        #(#fields)*
+                        .finish()
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderDebug<'a> {
+    /// Print another builder field.
+    pub fn push_field(&mut self, field: DebugField) -> &mut Self {
+        let ident = field.field_ident;
+        let name = ident.unraw().to_string();
+        let value = match field.field_type {
+            BuilderFieldType::Optional(_) | BuilderFieldType::Lazy(_) if field.sensitive => {
+                quote!(&self.#ident.as_ref().map(|_| "<sensitive>"))
+            }
+            BuilderFieldType::Precise(_) if field.sensitive => quote!(&"<sensitive>"),
+            BuilderFieldType::Optional(ty) | BuilderFieldType::Precise(ty) => {
+                self.bounds
+                    .push(parse_quote!(#ty: ::derive_builder::export::core::fmt::Debug));
+                quote!(&self.#ident)
+            }
+            // A `PhantomData` always implements `Debug`, and the closure of a lazy field
+            // never does, just like with the derived implementation.
+            BuilderFieldType::Lazy(_) | BuilderFieldType::Phantom(_) => quote!(&self.#ident),
+        };
+        self.fields.push(quote!(.field(#name, #value)));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_debug {
+    () => {
+        BuilderDebug {
+            enabled: true,
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            fields: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut debug = default_builder_debug!();
+        debug.enabled = false;
+
+        assert_eq!(quote!(#debug).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        let baz = syn::Ident::new("baz", ::proc_macro2::Span::call_site());
+        let marker = syn::Ident::new("marker", ::proc_macro2::Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut debug = default_builder_debug!();
+        debug.push_field(DebugField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: false,
+        });
+        debug.push_field(DebugField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+            sensitive: false,
+        });
+        debug.push_field(DebugField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+            sensitive: true,
+        });
+        debug.push_field(DebugField {
+            field_ident: &baz,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: true,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::fmt::Debug for FooBuilder
+            where
+                String: ::derive_builder::export::core::fmt::Debug
+            {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    f.debug_struct("FooBuilder")
+                        .field("foo", &self.foo)
+                        .field("marker", &self.marker)
+                        .field("bar", &"<sensitive>")
+                        .field("baz", &self.baz.as_ref().map(|_| "<sensitive>"))
+                        .finish()
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#debug).to_string(), expected.to_string());
+    }
+}
@@ -9,6 +9,31 @@ pub enum DefaultExpression {
 }
 
 impl DefaultExpression {
+    /// The type `T` if this is the explicit expression `T::default()`.
+    pub fn default_call_type(&self) -> Option<&syn::Ident> {
+        let block = match *self {
+            Self::Explicit(ref block) => block,
+            Self::Trait => return None,
+        };
+        let func = match block.as_expr() {
+            Some(syn::Expr::Call(call)) if call.args.is_empty() => &call.func,
+            _ => return None,
+        };
+        let path = match **func {
+            syn::Expr::Path(ref path) if path.qself.is_none() => &path.path,
+            _ => return None,
+        };
+        if path.leading_colon.is_some() || path.segments.len() != 2 {
+            return None;
+        }
+        let (ty, method) = (&path.segments[0], &path.segments[1]);
+        if ty.arguments.is_empty() && method.arguments.is_empty() && method.ident == "default" {
+            Some(&ty.ident)
+        } else {
+            None
+        }
+    }
+
     #[cfg(test)]
     pub fn explicit<I: Into<BlockContents>>(content: I) -> Self {
         DefaultExpression::Explicit(content.into())
@@ -35,3 +60,25 @@ impl ToTokens for DefaultExpression {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_call_type() {
+        let ty = |s: &str| {
+            DefaultExpression::explicit(syn::parse_str::<syn::Expr>(s).unwrap())
+                .default_call_type()
+                .map(ToString::to_string)
+        };
+
+        assert_eq!(ty("T::default()"), Some("T".to_string()));
+        assert_eq!(ty("T::new()"), None);
+        assert_eq!(ty("T::default(1)"), None);
+        assert_eq!(ty("Vec::<T>::default()"), None);
+        assert_eq!(ty("::T::default()"), None);
+        assert_eq!(ty("<T as Default>::default()"), None);
+        assert_eq!(DefaultExpression::Trait.default_call_type(), None);
+    }
+}
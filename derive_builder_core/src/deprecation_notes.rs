@@ -35,14 +35,13 @@ use syn;
 /// Proof of concept:
 /// - <https://play.rust-lang.org/?gist=8394141c07d1f6d75d314818389eb4d8>
 #[derive(Debug, Default, Clone)]
-pub struct DeprecationNotes(Vec<String>);
+pub struct DeprecationNotes(Vec<(String, Span)>);
 
 impl ToTokens for DeprecationNotes {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        for note in &self.0 {
-            let fn_ident =
-                syn::Ident::new("derive_builder_core_deprecation_note", Span::call_site());
-            tokens.append_all(quote!(
+        for &(ref note, span) in &self.0 {
+            let fn_ident = syn::Ident::new("derive_builder_core_deprecation_note", span);
+            tokens.append_all(quote_spanned!(span=>
                 {
                     #[deprecated(note=#note)]
                     fn #fn_ident() { }
@@ -57,11 +56,17 @@ impl DeprecationNotes {
     /// Appends a note to the collection.
     #[cfg(test)]
     pub fn push(&mut self, note: String) {
-        self.0.push(note)
+        self.push_spanned(note, Span::call_site())
+    }
+
+    /// Appends a note to the collection, reported at `span`, e.g. the span of the option it
+    /// is about.
+    pub fn push_spanned(&mut self, note: String, span: Span) {
+        self.0.push((note, span))
     }
 
     /// Create a view of these deprecation notes that can annotate a struct.
-    pub const fn as_item(&self) -> DeprecationNotesAsItem {
+    pub const fn as_item(&self) -> DeprecationNotesAsItem<'_> {
         DeprecationNotesAsItem(self)
     }
 }
@@ -0,0 +1,341 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+use BuilderFieldType;
+
+/// Comparison of two builders, implementing `quote::ToTokens`.
+///
+/// Emits a `FooBuilderDiff` struct with one `Option<bool>` per builder field, and a `diff`
+/// method on the builder producing it.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderDiff, BuilderFieldType, DiffField};
+/// # fn main() {
+/// #    let mut diff = default_builder_diff!();
+/// #    diff.push_field(DiffField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        presence_only: false,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#diff).to_string(), quote!(
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// pub struct FooBuilderDiff {
+///     pub foo: Option<bool>,
+/// }
+///
+/// impl FooBuilder {
+///     pub fn diff(&self, other: &Self) -> FooBuilderDiff
+///     where
+///         String: PartialEq
+///     {
+///         FooBuilderDiff {
+///             foo: match (&self.foo, &other.foo) {
+///                 (Some(a), Some(b)) => if a == b { None } else { Some(false) },
+///                 (None, None) => None,
+///                 _ => Some(true),
+///             },
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderDiff<'a> {
+    /// Enables code generation for the diff struct and method.
+    pub enabled: bool,
+    /// Name of the diff struct.
+    pub ident: syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the diff struct and method, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// Names of the compared builder fields.
+    pub field_idents: Vec<syn::Ident>,
+    /// Expressions comparing each builder field, evaluating to `Option<bool>`.
+    pub comparisons: Vec<TokenStream>,
+    /// Bounds the `diff` method needs, e.g. `T: PartialEq` for compared field types.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field compared by `BuilderDiff`.
+#[derive(Debug, Clone)]
+pub struct DiffField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Only compare whether the field is set, for types which do not implement `PartialEq`.
+    pub presence_only: bool,
+}
+
+impl<'a> DiffField<'a> {
+    fn comparison(&self) -> TokenStream {
+        let ident = self.field_ident;
+        let compare_presence = quote!(
+            if self.#ident.is_some() == other.#ident.is_some() {
+                ::derive_builder::export::core::option::Option::None
+            } else {
+                ::derive_builder::export::core::option::Option::Some(true)
+            }
+        );
+        match self.field_type {
+            BuilderFieldType::Optional(_) if self.presence_only => compare_presence,
+            // The closure of a lazy field cannot be compared.
+            BuilderFieldType::Lazy(_) => compare_presence,
+            BuilderFieldType::Optional(_) => quote!(
+                match (&self.#ident, &other.#ident) {
+                    (
+                        ::derive_builder::export::core::option::Option::Some(a),
+                        ::derive_builder::export::core::option::Option::Some(b),
+                    ) => if a == b {
+                        ::derive_builder::export::core::option::Option::None
+                    } else {
+                        ::derive_builder::export::core::option::Option::Some(false)
+                    },
+                    (
+                        ::derive_builder::export::core::option::Option::None,
+                        ::derive_builder::export::core::option::Option::None,
+                    ) => ::derive_builder::export::core::option::Option::None,
+                    _ => ::derive_builder::export::core::option::Option::Some(true),
+                }
+            ),
+            BuilderFieldType::Precise(_) => quote!(
+                if self.#ident == other.#ident {
+                    ::derive_builder::export::core::option::Option::None
+                } else {
+                    ::derive_builder::export::core::option::Option::Some(false)
+                }
+            ),
+            BuilderFieldType::Phantom(_) => {
+                quote!(::derive_builder::export::core::option::Option::None)
+            }
+        }
+    }
+
+    fn bound(&self) -> Option<syn::WherePredicate> {
+        match self.field_type {
+            BuilderFieldType::Optional(_) if self.presence_only => None,
+            BuilderFieldType::Optional(ty) | BuilderFieldType::Precise(ty) => {
+                Some(parse_quote!(#ty: ::derive_builder::export::core::cmp::PartialEq))
+            }
+            BuilderFieldType::Lazy(_) | BuilderFieldType::Phantom(_) => None,
+        }
+    }
+}
+
+impl<'a> ToTokens for BuilderDiff<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let field_idents = &self.field_idents;
+        let comparisons = &self.comparisons;
+        let method_where_clause = if self.bounds.is_empty() {
+            quote!()
+        } else {
+            let bounds = &self.bounds;
+            quote!(where #(#bounds),*)
+        };
+        let struct_doc = format!(
+            "Fields which differ between two `{}`s, see `{}::diff`.",
+            builder_ident, builder_ident
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #struct_doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            #vis struct #ident {
+                #(
+                    /// `None` if the field is the same in both builders, `Some(true)` if it is
+                    /// only set in one of them, and `Some(false)` if both values differ.
+                    pub #field_idents: ::derive_builder::export::core::option::Option<bool>,
+                )*
+            }
+
+            impl #ident {
+                /// Whether any field differs between the two builders.
+                #vis fn has_changes(&self) -> bool {
+                    false #(|| self.#field_idents.is_some())*
+                }
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                /// Compare the fields of this builder with those of `other`.
+                #vis fn diff(&self, other: &Self) -> #ident
+                    #method_where_clause
+                {
+                    #ident {
+                        #(#field_idents: #comparisons,)*
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderDiff<'a> {
+    /// Compare another builder field.
+    pub fn push_field(&mut self, field: DiffField) -> &mut Self {
+        self.field_idents.push(field.field_ident.clone());
+        self.comparisons.push(field.comparison());
+        if let Some(bound) = field.bound() {
+            self.bounds.push(bound);
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_diff {
+    () => {
+        BuilderDiff {
+            enabled: true,
+            ident: syn::Ident::new("FooBuilderDiff", ::proc_macro2::Span::call_site()),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            field_idents: vec![],
+            comparisons: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    fn diff_struct(fields: TokenStream, has_changes: TokenStream) -> TokenStream {
+        let mut result = quote!();
+        result.append_all(quote!(
+            #[doc = "Fields which differ between two `FooBuilder`s, see `FooBuilder::diff`."]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            pub struct FooBuilderDiff {
+                #fields
+            }
+
+            impl FooBuilderDiff {
+                /// Whether any field differs between the two builders.
+                pub fn has_changes(&self) -> bool {
+                    #has_changes
+                }
+            }
+        ));
+        result
+    }
+
+    #[test]
+    fn disabled() {
+        let mut diff = default_builder_diff!();
+        diff.enabled = false;
+
+        assert_eq!(quote!(#diff).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let handle: syn::Type = parse_quote!(Handle);
+        let mut diff = default_builder_diff!();
+        diff.push_field(DiffField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            presence_only: false,
+        });
+        diff.push_field(DiffField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Optional(&handle),
+            presence_only: true,
+        });
+
+        let mut expected = diff_struct(
+            quote!(
+                /// `None` if the field is the same in both builders, `Some(true)` if it is
+                /// only set in one of them, and `Some(false)` if both values differ.
+                pub foo: ::derive_builder::export::core::option::Option<bool>,
+                /// `None` if the field is the same in both builders, `Some(true)` if it is
+                /// only set in one of them, and `Some(false)` if both values differ.
+                pub bar: ::derive_builder::export::core::option::Option<bool>,
+            ),
+            quote!(false || self.foo.is_some() || self.bar.is_some()),
+        );
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl FooBuilder {
+                /// Compare the fields of this builder with those of `other`.
+                pub fn diff(&self, other: &Self) -> FooBuilderDiff
+                where
+                    String: ::derive_builder::export::core::cmp::PartialEq
+                {
+                    FooBuilderDiff {
+                        foo: match (&self.foo, &other.foo) {
+                            (
+                                ::derive_builder::export::core::option::Option::Some(a),
+                                ::derive_builder::export::core::option::Option::Some(b),
+                            ) => if a == b {
+                                ::derive_builder::export::core::option::Option::None
+                            } else {
+                                ::derive_builder::export::core::option::Option::Some(false)
+                            },
+                            (
+                                ::derive_builder::export::core::option::Option::None,
+                                ::derive_builder::export::core::option::Option::None,
+                            ) => ::derive_builder::export::core::option::Option::None,
+                            _ => ::derive_builder::export::core::option::Option::Some(true),
+                        },
+                        bar: if self.bar.is_some() == other.bar.is_some() {
+                            ::derive_builder::export::core::option::Option::None
+                        } else {
+                            ::derive_builder::export::core::option::Option::Some(true)
+                        },
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#diff).to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,334 @@
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+use BuilderFieldType;
+
+/// Implementation of `Display` for the builder, implementing `quote::ToTokens`.
+///
+/// Prints the builder name followed by every builder field. Unset fields print as `unset`.
+/// Set fields print their value if its type implements `Display` and `show_values` is
+/// enabled, and `set` otherwise.
+///
+/// Whether a value implements `Display` is decided where the impl is type-checked, by
+/// resolving a method on a private wrapper through auto-referencing: the impl of the
+/// `Display`-bounded trait is found first if it applies, the catch-all impl on the reference
+/// otherwise. Values of generic field types therefore always print as `set`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderDisplay, BuilderFieldType, DisplayField};
+/// # fn main() {
+/// #    let mut display = default_builder_display!();
+/// #    display.show_values = false;
+/// #    display.push_field(DisplayField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        sensitive: false,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#display).to_string(), quote!(
+/// impl Display for FooBuilder {
+///     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+///         write!(f, "FooBuilder {{")?;
+///         write!(f, " foo: ")?;
+///         match self.foo {
+///             Some(_) => write!(f, "set")?,
+///             None => write!(f, "unset")?,
+///         }
+///         write!(f, " }}")
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderDisplay<'a> {
+    /// Enables code generation for the `Display` implementation.
+    pub enabled: bool,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Whether set fields print their value when its type implements `Display`.
+    pub show_values: bool,
+    /// Statements writing each builder field, in declaration order.
+    pub fields: Vec<TokenStream>,
+    /// Whether some field writes its value, which requires the helpers picking `Display`.
+    pub writes_values: bool,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field printed by `BuilderDisplay`.
+#[derive(Debug, Clone)]
+pub struct DisplayField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Print `<sensitive>` instead of the value, see `#[builder(field(sensitive))]`.
+    pub sensitive: bool,
+}
+
+impl<'a> ToTokens for BuilderDisplay<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let header = format!("{} {{{{", builder_ident.unraw());
+        let fields = &self.fields;
+        let helpers = if self.writes_values {
+            quote!(
+                struct __DisplayValue<'__v, __T: ?Sized>(&'__v __T);
+
+                trait __WriteValue {
+                    fn __write(
+                        &self,
+                        f: &mut ::derive_builder::export::core::fmt::Formatter,
+                    ) -> ::derive_builder::export::core::fmt::Result;
+                }
+
+                impl<'__v, __T: ::derive_builder::export::core::fmt::Display + ?Sized> __WriteValue
+                    for __DisplayValue<'__v, __T>
+                {
+                    fn __write(
+                        &self,
+                        f: &mut ::derive_builder::export::core::fmt::Formatter,
+                    ) -> ::derive_builder::export::core::fmt::Result {
+                        ::derive_builder::export::core::fmt::Display::fmt(self.0, f)
+                    }
+                }
+
+                trait __WriteSet {
+                    fn __write(
+                        &self,
+                        f: &mut ::derive_builder::export::core::fmt::Formatter,
+                    ) -> ::derive_builder::export::core::fmt::Result;
+                }
+
+                impl<'__v, __T: ?Sized> __WriteSet for &__DisplayValue<'__v, __T> {
+                    fn __write(
+                        &self,
+                        f: &mut ::derive_builder::export::core::fmt::Formatter,
+                    ) -> ::derive_builder::export::core::fmt::Result {
+                        write!(f, "set")
+                    }
+                }
+            )
+        } else {
+            quote!()
+        };
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::export::core::fmt::Display
+                for #builder_ident #ty_generics
+                #where_clause
+            {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    #helpers
+                    write!(f, #header)?;
+                    #(#fields)*
+                    write!(f, " }}")
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderDisplay<'a> {
+    /// Print another builder field.
+    pub fn push_field(&mut self, field: DisplayField) -> &mut Self {
+        let ident = field.field_ident;
+        let separator = if self.fields.is_empty() { " " } else { ", " };
+        let label = format!("{}{}: ", separator, ident.unraw());
+        let write_field = match (&field.field_type, self.show_values) {
+            (BuilderFieldType::Optional(_), _) | (BuilderFieldType::Lazy(_), _)
+                if field.sensitive =>
+            {
+                quote!(
+                    match self.#ident {
+                        ::derive_builder::export::core::option::Option::Some(_) => write!(f, "<sensitive>")?,
+                        ::derive_builder::export::core::option::Option::None => write!(f, "unset")?,
+                    }
+                )
+            }
+            (BuilderFieldType::Precise(_), _) if field.sensitive => quote!(
+                write!(f, "<sensitive>")?;
+            ),
+            (BuilderFieldType::Optional(_), true) => {
+                self.writes_values = true;
+                quote!(
+                    match self.#ident {
+                        ::derive_builder::export::core::option::Option::Some(ref value) => {
+                            (&__DisplayValue(value)).__write(f)?
+                        }
+                        ::derive_builder::export::core::option::Option::None => write!(f, "unset")?,
+                    }
+                )
+            }
+            // The closure of a lazy field has no value to show.
+            (BuilderFieldType::Optional(_), false) | (BuilderFieldType::Lazy(_), _) => quote!(
+                match self.#ident {
+                    ::derive_builder::export::core::option::Option::Some(_) => write!(f, "set")?,
+                    ::derive_builder::export::core::option::Option::None => write!(f, "unset")?,
+                }
+            ),
+            (BuilderFieldType::Precise(_), true) => {
+                self.writes_values = true;
+                quote!(
+                    (&__DisplayValue(&self.#ident)).__write(f)?;
+                )
+            }
+            (BuilderFieldType::Precise(_), false) => quote!(
+                write!(f, "set")?;
+            ),
+            (BuilderFieldType::Phantom(_), _) => return self,
+        };
+
+        self.fields.push(quote!(
+            write!(f, #label)?;
+            #write_field
+        ));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_display {
+    () => {
+        BuilderDisplay {
+            enabled: true,
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            show_values: true,
+            fields: vec![],
+            writes_values: false,
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut display = default_builder_display!();
+        display.enabled = false;
+
+        assert_eq!(quote!(#display).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn hidden_values() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        let marker = syn::Ident::new("marker", ::proc_macro2::Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut display = default_builder_display!();
+        display.show_values = false;
+        display.push_field(DisplayField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: false,
+        });
+        display.push_field(DisplayField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+            sensitive: false,
+        });
+        display.push_field(DisplayField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+            sensitive: false,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::fmt::Display for FooBuilder {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    write!(f, "FooBuilder {{")?;
+                    write!(f, " foo: ")?;
+                    match self.foo {
+                        ::derive_builder::export::core::option::Option::Some(_) => write!(f, "set")?,
+                        ::derive_builder::export::core::option::Option::None => write!(f, "unset")?,
+                    }
+                    write!(f, ", bar: ")?;
+                    write!(f, "set")?;
+                    write!(f, " }}")
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#display).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn sensitive_values() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut display = default_builder_display!();
+        display.push_field(DisplayField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: true,
+        });
+        display.push_field(DisplayField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+            sensitive: true,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::fmt::Display for FooBuilder {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    write!(f, "FooBuilder {{")?;
+                    write!(f, " foo: ")?;
+                    match self.foo {
+                        ::derive_builder::export::core::option::Option::Some(_) => write!(f, "<sensitive>")?,
+                        ::derive_builder::export::core::option::Option::None => write!(f, "unset")?,
+                    }
+                    write!(f, ", bar: ")?;
+                    write!(f, "<sensitive>")?;
+                    write!(f, " }}")
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#display).to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,244 @@
+use allow_lints;
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+/// Builder choosing a unit variant of an enum, implementing `quote::ToTokens`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::EnumBuilder;
+/// # fn main() {
+/// #    let mut enum_builder = default_enum_builder!();
+/// #    enum_builder.push_variant(&syn::Ident::new("Red", ::proc_macro2::Span::call_site()));
+/// #
+/// #    assert_eq!(quote!(#enum_builder).to_string(), quote!(
+/// #[derive(Default)]
+/// pub struct ColorBuilder {
+///     choice: Option<Color>,
+/// }
+///
+/// impl ColorBuilder {
+///     pub fn choose_red(&mut self) -> &mut Self {
+///         self.choice = Some(Color::Red);
+///         self
+///     }
+///
+///     pub fn build(&self) -> Result<Color, UnchosenError> {
+///         match self.choice {
+///             Some(Color::Red) => Ok(Color::Red),
+///             None => Err(UnchosenError::new("Color")),
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EnumBuilder<'a> {
+    /// Name of the builder struct.
+    pub ident: syn::Ident,
+    /// Name of the target enum.
+    pub target_ident: &'a syn::Ident,
+    /// Visibility of the builder struct and its methods, e.g. `syn::Visibility::Public`.
+    pub visibility: &'a syn::Visibility,
+    /// Methods choosing each variant.
+    pub methods: Vec<TokenStream>,
+    /// Match arms of the build method returning each variant.
+    pub arms: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+impl<'a> ToTokens for EnumBuilder<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vis = self.visibility;
+        let ident = &self.ident;
+        let target_ident = self.target_ident;
+        let target_name = target_ident.unraw().to_string();
+        let methods = &self.methods;
+        let arms = &self.arms;
+        let builder_doc = format!(
+            "Builder for [`{ty}`](enum.{ty}.html), choosing one of its variants.",
+            ty = target_name
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #builder_doc]
+            #[derive(Default)]
+            #vis struct #ident {
+                choice: ::derive_builder::export::core::option::Option<#target_ident>,
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[allow(dead_code)]
+            impl #ident {
+                #(#methods)*
+
+                /// Builds the chosen variant.
+                ///
+                /// # Errors
+                ///
+                /// If no variant has been chosen.
+                #vis fn build(&self)
+                    -> ::derive_builder::export::core::result::Result<#target_ident, ::derive_builder::UnchosenError>
+                {
+                    match self.choice {
+                        #(#arms)*
+                        ::derive_builder::export::core::option::Option::None => {
+                            ::derive_builder::export::core::result::Result::Err(
+                                ::derive_builder::UnchosenError::new(#target_name),
+                            )
+                        }
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> EnumBuilder<'a> {
+    /// Add a method choosing another variant.
+    pub fn push_variant(&mut self, variant: &syn::Ident) -> &mut Self {
+        let vis = self.visibility;
+        let target_ident = self.target_ident;
+        let name = variant.unraw().to_string();
+        let method = syn::Ident::new(&format!("choose_{}", snake_case(&name)), Span::call_site());
+        let doc = format!(
+            "Chooses the `{}` variant, replacing any variant chosen before.",
+            name
+        );
+
+        self.methods.push(quote!(
+            #[doc = #doc]
+            #vis fn #method(&mut self) -> &mut Self {
+                self.choice = ::derive_builder::export::core::option::Option::Some(#target_ident::#variant);
+                self
+            }
+        ));
+        self.arms.push(quote!(
+            ::derive_builder::export::core::option::Option::Some(#target_ident::#variant) => {
+                ::derive_builder::export::core::result::Result::Ok(#target_ident::#variant)
+            }
+        ));
+        self
+    }
+}
+
+/// The name of a variant in snake case, e.g. `http_server` for `HttpServer` or `HTTPServer`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 && chars[i - 1] != '_' {
+            let after_lower = !chars[i - 1].is_uppercase();
+            let before_lower = chars.get(i + 1).map(|n| n.is_lowercase()).unwrap_or(false);
+            if after_lower || (chars[i - 1].is_uppercase() && before_lower) {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_enum_builder {
+    () => {
+        EnumBuilder {
+            ident: syn::Ident::new("ColorBuilder", ::proc_macro2::Span::call_site()),
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Color",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            visibility: Box::leak(Box::new(parse_quote!(pub))),
+            methods: vec![],
+            arms: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn snake_case_names() {
+        assert_eq!(snake_case("Red"), "red");
+        assert_eq!(snake_case("DarkRed"), "dark_red");
+        assert_eq!(snake_case("HTTPServer"), "http_server");
+        assert_eq!(snake_case("Rgb8Bit"), "rgb8_bit");
+        assert_eq!(snake_case("Already_Snake"), "already_snake");
+    }
+
+    #[test]
+    fn variants() {
+        let mut enum_builder = default_enum_builder!();
+        enum_builder.push_variant(&syn::Ident::new("DarkRed", Span::call_site()));
+        let result_ty = quote!(
+            ::derive_builder::export::core::result::Result<Color, ::derive_builder::UnchosenError>
+        );
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            #[doc = "Builder for [`Color`](enum.Color.html), choosing one of its variants."]
+            #[derive(Default)]
+            pub struct ColorBuilder {
+                choice: ::derive_builder::export::core::option::Option<Color>,
+            }
+        ));
+
+        expected.append_all(quote!(
+            #[allow(dead_code)]
+            impl ColorBuilder {
+                #[doc = "Chooses the `DarkRed` variant, replacing any variant chosen before."]
+                pub fn choose_dark_red(&mut self) -> &mut Self {
+                    self.choice =
+                        ::derive_builder::export::core::option::Option::Some(Color::DarkRed);
+                    self
+                }
+
+                /// Builds the chosen variant.
+                ///
+                /// # Errors
+                ///
+                /// If no variant has been chosen.
+                pub fn build(&self) -> #result_ty {
+                    match self.choice {
+                        ::derive_builder::export::core::option::Option::Some(Color::DarkRed) => {
+                            ::derive_builder::export::core::result::Result::Ok(Color::DarkRed)
+                        }
+                        ::derive_builder::export::core::option::Option::None => {
+                            ::derive_builder::export::core::result::Result::Err(
+                                ::derive_builder::UnchosenError::new("Color"),
+                            )
+                        }
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#enum_builder).to_string(), expected.to_string());
+    }
+}
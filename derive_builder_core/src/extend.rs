@@ -0,0 +1,420 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use builder::impl_bounds;
+use proc_macro2::TokenStream;
+use quote::{format_ident, ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+use BuilderPattern;
+use Setter;
+
+/// Batch updates of a builder, implementing `quote::ToTokens`.
+///
+/// Emits a `FooField` enum with one variant per setter, holding the value to set, and
+/// implements `Extend<FooField>` for the builder by calling the setter of each variant.
+/// Optionally emits `FooField::parse_from_str`, parsing a variant from a field name and a value.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderExtend, Setter};
+/// # fn main() {
+/// #    let mut extend = default_builder_extend!();
+/// #    extend.push_setter(&default_setter!());
+/// #
+/// #    assert_eq!(quote!(#extend).to_string(), quote!(
+/// pub enum FooField {
+///     Foo(Foo),
+/// }
+///
+/// impl Extend<FooField> for FooBuilder {
+///     fn extend<I: IntoIterator<Item = FooField>>(&mut self, fields: I) {
+///         for field in fields {
+///             match field {
+///                 FooField::Foo(value) => {
+///                     self.foo(value);
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderExtend<'a> {
+    /// Enables code generation for the enum and the `Extend` implementation.
+    pub enabled: bool,
+    /// Name of the field enum.
+    pub ident: syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Builder pattern of the builder struct, which decides the bounds of the `Extend`
+    /// implementation like those of the setters.
+    pub pattern: BuilderPattern,
+    /// Visibility of the field enum, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// The identifier of the inherent method that creates an empty builder, used to call the
+    /// setters of the owned pattern.
+    pub create_empty: &'a syn::Ident,
+    /// Emit `parse_from_str` on the field enum.
+    pub parse: bool,
+    /// Variants of the field enum, e.g. `Foo(u32),`.
+    pub variants: Vec<TokenStream>,
+    /// Match arms calling the setter of each variant.
+    pub arms: Vec<TokenStream>,
+    /// Match arms parsing each variant from its field name.
+    pub parse_arms: Vec<TokenStream>,
+    /// Types of the variants, which `parse_from_str` requires to implement `FromStr`.
+    pub value_types: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+impl<'a> ToTokens for BuilderExtend<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let enum_generics = impl_generics.as_ref();
+        let mut variants = self.variants.clone();
+        let mut arms = self.arms.clone();
+        if let Some((variant, arm)) = phantom_variant(ident, self.generics) {
+            variants.push(variant);
+            arms.push(arm);
+        }
+        let bounded_generics = impl_bounds(self.generics, self.pattern);
+        let (bounded_impl_generics, _, bounded_where_clause) = bounded_generics.split_for_impl();
+        let enum_doc = format!(
+            "A value for one field of a `{}`, see its `Extend` implementation.",
+            builder_ident
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #enum_doc]
+            #vis enum #ident #enum_generics #where_clause {
+                #(#variants)*
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #bounded_impl_generics ::derive_builder::export::core::iter::Extend<#ident #ty_generics>
+                for #builder_ident #ty_generics
+                #bounded_where_clause
+            {
+                fn extend<I: ::derive_builder::export::core::iter::IntoIterator<Item = #ident #ty_generics>>(
+                    &mut self,
+                    fields: I,
+                ) {
+                    for field in fields {
+                        match field {
+                            #(#arms)*
+                        }
+                    }
+                }
+            }
+        ));
+
+        if !self.parse {
+            return;
+        }
+
+        let parse_arms = &self.parse_arms;
+        let value_types = &self.value_types;
+        let mut parse_where_clause = where_clause
+            .and_then(|clause| clause.cloned())
+            .unwrap_or_else(|| parse_quote!(where));
+        for ty in value_types {
+            parse_where_clause.predicates.push(parse_quote!(
+                #ty: ::derive_builder::export::core::str::FromStr
+            ));
+            parse_where_clause.predicates.push(parse_quote!(
+                <#ty as ::derive_builder::export::core::str::FromStr>::Err:
+                    ::derive_builder::export::core::fmt::Display
+            ));
+        }
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #ident #ty_generics #parse_where_clause {
+                /// Parse the value of the field named `name`.
+                #vis fn parse_from_str(name: &str, value: &str)
+                    -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::FieldParseError>
+                {
+                    match name {
+                        #(#parse_arms)*
+                        _ => ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::FieldParseError::new(
+                                ::derive_builder::export::core::string::String::from(name),
+                                ::derive_builder::export::core::string::String::from("unknown field"),
+                            )
+                        ),
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderExtend<'a> {
+    /// Add a variant calling another setter.
+    pub fn push_setter(&mut self, setter: &Setter) -> &mut Self {
+        let ty = match setter.extend_value_type() {
+            Some(ty) => ty,
+            None => return self,
+        };
+
+        let enum_ident = &self.ident;
+        let name = setter.field_ident.unraw().to_string();
+        let variant = variant_ident(setter.field_ident);
+        let set = set_value(setter, self.create_empty);
+
+        self.variants.push(quote!(
+            #[allow(missing_docs)]
+            #variant(#ty),
+        ));
+        self.arms.push(quote!(
+            #enum_ident::#variant(value) => {
+                #set
+            }
+        ));
+        self.parse_arms.push(quote!(
+            #name => match value.parse::<#ty>() {
+                ::derive_builder::export::core::result::Result::Ok(parsed) => {
+                    ::derive_builder::export::core::result::Result::Ok(#enum_ident::#variant(parsed))
+                }
+                ::derive_builder::export::core::result::Result::Err(e) => {
+                    ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::FieldParseError::new(
+                            ::derive_builder::export::core::string::String::from(name),
+                            ::derive_builder::export::core::string::ToString::to_string(&e),
+                        )
+                    )
+                }
+            },
+        ));
+        self.value_types.push(ty);
+        self
+    }
+}
+
+/// Statements calling the setter with `value` through `&mut self`, whatever its pattern.
+pub(crate) fn set_value(setter: &Setter, create_empty: &syn::Ident) -> TokenStream {
+    let ident = &setter.ident;
+    match setter.pattern {
+        BuilderPattern::Mutable => quote!(self.#ident(value);),
+        BuilderPattern::Immutable => quote!(*self = self.#ident(value);),
+        BuilderPattern::Owned => quote!(
+            let builder = ::derive_builder::export::core::mem::replace(
+                self,
+                Self::#create_empty()
+            );
+            *self = builder.#ident(value);
+        ),
+    }
+}
+
+/// A hidden variant of `enum_ident` holding every lifetime and type parameter of `generics`,
+/// which the setters may leave unused, and the match arm for it. The variant holds
+/// `Infallible`, so it cannot be constructed.
+pub(crate) fn phantom_variant(
+    enum_ident: &syn::Ident,
+    generics: Option<&syn::Generics>,
+) -> Option<(TokenStream, TokenStream)> {
+    let generics = generics?;
+    let markers: Vec<TokenStream> = generics
+        .params
+        .iter()
+        .filter_map(|param| match *param {
+            syn::GenericParam::Lifetime(ref def) => {
+                let lifetime = &def.lifetime;
+                Some(quote!(&#lifetime ()))
+            }
+            syn::GenericParam::Type(ref param) => {
+                let ident = &param.ident;
+                Some(quote!(#ident))
+            }
+            syn::GenericParam::Const(_) => None,
+        })
+        .collect();
+
+    if markers.is_empty() {
+        return None;
+    }
+
+    Some((
+        quote!(
+            #[doc(hidden)]
+            __Phantom(
+                ::derive_builder::export::core::convert::Infallible,
+                #(::derive_builder::export::core::marker::PhantomData<#markers>),*
+            ),
+        ),
+        quote!(
+            #enum_ident::__Phantom(never, ..) => match never {},
+        ),
+    ))
+}
+
+/// The variant of the field enum for the field `field_ident`, in upper camel case.
+///
+/// Names which are no identifiers in upper camel case, e.g. those of `_1`, `__` or `self_`, are
+/// prefixed with `Field`. Distinct fields may still share a variant, e.g. `foo_bar` and
+/// `foo__bar`, which `Options` reports as an error.
+pub(crate) fn variant_ident(field_ident: &syn::Ident) -> syn::Ident {
+    let variant: String = field_ident
+        .unraw()
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    match syn::parse_str::<syn::Ident>(&variant) {
+        Ok(ident) => ident,
+        Err(_) => format_ident!("Field{}", variant),
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_extend {
+    () => {
+        BuilderExtend {
+            enabled: true,
+            ident: syn::Ident::new("FooField", ::proc_macro2::Span::call_site()),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            pattern: Default::default(),
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            create_empty: Box::leak(Box::new(syn::Ident::new(
+                "create_empty",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            parse: false,
+            variants: vec![],
+            arms: vec![],
+            parse_arms: vec![],
+            value_types: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use default_setter;
+    use BuilderFieldType;
+
+    #[test]
+    fn disabled() {
+        let mut extend = default_builder_extend!();
+        extend.enabled = false;
+
+        assert_eq!(quote!(#extend).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn variant_idents() {
+        let variant = |name: &str| variant_ident(&syn::parse_str(name).unwrap()).to_string();
+
+        assert_eq!(variant("foo"), "Foo");
+        assert_eq!(variant("foo_bar"), "FooBar");
+        assert_eq!(variant("_foo__bar"), "FooBar");
+        assert_eq!(variant("r#type"), "Type");
+        assert_eq!(variant("_1"), "Field1");
+        assert_eq!(variant("__"), "Field");
+        assert_eq!(variant("self_"), "FieldSelf");
+    }
+
+    #[test]
+    fn owned_setter() {
+        let ident = syn::Ident::new("dolor_sit", ::proc_macro2::Span::call_site());
+        let ty: syn::Type = parse_quote!(u16);
+        let mut setter = default_setter!();
+        setter.ident = ident.clone();
+        setter.field_ident = &ident;
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.pattern = BuilderPattern::Owned;
+        let mut extend = default_builder_extend!();
+        extend.push_setter(&setter);
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            #[doc = "A value for one field of a `FooBuilder`, see its `Extend` implementation."]
+            pub enum FooField {
+                #[allow(missing_docs)]
+                DolorSit(u16),
+            }
+        ));
+
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::iter::Extend<FooField> for FooBuilder {
+                fn extend<I: ::derive_builder::export::core::iter::IntoIterator<Item = FooField>>(
+                    &mut self,
+                    fields: I,
+                ) {
+                    for field in fields {
+                        match field {
+                            FooField::DolorSit(value) => {
+                                let builder = ::derive_builder::export::core::mem::replace(
+                                    self,
+                                    Self::create_empty()
+                                );
+                                *self = builder.dolor_sit(value);
+                            }
+                        }
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#extend).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn fallible_setters_are_skipped() {
+        let mut setter = default_setter!();
+        setter.once = Some(::SetOnce::Error);
+        let mut extend = default_builder_extend!();
+        extend.push_setter(&setter);
+
+        assert!(extend.variants.is_empty());
+    }
+}
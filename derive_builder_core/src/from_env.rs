@@ -0,0 +1,404 @@
+use std::borrow::Cow;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+/// Constructor populating a builder from environment variables, implementing
+/// `quote::ToTokens`.
+///
+/// The prefix of the variable names is either a parameter of the constructor, or fixed when
+/// generating it. With a fixed prefix, every missing required variable is reported at once.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::FromEnv;
+/// # fn main() {
+/// #    let from_env = default_from_env!();
+/// #
+/// #    assert_eq!(quote!(#from_env).to_string(), quote!(
+/// pub fn from_env(prefix: &str)
+///     -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::EnvVarError>
+/// {
+///     let mut builder = Self::create_empty();
+///     // .. one block per `EnvVar`
+///     ::derive_builder::export::core::result::Result::Ok(builder)
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FromEnv<'a> {
+    /// Enables code generation for this constructor.
+    pub enabled: bool,
+    /// Visibility of the constructor, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// Name of the builder's `create_empty` function.
+    pub create_empty: &'a syn::Ident,
+    /// Prefix of the variable names, or `None` to take it as a parameter.
+    pub prefix: Option<String>,
+    /// Whether one of the variables is required, so missing ones must be collected.
+    pub has_required: bool,
+    /// Statements reading one environment variable each into the builder.
+    pub vars: Vec<TokenStream>,
+}
+
+impl<'a> ToTokens for FromEnv<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let create_empty = self.create_empty;
+        let vars = &self.vars;
+
+        if let Some(ref prefix) = self.prefix {
+            let doc = format!(
+                "Create a builder from the environment variables `{}_{{FIELD_NAME}}`.",
+                prefix
+            );
+            let (collect_missing, check_missing) = if self.has_required {
+                (
+                    quote!(let mut __missing_vars = ::derive_builder::export::core::vec::Vec::new();),
+                    quote!(if !__missing_vars.is_empty() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::EnvError::Missing(__missing_vars),
+                        );
+                    }),
+                )
+            } else {
+                (quote!(), quote!())
+            };
+
+            tokens.append_all(quote!(
+                #[doc = #doc]
+                #vis fn from_env()
+                    -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::EnvError>
+                {
+                    let mut builder = Self::#create_empty();
+                    #collect_missing
+                    #(#vars)*
+                    #check_missing
+                    ::derive_builder::export::core::result::Result::Ok(builder)
+                }
+            ));
+        } else {
+            tokens.append_all(quote!(
+                /// Create a builder from the environment variables `{prefix}_{FIELD_NAME}`.
+                #vis fn from_env(prefix: &str)
+                    -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::EnvVarError>
+                {
+                    let mut builder = Self::#create_empty();
+                    #(#vars)*
+                    ::derive_builder::export::core::result::Result::Ok(builder)
+                }
+            ));
+        }
+    }
+}
+
+impl<'a> FromEnv<'a> {
+    /// Read another environment variable in the constructor.
+    pub fn push_var(&mut self, var: EnvVar) -> &mut Self {
+        self.has_required |= var.required;
+        self.vars.push(quote!(#var));
+        self
+    }
+}
+
+/// Reads one builder field from an environment variable, implementing
+/// `quote::ToTokens`.
+#[derive(Debug, Clone)]
+pub struct EnvVar<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type the variable is parsed as, using `FromStr`.
+    pub parse_ty: &'a syn::Type,
+    /// How many times the parsed value must be wrapped in `Some` to fit the builder field.
+    pub wrap_in_some: usize,
+    /// Whether a missing variable is an error, rather than leaving the field unset.
+    pub required: bool,
+    /// Prefix of the variable name fixed when generating the constructor, or `None` if the
+    /// constructor takes it as a parameter.
+    pub prefix: Option<String>,
+}
+
+impl<'a> ToTokens for EnvVar<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field_ident = self.field_ident;
+        let parse_ty = self.parse_ty;
+        let suffix = field_ident.unraw().to_string().to_uppercase();
+
+        let mut value = quote!(parsed);
+        for _ in 0..self.wrap_in_some {
+            value = quote!(::derive_builder::export::core::option::Option::Some(#value));
+        }
+
+        // With a fixed prefix, the name is a `&'static str` and missing variables are collected
+        // by `FromEnv`.
+        let (var_name, error_var_name, not_present) = match self.prefix {
+            Some(ref prefix) => {
+                let var_name = format!("{}_{}", prefix, suffix);
+                let not_present = if self.required {
+                    quote!(__missing_vars.push(var_name))
+                } else {
+                    quote!({})
+                };
+                (
+                    quote!(#var_name),
+                    quote!(::derive_builder::export::core::convert::Into::into(
+                        var_name
+                    )),
+                    not_present,
+                )
+            }
+            None => {
+                let not_present = if self.required {
+                    quote!(
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::EnvVarError::new(
+                                var_name,
+                                ::derive_builder::export::core::convert::Into::into("not present"),
+                            )
+                        )
+                    )
+                } else {
+                    quote!({})
+                };
+                (
+                    quote!(::derive_builder::export::core::format!("{}_{}", prefix, #suffix)),
+                    quote!(var_name),
+                    not_present,
+                )
+            }
+        };
+        let invalid = {
+            let error = quote!(::derive_builder::EnvVarError::new(
+                #error_var_name,
+                ::derive_builder::export::core::string::ToString::to_string(&e),
+            ));
+            if self.prefix.is_some() {
+                quote!(::derive_builder::EnvError::Invalid(#error))
+            } else {
+                error
+            }
+        };
+
+        tokens.append_all(quote!(
+            let var_name = #var_name;
+            match ::derive_builder::export::core::env::var(&var_name) {
+                ::derive_builder::export::core::result::Result::Ok(value) => {
+                    let parsed = match value.parse::<#parse_ty>() {
+                        ::derive_builder::export::core::result::Result::Ok(parsed) => parsed,
+                        ::derive_builder::export::core::result::Result::Err(e) => {
+                            return ::derive_builder::export::core::result::Result::Err(
+                                #invalid
+                            );
+                        }
+                    };
+                    builder.#field_ident = #value;
+                }
+                ::derive_builder::export::core::result::Result::Err(
+                    ::derive_builder::export::core::env::VarError::NotPresent
+                ) => #not_present,
+                ::derive_builder::export::core::result::Result::Err(e) => {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        #invalid
+                    );
+                }
+            }
+        ));
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_from_env {
+    () => {
+        FromEnv {
+            enabled: true,
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            create_empty: &syn::Ident::new("create_empty", ::proc_macro2::Span::call_site()),
+            prefix: None,
+            has_required: false,
+            vars: vec![],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let from_env = default_from_env!();
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#from_env).to_string(),
+            quote!(
+                /// Create a builder from the environment variables `{prefix}_{FIELD_NAME}`.
+                pub fn from_env(prefix: &str)
+                    -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::EnvVarError>
+                {
+                    let mut builder = Self::create_empty();
+                    ::derive_builder::export::core::result::Result::Ok(builder)
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        let mut from_env = default_from_env!();
+        from_env.enabled = false;
+
+        assert_eq!(quote!(#from_env).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn optional_var() {
+        let field_ident = syn::Ident::new_raw("port", ::proc_macro2::Span::call_site());
+        let parse_ty: syn::Type = parse_quote!(u16);
+        let var = EnvVar {
+            field_ident: &field_ident,
+            parse_ty: &parse_ty,
+            wrap_in_some: 1,
+            required: false,
+            prefix: None,
+        };
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#var).to_string(),
+            quote!(
+                let var_name = ::derive_builder::export::core::format!("{}_{}", prefix, "PORT");
+                match ::derive_builder::export::core::env::var(&var_name) {
+                    ::derive_builder::export::core::result::Result::Ok(value) => {
+                        let parsed = match value.parse::<u16>() {
+                            ::derive_builder::export::core::result::Result::Ok(parsed) => parsed,
+                            ::derive_builder::export::core::result::Result::Err(e) => {
+                                return ::derive_builder::export::core::result::Result::Err(
+                                    ::derive_builder::EnvVarError::new(var_name, ::derive_builder::export::core::string::ToString::to_string(&e),)
+                                );
+                            }
+                        };
+                        builder.r#port = ::derive_builder::export::core::option::Option::Some(parsed);
+                    }
+                    ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::export::core::env::VarError::NotPresent
+                    ) => {},
+                    ::derive_builder::export::core::result::Result::Err(e) => {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::EnvVarError::new(var_name, ::derive_builder::export::core::string::ToString::to_string(&e),)
+                        );
+                    }
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn required_var() {
+        let field_ident = syn::Ident::new("host", ::proc_macro2::Span::call_site());
+        let parse_ty: syn::Type = parse_quote!(String);
+        let var = EnvVar {
+            field_ident: &field_ident,
+            parse_ty: &parse_ty,
+            wrap_in_some: 0,
+            required: true,
+            prefix: None,
+        };
+
+        let tokens = quote!(#var).to_string();
+        assert!(tokens.contains(&quote!(builder.host = parsed;).to_string()));
+        assert!(tokens.contains(
+            &quote!(::derive_builder::EnvVarError::new(
+                var_name,
+                ::derive_builder::export::core::convert::Into::into("not present"),
+            ))
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn fixed_prefix() {
+        let field_ident = syn::Ident::new("host", ::proc_macro2::Span::call_site());
+        let parse_ty: syn::Type = parse_quote!(String);
+        let mut from_env = default_from_env!();
+        from_env.prefix = Some("APP".to_string());
+        from_env.push_var(EnvVar {
+            field_ident: &field_ident,
+            parse_ty: &parse_ty,
+            wrap_in_some: 1,
+            required: true,
+            prefix: Some("APP".to_string()),
+        });
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#from_env).to_string(),
+            quote!(
+                #[doc = "Create a builder from the environment variables `APP_{FIELD_NAME}`."]
+                pub fn from_env()
+                    -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::EnvError>
+                {
+                    let mut builder = Self::create_empty();
+                    let mut __missing_vars = ::derive_builder::export::core::vec::Vec::new();
+                    let var_name = "APP_HOST";
+                    match ::derive_builder::export::core::env::var(&var_name) {
+                        ::derive_builder::export::core::result::Result::Ok(value) => {
+                            let parsed = match value.parse::<String>() {
+                                ::derive_builder::export::core::result::Result::Ok(parsed) => parsed,
+                                ::derive_builder::export::core::result::Result::Err(e) => {
+                                    return ::derive_builder::export::core::result::Result::Err(
+                                        ::derive_builder::EnvError::Invalid(::derive_builder::EnvVarError::new(
+                                            ::derive_builder::export::core::convert::Into::into(var_name),
+                                            ::derive_builder::export::core::string::ToString::to_string(&e),
+                                        ))
+                                    );
+                                }
+                            };
+                            builder.host = ::derive_builder::export::core::option::Option::Some(parsed);
+                        }
+                        ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::export::core::env::VarError::NotPresent
+                        ) => __missing_vars.push(var_name),
+                        ::derive_builder::export::core::result::Result::Err(e) => {
+                            return ::derive_builder::export::core::result::Result::Err(
+                                ::derive_builder::EnvError::Invalid(::derive_builder::EnvVarError::new(
+                                    ::derive_builder::export::core::convert::Into::into(var_name),
+                                    ::derive_builder::export::core::string::ToString::to_string(&e),
+                                ))
+                            );
+                        }
+                    }
+                    if !__missing_vars.is_empty() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::EnvError::Missing(__missing_vars),
+                        );
+                    }
+                    ::derive_builder::export::core::result::Result::Ok(builder)
+                }
+            )
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use allow_lints;
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+use BuilderPattern;
+use Setter;
+
+/// Construction of the target struct from a tuple of its required fields, implementing
+/// `quote::ToTokens`.
+///
+/// The fields are passed to the setters of a new builder in declaration order, and the struct
+/// is built with the build method, panicking if it fails.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{FromParts, Setter};
+/// # fn main() {
+/// #    let mut from_parts = default_from_parts!();
+/// #    from_parts.push_setter(&default_setter!());
+/// #
+/// #    assert_eq!(quote!(#from_parts).to_string(), quote!(
+/// impl From<(Foo,)> for Foo {
+///     fn from((__part0,): (Foo,)) -> Self {
+///         let mut __builder = <FooBuilder>::create_empty();
+///         __builder.foo(__part0);
+///         Result::expect(__builder.build(), "the parts should build a valid `Foo`")
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FromParts<'a> {
+    /// Enables code generation for the conversion.
+    pub enabled: bool,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// The identifier of the inherent method that creates an empty builder.
+    pub create_empty: &'a syn::Ident,
+    /// Name of the build method.
+    pub build_fn: &'a syn::Ident,
+    /// Whether the build method returns the target struct itself rather than a `Result`, see
+    /// `#[builder(field(default))]`.
+    pub infallible: bool,
+    /// Additional bounds the build method needs, which the conversion needs too.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Names binding the elements of the tuple, one per required field.
+    pub part_idents: Vec<syn::Ident>,
+    /// Types of the elements of the tuple.
+    pub part_types: Vec<TokenStream>,
+    /// Calls of the setter of each required field.
+    pub setter_calls: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+impl<'a> ToTokens for FromParts<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let target_ident = self.target_ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let mut where_clause = where_clause.and_then(|clause| clause.cloned());
+        if !self.bounds.is_empty() {
+            where_clause
+                .get_or_insert_with(|| parse_quote!(where))
+                .predicates
+                .extend(self.bounds.iter().cloned());
+        }
+        let create_empty = self.create_empty;
+        let build_fn = self.build_fn;
+        let part_idents = &self.part_idents;
+        let part_types = &self.part_types;
+        let setter_calls = &self.setter_calls;
+        let build = if self.infallible {
+            quote!(__builder.#build_fn())
+        } else {
+            let message = format!("the parts should build a valid `{}`", target_ident);
+            quote!(::derive_builder::export::core::result::Result::expect(
+                __builder.#build_fn(),
+                #message
+            ))
+        };
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::export::core::convert::From<(#(#part_types,)*)>
+                for #target_ident #ty_generics
+                #where_clause
+            {
+                fn from((#(#part_idents,)*): (#(#part_types,)*)) -> Self {
+                    #[allow(unused_mut)]
+                    let mut __builder = <#builder_ident #ty_generics>::#create_empty();
+                    #(#setter_calls)*
+                    #build
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> FromParts<'a> {
+    /// Add the setter of another required field as the next element of the tuple.
+    ///
+    /// The setter must be able to accept the value, see `Setter::extend_value_type`.
+    pub fn push_setter(&mut self, setter: &Setter) -> &mut Self {
+        let ty = match setter.extend_value_type() {
+            Some(ty) => ty,
+            None => return self,
+        };
+
+        let ident = &setter.ident;
+        let part = syn::Ident::new(
+            &format!("__part{}", self.part_idents.len()),
+            Span::call_site(),
+        );
+        self.setter_calls.push(match setter.pattern {
+            BuilderPattern::Mutable => quote!(__builder.#ident(#part);),
+            BuilderPattern::Immutable | BuilderPattern::Owned => {
+                quote!(__builder = __builder.#ident(#part);)
+            }
+        });
+        self.part_idents.push(part);
+        self.part_types.push(ty);
+        self
+    }
+
+    /// Add an additional bound of the build method.
+    pub fn push_bound(&mut self, bound: syn::WherePredicate) -> &mut Self {
+        self.bounds.push(bound);
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_from_parts {
+    () => {
+        FromParts {
+            enabled: true,
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            create_empty: Box::leak(Box::new(syn::Ident::new(
+                "create_empty",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            build_fn: Box::leak(Box::new(syn::Ident::new(
+                "build",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            infallible: false,
+            bounds: vec![],
+            part_idents: vec![],
+            part_types: vec![],
+            setter_calls: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use default_setter;
+    use BuilderFieldType;
+
+    #[test]
+    fn disabled() {
+        let mut from_parts = default_from_parts!();
+        from_parts.enabled = false;
+
+        assert_eq!(quote!(#from_parts).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn no_parts() {
+        let from_parts = default_from_parts!();
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::convert::From<()> for Foo {
+                fn from((): ()) -> Self {
+                    #[allow(unused_mut)]
+                    let mut __builder = <FooBuilder>::create_empty();
+                    ::derive_builder::export::core::result::Result::expect(
+                        __builder.build(),
+                        "the parts should build a valid `Foo`"
+                    )
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#from_parts).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn setters() {
+        let ident = syn::Ident::new("dolor", ::proc_macro2::Span::call_site());
+        let ty: syn::Type = parse_quote!(u16);
+        let mut owned = default_setter!();
+        owned.ident = ident.clone();
+        owned.field_ident = &ident;
+        owned.field_type = BuilderFieldType::Optional(&ty);
+        owned.pattern = BuilderPattern::Owned;
+        let mut from_parts = default_from_parts!();
+        from_parts.push_setter(&default_setter!());
+        from_parts.push_setter(&owned);
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::convert::From<(Foo, u16,)> for Foo {
+                fn from((__part0, __part1,): (Foo, u16,)) -> Self {
+                    #[allow(unused_mut)]
+                    let mut __builder = <FooBuilder>::create_empty();
+                    __builder.foo(__part0);
+                    __builder = __builder.dolor(__part1);
+                    ::derive_builder::export::core::result::Result::expect(
+                        __builder.build(),
+                        "the parts should build a valid `Foo`"
+                    )
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#from_parts).to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,194 @@
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::spanned::Spanned;
+
+use BuilderFieldType;
+
+/// Implementation of `Hash` for the builder, implementing `quote::ToTokens`.
+///
+/// Hashes the builder fields in declaration order. Every hashed field type is
+/// bounded by `Hash`, with the bound spanned at the field type, so that a field
+/// which cannot be hashed is reported where it is declared.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, BuilderHash, HashField};
+/// # fn main() {
+/// #    let mut hash = default_builder_hash!();
+/// #    hash.push_field(HashField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#hash).to_string(), quote!(
+/// impl Hash for FooBuilder
+/// where
+///     String: Hash
+/// {
+///     fn hash<__H: Hasher>(&self, state: &mut __H) {
+///         Hash::hash(&self.foo, state);
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderHash<'a> {
+    /// Enables code generation for the `Hash` implementation.
+    pub enabled: bool,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Names of the hashed builder fields.
+    pub field_idents: Vec<syn::Ident>,
+    /// Bounds requiring each hashed field type to implement `Hash`.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field hashed by `BuilderHash`.
+#[derive(Debug, Clone)]
+pub struct HashField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+}
+
+impl<'a> ToTokens for BuilderHash<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let mut predicates: Vec<&syn::WherePredicate> = where_clause
+            .and_then(|clause| clause)
+            .map(|clause| clause.predicates.iter().collect())
+            .unwrap_or_default();
+        predicates.extend(&self.bounds);
+        let where_clause = if predicates.is_empty() {
+            quote!()
+        } else {
+            quote!(where #(#predicates),*)
+        };
+        let field_idents = &self.field_idents;
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::export::core::hash::Hash
+                for #builder_ident #ty_generics
+                #where_clause
+            {
+                fn hash<__H: ::derive_builder::export::core::hash::Hasher>(&self, state: &mut __H) {
+                    #(::derive_builder::export::core::hash::Hash::hash(&self.#field_idents, state);)*
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderHash<'a> {
+    /// Hash another builder field.
+    pub fn push_field(&mut self, field: HashField) -> &mut Self {
+        let ty = match field.field_type {
+            BuilderFieldType::Optional(ty) | BuilderFieldType::Precise(ty) => ty,
+            // The closure of a lazy field cannot be hashed, see `Options::check_lazy_setters`.
+            BuilderFieldType::Lazy(_) | BuilderFieldType::Phantom(_) => return self,
+        };
+        let bound = quote_spanned!(ty.span()=> #ty: ::derive_builder::export::core::hash::Hash);
+        self.field_idents.push(field.field_ident.clone());
+        self.bounds.push(parse_quote!(#bound));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_hash {
+    () => {
+        BuilderHash {
+            enabled: true,
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            field_idents: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut hash = default_builder_hash!();
+        hash.enabled = false;
+
+        assert_eq!(quote!(#hash).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        let marker = syn::Ident::new("marker", ::proc_macro2::Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut hash = default_builder_hash!();
+        hash.push_field(HashField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+        });
+        hash.push_field(HashField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+        });
+        hash.push_field(HashField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::hash::Hash for FooBuilder
+            where
+                String: ::derive_builder::export::core::hash::Hash,
+                Vec<u8>: ::derive_builder::export::core::hash::Hash
+            {
+                fn hash<__H: ::derive_builder::export::core::hash::Hasher>(&self, state: &mut __H) {
+                    ::derive_builder::export::core::hash::Hash::hash(&self.foo, state);
+                    ::derive_builder::export::core::hash::Hash::hash(&self.bar, state);
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#hash).to_string(), expected.to_string());
+    }
+}
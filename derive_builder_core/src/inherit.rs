@@ -0,0 +1,319 @@
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{format_ident, ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+use BuilderPattern;
+use Setter;
+
+/// The interface of a builder which other builders can inherit, implementing
+/// `quote::ToTokens`.
+///
+/// Implements `derive_builder::BuilderMethods` for the builder and, for builders without type
+/// parameters, emits a `macro_rules!` macro which expands to forwarders for every setter. A
+/// builder inheriting this one invokes the macro in its inherent impl, see `InheritedSetters`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::BuilderInterface;
+/// # fn main() {
+/// #    let interface = default_builder_interface!();
+/// #
+/// #    assert_eq!(quote!(#interface).to_string(), quote!(
+/// impl BuilderMethods for FooBuilder {
+///     type Target = Foo;
+/// }
+///
+/// #[doc(hidden)]
+/// #[allow(unused_macros)]
+/// macro_rules! __derive_builder_forward_FooBuilder {
+///     (owned, $field:ident) => {};
+///     (mutable, $field:ident) => {};
+///     (immutable, $field:ident) => {};
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderInterface<'a> {
+    /// Enables code generation for the interface.
+    pub enabled: bool,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Setter forwarders for an inheriting builder using the owned pattern.
+    pub owned: Vec<TokenStream>,
+    /// Setter forwarders for an inheriting builder using the mutable pattern.
+    pub mutable: Vec<TokenStream>,
+    /// Setter forwarders for an inheriting builder using the immutable pattern.
+    pub immutable: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+impl<'a> ToTokens for BuilderInterface<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let target_ident = self.target_ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::BuilderMethods for #builder_ident #ty_generics
+                #where_clause
+            {
+                type Target = #target_ident #ty_generics;
+            }
+        ));
+
+        // Forwarders are spliced into the inheriting builder, where the type parameters of
+        // this builder are not in scope.
+        if self.generics.map(|g| !g.params.is_empty()).unwrap_or(false) {
+            return;
+        }
+
+        let forward_macro = forward_macro_ident(builder_ident);
+        let owned = &self.owned;
+        let mutable = &self.mutable;
+        let immutable = &self.immutable;
+        tokens.append_all(quote!(
+            #[doc(hidden)]
+            #[allow(unused_macros)]
+            macro_rules! #forward_macro {
+                (owned, $field:ident) => { #(#owned)* };
+                (mutable, $field:ident) => { #(#mutable)* };
+                (immutable, $field:ident) => { #(#immutable)* };
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderInterface<'a> {
+    /// Forward another setter to inheriting builders.
+    pub fn push_setter(&mut self, setter: &Setter) -> &mut Self {
+        if let Some(forwarder) = setter.forwarder(BuilderPattern::Owned) {
+            self.owned.push(forwarder);
+        }
+        if let Some(forwarder) = setter.forwarder(BuilderPattern::Mutable) {
+            self.mutable.push(forwarder);
+        }
+        if let Some(forwarder) = setter.forwarder(BuilderPattern::Immutable) {
+            self.immutable.push(forwarder);
+        }
+        self
+    }
+}
+
+/// The setters of an inherited builder, forwarded to the builder field holding it,
+/// implementing `quote::ToTokens`.
+///
+/// Expands to an invocation of the macro emitted by the inherited builder's `BuilderInterface`,
+/// to be placed in the inherent impl of the inheriting builder.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderPattern, InheritedSetters};
+/// # fn main() {
+/// #    let inherited = default_inherited_setters!();
+/// #
+/// #    assert_eq!(quote!(#inherited).to_string(), quote!(
+/// __derive_builder_forward_BaseBuilder!(mutable, base);
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct InheritedSetters<'a> {
+    /// Name of the inherited builder.
+    pub builder_ident: &'a syn::Ident,
+    /// Name of the builder field holding the inherited builder.
+    pub field_ident: &'a syn::Ident,
+    /// Pattern of the inheriting builder.
+    pub pattern: BuilderPattern,
+}
+
+impl<'a> ToTokens for InheritedSetters<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let forward_macro = forward_macro_ident(self.builder_ident);
+        let field_ident = self.field_ident;
+        let pattern = match self.pattern {
+            BuilderPattern::Owned => quote!(owned),
+            BuilderPattern::Mutable => quote!(mutable),
+            BuilderPattern::Immutable => quote!(immutable),
+        };
+
+        tokens.append_all(quote!(#forward_macro!(#pattern, #field_ident);));
+    }
+}
+
+/// The name of the macro expanding to forwarders for the setters of `builder_ident`.
+fn forward_macro_ident(builder_ident: &syn::Ident) -> syn::Ident {
+    format_ident!("__derive_builder_forward_{}", builder_ident.unraw())
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_interface {
+    () => {
+        BuilderInterface {
+            enabled: true,
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            owned: vec![],
+            mutable: vec![],
+            immutable: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_inherited_setters {
+    () => {
+        InheritedSetters {
+            builder_ident: Box::leak(Box::new(syn::Ident::new(
+                "BaseBuilder",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            field_ident: Box::leak(Box::new(syn::Ident::new(
+                "base",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            pattern: BuilderPattern::Mutable,
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use default_setter;
+    use BuilderFieldType;
+
+    #[test]
+    fn disabled() {
+        let mut interface = default_builder_interface!();
+        interface.enabled = false;
+
+        assert_eq!(quote!(#interface).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn forwarders() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let ty: syn::Type = parse_quote!(u16);
+        let mut setter = default_setter!();
+        setter.field_ident = &foo;
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        let mut interface = default_builder_interface!();
+        interface.push_setter(&setter);
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::BuilderMethods for FooBuilder {
+                type Target = Foo;
+            }
+
+            #[doc(hidden)]
+            #[allow(unused_macros)]
+            macro_rules! __derive_builder_forward_FooBuilder {
+                (owned, $field:ident) => {
+                    #[allow(unused_mut)]
+                    pub fn foo(self, value: u16) -> Self {
+                        let mut new = self;
+                        new.$field.foo(value);
+                        new
+                    }
+                };
+                (mutable, $field:ident) => {
+                    #[allow(unused_mut)]
+                    pub fn foo(&mut self, value: u16) -> &mut Self {
+                        let mut new = self;
+                        new.$field.foo(value);
+                        new
+                    }
+                };
+                (immutable, $field:ident) => {
+                    #[allow(unused_mut)]
+                    pub fn foo(&self, value: u16) -> Self {
+                        let mut new = ::derive_builder::export::core::clone::Clone::clone(self);
+                        new.$field.foo(value);
+                        new
+                    }
+                };
+            }
+        ));
+
+        assert_eq!(quote!(#interface).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generic_builder() {
+        let generics: syn::Generics = parse_quote!(<T>);
+        let mut interface = default_builder_interface!();
+        interface.generics = Some(&generics);
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            impl<T> ::derive_builder::BuilderMethods for FooBuilder<T> {
+                type Target = Foo<T>;
+            }
+        ));
+
+        assert_eq!(quote!(#interface).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn inherited_setters() {
+        let mut inherited = default_inherited_setters!();
+        inherited.pattern = BuilderPattern::Owned;
+
+        assert_eq!(
+            quote!(#inherited).to_string(),
+            quote!(__derive_builder_forward_BaseBuilder!(owned, base);).to_string()
+        );
+    }
+}
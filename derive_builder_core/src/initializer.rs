@@ -1,6 +1,7 @@
-use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, TokenStreamExt};
+use proc_macro2::{Delimiter, Group, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, ToTokens, TokenStreamExt};
 use syn;
+use syn::ext::IdentExt;
 use BuilderPattern;
 use DEFAULT_STRUCT_NAME;
 
@@ -39,6 +40,8 @@ use crate::{BlockContents, DefaultExpression};
 pub struct Initializer<'a> {
     /// Name of the target field.
     pub field_ident: &'a syn::Ident,
+    /// Type of the target field.
+    pub field_type: &'a syn::Type,
     /// Whether the builder implements a setter for this field.
     pub field_enabled: bool,
     /// How the build method takes and returns `self` (e.g. mutably).
@@ -47,6 +50,12 @@ pub struct Initializer<'a> {
     ///
     /// This takes precedence over a default struct identifier.
     pub default_value: Option<&'a DefaultExpression>,
+    /// Whether `default_value` evaluates to a `Result`, whose error is returned from the build
+    /// method, see `#[builder(default = "...", try)]`.
+    pub try_default: bool,
+    /// Type whose `Default` impl provides the value of an unset field, from
+    /// `#[builder(setter(or_default))]`.
+    pub or_default: Option<&'a syn::Type>,
     /// Whether the build_method defines a default struct.
     pub use_default_struct: bool,
     /// Span where the macro was told to use a preexisting error type, instead of creating one,
@@ -63,49 +72,159 @@ pub struct Initializer<'a> {
     ///
     /// For sub-builder fields, this will be `build` (or similar)
     pub conversion: FieldConversion<'a>,
+    /// Assume that a field without a default value is set, rather than returning an error.
+    ///
+    /// This is only sound inside an `unsafe` method whose caller guarantees it, see
+    /// `#[builder(build_fn(unchecked))]`.
+    pub unchecked: bool,
+    /// Panic if a field without a default value is unset, rather than returning an error, see
+    /// `#[builder(build_fn(expect))]`.
+    pub expect: bool,
+    /// Function applied to the value of the target field, after defaulting, see
+    /// `#[builder(field(map = "..."))]`.
+    pub map: Option<&'a syn::Expr>,
+    /// Closure computing the value of an unset field, when the builder field holds a closure
+    /// too, see `#[builder(setter(lazy = "..."))]`.
+    ///
+    /// This requires the build method to take the builder by value.
+    pub lazy: Option<&'a syn::Expr>,
+    /// Expression computing the target field from the other fields, which it reads as
+    /// `self.field`, see `#[builder(field(computed = "..."))]`.
+    ///
+    /// This requires the build method to bind the fields to locals, see `Initializer::binding`.
+    pub computed: Option<&'a syn::Expr>,
+    /// Conditions the value of the target field must satisfy, which read it as `value`, each
+    /// with the message of the validation error returned from the build method if it does not,
+    /// see `#[builder(field(assert = "...", error = "..."))]`.
+    ///
+    /// These are not checked by unchecked initializers.
+    pub assertions: Vec<(&'a BlockContents, &'a syn::LitStr)>,
+    /// Whether an allocator is available, in which case the validation errors of failed
+    /// assertions are converted from a `String` rather than a `&'static str`.
+    pub alloc: bool,
 }
 
 impl<'a> ToTokens for Initializer<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let struct_field = &self.field_ident;
-        let builder_field = struct_field;
+        let value = self.value();
 
-        // This structure prevents accidental failure to add the trailing `,` due to incautious `return`
-        let append_rhs = |tokens: &mut TokenStream| {
-            if !self.field_enabled {
-                let default = self.default();
-                tokens.append_all(quote!(
-                    #default
-                ));
-            } else {
-                match &self.conversion {
-                    FieldConversion::Block(conv) => {
-                        conv.to_tokens(tokens);
-                    }
-                    FieldConversion::Move => tokens.append_all(quote!( self.#builder_field )),
-                    FieldConversion::OptionOrDefault => {
-                        let match_some = self.match_some();
-                        let match_none = self.match_none();
-                        tokens.append_all(quote!(
-                            match self.#builder_field {
-                                #match_some,
-                                #match_none,
-                            }
-                        ));
+        tokens.append_all(quote!(#struct_field: #value,));
+    }
+}
+
+impl<'a> Initializer<'a> {
+    /// The value of the target field.
+    fn value(&self) -> TokenStream {
+        let builder_field = self.field_ident;
+
+        let mut value = TokenStream::new();
+        if let Some(computed) = self.computed {
+            value.append_all(map_field_accesses(
+                computed.into_token_stream(),
+                &mut |ident| binding_ident(ident).into_token_stream(),
+            ));
+        } else if !self.field_enabled {
+            let default = self.default();
+            value.append_all(quote!(
+                #default
+            ));
+        } else {
+            match &self.conversion {
+                FieldConversion::Block(conv) => {
+                    conv.to_tokens(&mut value);
+                }
+                FieldConversion::Move => value.append_all(quote!( self.#builder_field )),
+                FieldConversion::Value => match self.builder_pattern {
+                    BuilderPattern::Owned => value.append_all(quote!( self.#builder_field )),
+                    BuilderPattern::Mutable | BuilderPattern::Immutable => {
+                        value.append_all(quote!(
+                            ::derive_builder::export::core::clone::Clone::clone(&self.#builder_field)
+                        ))
                     }
+                },
+                FieldConversion::OptionOrDefault => {
+                    let match_some = self.match_some();
+                    let match_none = self.match_none();
+                    value.append_all(quote!(
+                        match self.#builder_field {
+                            #match_some,
+                            #match_none,
+                        }
+                    ));
                 }
             }
-        };
+        }
+
+        if let Some(map) = self.map {
+            // Passing the closure to a generic function lets the type of its parameter be
+            // inferred from the value.
+            value = quote!({
+                fn __derive_builder_apply_map<T, F: ::derive_builder::export::core::ops::FnOnce(T) -> T>(value: T, f: F) -> T {
+                    f(value)
+                }
+                __derive_builder_apply_map(#value, #map)
+            });
+        }
 
-        tokens.append_all(quote!(#struct_field:));
-        append_rhs(tokens);
-        tokens.append_all(quote!(,));
+        if self.assertions.is_empty() || self.unchecked || self.expect {
+            return value;
+        }
+
+        let checks = self.assertions.iter().map(|&(assertion, message)| {
+            let error = if self.alloc {
+                quote!(::derive_builder::export::core::string::String::from(#message))
+            } else {
+                quote!(#message)
+            };
+            quote!(
+                if !(#assertion) {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::export::core::convert::Into::into(#error)
+                    );
+                }
+            )
+        });
+        quote!({
+            let value = #value;
+            #(#checks)*
+            value
+        })
+    }
+
+    /// The local holding the value of the target field in build methods which bind the fields
+    /// to locals.
+    pub fn binding_ident(&self) -> syn::Ident {
+        binding_ident(self.field_ident)
+    }
+
+    /// Statement binding the value of the target field to `binding_ident`, so that computed
+    /// fields initialized later can read it.
+    pub fn binding(&self) -> TokenStream {
+        let ident = self.binding_ident();
+        let ty = self.field_type;
+        let value = self.value();
+        quote!(let #ident: #ty = #value;)
+    }
+
+    /// Whether the value of the field is always taken from the default struct, as for a field
+    /// without a setter or a default of its own.
+    pub fn is_default_struct_field(&self) -> bool {
+        !self.field_enabled
+            && self.use_default_struct
+            && self.lazy.is_none()
+            && self.default_value.is_none()
+            && self.or_default.is_none()
+            && self.map.is_none()
+            && self.computed.is_none()
     }
-}
 
-impl<'a> Initializer<'a> {
     /// To be used inside of `#struct_field: match self.#builder_field { ... }`
     fn match_some(&'a self) -> MatchSome {
+        if self.lazy.is_some() {
+            return MatchSome::Call;
+        }
+
         match self.builder_pattern {
             BuilderPattern::Owned => MatchSome::Move,
             BuilderPattern::Mutable | BuilderPattern::Immutable => MatchSome::Clone,
@@ -114,14 +233,25 @@ impl<'a> Initializer<'a> {
 
     /// To be used inside of `#struct_field: match self.#builder_field { ... }`
     fn match_none(&'a self) -> MatchNone<'a> {
+        if let Some(lazy) = self.lazy {
+            return MatchNone::Call(lazy);
+        }
+
         match self.default_value {
+            Some(expr) if self.try_default => MatchNone::TryDefaultTo(expr),
             Some(expr) => MatchNone::DefaultTo(expr),
             None => {
-                if self.use_default_struct {
+                if let Some(ty) = self.or_default {
+                    MatchNone::DefaultOf(ty)
+                } else if self.use_default_struct {
                     MatchNone::UseDefaultStructField(self.field_ident)
+                } else if self.unchecked {
+                    MatchNone::Unreachable
+                } else if self.expect {
+                    MatchNone::Panic(self.field_ident.unraw().to_string())
                 } else {
                     MatchNone::ReturnError(
-                        self.field_ident.to_string(),
+                        self.field_ident.unraw().to_string(),
                         self.custom_error_type_span,
                     )
                 }
@@ -130,8 +260,17 @@ impl<'a> Initializer<'a> {
     }
 
     fn default(&'a self) -> TokenStream {
+        if let Some(lazy) = self.lazy {
+            return quote!((#lazy)());
+        }
+
         match self.default_value {
+            Some(expr) if self.try_default => try_default_expression(expr),
             Some(ref expr) => quote!(#expr),
+            None if self.or_default.is_some() => {
+                let ty = self.or_default;
+                quote!(<#ty as ::derive_builder::export::core::default::Default>::default())
+            }
             None if self.use_default_struct => {
                 let struct_ident = syn::Ident::new(DEFAULT_STRUCT_NAME, Span::call_site());
                 let field_ident = self.field_ident;
@@ -142,6 +281,72 @@ impl<'a> Initializer<'a> {
     }
 }
 
+/// The local holding the value of a field in build methods which bind the fields to locals.
+fn binding_ident(field_ident: &syn::Ident) -> syn::Ident {
+    format_ident!("__field_{}", field_ident.unraw())
+}
+
+/// Get the fields a `field(computed = "...")` expression reads as `self.field`.
+pub fn computed_field_accesses(expr: &syn::Expr) -> Vec<syn::Ident> {
+    let mut fields = Vec::new();
+    map_field_accesses(expr.into_token_stream(), &mut |ident| {
+        fields.push(ident.clone());
+        quote!(self.#ident)
+    });
+    fields
+}
+
+/// Replace each field access `self.field` in the tokens, but not method calls such as
+/// `self.method()`, with the tokens `f` returns for the field.
+fn map_field_accesses(
+    tokens: TokenStream,
+    f: &mut dyn FnMut(&syn::Ident) -> TokenStream,
+) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut mapped = TokenStream::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Some(field) = field_access(&tokens[i..]) {
+            mapped.append_all(f(&field));
+            i += 3;
+            continue;
+        }
+
+        match &tokens[i] {
+            TokenTree::Group(group) => {
+                let mut mapped_group =
+                    Group::new(group.delimiter(), map_field_accesses(group.stream(), f));
+                mapped_group.set_span(group.span());
+                mapped.append(mapped_group);
+            }
+            token => mapped.append(token.clone()),
+        }
+        i += 1;
+    }
+    mapped
+}
+
+/// Get the field if the tokens start with a field access `self.field`.
+fn field_access(tokens: &[TokenTree]) -> Option<syn::Ident> {
+    match (tokens.first(), tokens.get(1), tokens.get(2)) {
+        (
+            Some(TokenTree::Ident(receiver)),
+            Some(TokenTree::Punct(dot)),
+            Some(TokenTree::Ident(field)),
+        ) if receiver == "self" && dot.as_char() == '.' && dot.spacing() == Spacing::Alone => {
+            // A method call, possibly with turbofish, is not a field access.
+            match tokens.get(3) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    None
+                }
+                Some(TokenTree::Punct(colon)) if colon.as_char() == ':' => None,
+                _ => Some(field.clone()),
+            }
+        }
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FieldConversion<'a> {
     /// Usual conversion: unwrap the Option from the builder, or (hope to) use a default value
@@ -150,30 +355,52 @@ pub enum FieldConversion<'a> {
     Block(&'a BlockContents),
     /// Custom conversion is just to move the field from the builder
     Move,
+    /// The builder field holds the target value itself, see `#[builder(field(default))]`; move
+    /// it from the builder, or clone it unless the builder is consumed
+    Value,
 }
 
 /// To be used inside of `#struct_field: match self.#builder_field { ... }`
 enum MatchNone<'a> {
     /// Inner value must be a valid Rust expression
     DefaultTo(&'a DefaultExpression),
+    /// Inner value must be a valid Rust expression evaluating to a `Result`
+    TryDefaultTo(&'a DefaultExpression),
+    /// Inner value must be the field type, which must implement `Default`
+    DefaultOf(&'a syn::Type),
     /// Inner value must be the field identifier
     ///
     /// The default struct must be in scope in the build_method.
     UseDefaultStructField(&'a syn::Ident),
     /// Inner value must be the field name
     ReturnError(String, Option<Span>),
+    /// The caller guarantees the field is set
+    Unreachable,
+    /// Inner value must be the field name
+    Panic(String),
+    /// Inner value must be a closure returning the field value
+    Call(&'a syn::Expr),
 }
 
 impl<'a> ToTokens for MatchNone<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match *self {
             MatchNone::DefaultTo(expr) => tokens.append_all(quote!(
-                None => #expr
+                ::derive_builder::export::core::option::Option::None => #expr
+            )),
+            MatchNone::TryDefaultTo(expr) => {
+                let value = try_default_expression(expr);
+                tokens.append_all(quote!(
+                    ::derive_builder::export::core::option::Option::None => #value
+                ))
+            }
+            MatchNone::DefaultOf(ty) => tokens.append_all(quote!(
+                ::derive_builder::export::core::option::Option::None => <#ty as ::derive_builder::export::core::default::Default>::default()
             )),
             MatchNone::UseDefaultStructField(field_ident) => {
                 let struct_ident = syn::Ident::new(DEFAULT_STRUCT_NAME, Span::call_site());
                 tokens.append_all(quote!(
-                    None => #struct_ident.#field_ident
+                    ::derive_builder::export::core::option::Option::None => #struct_ident.#field_ident
                 ))
             }
             MatchNone::ReturnError(ref field_name, ref span) => {
@@ -182,27 +409,59 @@ impl<'a> ToTokens for MatchNone<'a> {
                     ::derive_builder::UninitializedFieldError::from(#field_name)
                 ));
                 tokens.append_all(quote!(
-                    None => return ::derive_builder::export::core::result::Result::Err(#err_conv)
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(#err_conv)
                 ));
             }
+            MatchNone::Unreachable => tokens.append_all(quote!(
+                ::derive_builder::export::core::option::Option::None => unsafe { ::derive_builder::export::core::hint::unreachable_unchecked() }
+            )),
+            MatchNone::Panic(ref field_name) => {
+                let message = format!("`{}` must be initialized", field_name);
+                tokens.append_all(quote!(
+                    ::derive_builder::export::core::option::Option::None => ::derive_builder::export::core::panic!(#message)
+                ))
+            }
+            MatchNone::Call(lazy) => tokens.append_all(quote!(
+                ::derive_builder::export::core::option::Option::None => (#lazy)()
+            )),
         }
     }
 }
 
+/// Returns expression unwrapping the `Result` of a fallible default, returning its error
+/// from the build method.
+fn try_default_expression(expr: &DefaultExpression) -> TokenStream {
+    quote!(
+        match #expr {
+            ::derive_builder::export::core::result::Result::Ok(value) => value,
+            ::derive_builder::export::core::result::Result::Err(e) => {
+                return ::derive_builder::export::core::result::Result::Err(
+                    ::derive_builder::export::core::convert::Into::into(e)
+                )
+            }
+        }
+    )
+}
+
 /// To be used inside of `#struct_field: match self.#builder_field { ... }`
 enum MatchSome {
     Move,
     Clone,
+    /// The builder field holds a closure, which is moved out and called
+    Call,
 }
 
-impl<'a> ToTokens for MatchSome {
+impl ToTokens for MatchSome {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match *self {
             Self::Move => tokens.append_all(quote!(
-                Some(value) => value
+                ::derive_builder::export::core::option::Option::Some(value) => value
             )),
             Self::Clone => tokens.append_all(quote!(
-                Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value)
+                ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value)
+            )),
+            Self::Call => tokens.append_all(quote!(
+                ::derive_builder::export::core::option::Option::Some(value) => value()
             )),
         }
     }
@@ -216,12 +475,22 @@ macro_rules! default_initializer {
     () => {
         Initializer {
             field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+            field_type: Box::leak(Box::new(syn::parse_quote!(Foo))),
             field_enabled: true,
             builder_pattern: BuilderPattern::Mutable,
             default_value: None,
+            try_default: false,
+            or_default: None,
             use_default_struct: false,
             conversion: FieldConversion::OptionOrDefault,
             custom_error_type_span: None,
+            unchecked: false,
+            expect: false,
+            map: None,
+            lazy: None,
+            computed: None,
+            assertions: vec![],
+            alloc: true,
         }
     };
 }
@@ -240,8 +509,8 @@ mod tests {
             quote!(#initializer).to_string(),
             quote!(
                 foo: match self.foo {
-                    Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
-                    None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
                         ::derive_builder::UninitializedFieldError::from("foo")
                     )),
                 },
@@ -259,8 +528,8 @@ mod tests {
             quote!(#initializer).to_string(),
             quote!(
                 foo: match self.foo {
-                    Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
-                    None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
                         ::derive_builder::UninitializedFieldError::from("foo")
                     )),
                 },
@@ -278,8 +547,8 @@ mod tests {
             quote!(#initializer).to_string(),
             quote!(
                 foo: match self.foo {
-                    Some(value) => value,
-                    None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                    ::derive_builder::export::core::option::Option::Some(value) => value,
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
                         ::derive_builder::UninitializedFieldError::from("foo")
                     )),
                 },
@@ -288,6 +557,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lazy() {
+        let lazy: syn::Expr = parse_quote!(|| 42);
+        let mut initializer = default_initializer!();
+        initializer.builder_pattern = BuilderPattern::Owned;
+        initializer.lazy = Some(&lazy);
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: match self.foo {
+                    ::derive_builder::export::core::option::Option::Some(value) => value(),
+                    ::derive_builder::export::core::option::Option::None => (#lazy)(),
+                },
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn raw_identifier() {
+        let ident = syn::Ident::new_raw("type", Span::call_site());
+        let mut initializer = default_initializer!();
+        initializer.field_ident = &ident;
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                r#type: match self.r#type {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                        ::derive_builder::UninitializedFieldError::from("type")
+                    )),
+                },
+            )
+            .to_string()
+        );
+    }
+
     #[test]
     fn default_value() {
         let mut initializer = default_initializer!();
@@ -298,8 +606,80 @@ mod tests {
             quote!(#initializer).to_string(),
             quote!(
                 foo: match self.foo {
-                    Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
-                    None => { 42 },
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => { 42 },
+                },
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn try_default_value() {
+        let mut initializer = default_initializer!();
+        let default_value = DefaultExpression::explicit::<syn::Expr>(parse_quote!(foo()));
+        initializer.default_value = Some(&default_value);
+        initializer.try_default = true;
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: match self.foo {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => match { foo() } {
+                        ::derive_builder::export::core::result::Result::Ok(value) => value,
+                        ::derive_builder::export::core::result::Result::Err(e) => {
+                            return ::derive_builder::export::core::result::Result::Err(
+                                ::derive_builder::export::core::convert::Into::into(e)
+                            )
+                        }
+                    },
+                },
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn map() {
+        let map: syn::Expr = parse_quote!(|v| v + 1);
+        let mut initializer = default_initializer!();
+        initializer.map = Some(&map);
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: {
+                    fn __derive_builder_apply_map<T, F: ::derive_builder::export::core::ops::FnOnce(T) -> T>(value: T, f: F) -> T {
+                        f(value)
+                    }
+                    __derive_builder_apply_map(
+                        match self.foo {
+                            ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                            ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                                ::derive_builder::UninitializedFieldError::from("foo")
+                            )),
+                        },
+                        |v| v + 1
+                    )
+                },
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn or_default() {
+        let ty: syn::Type = parse_quote!(Vec<u8>);
+        let mut initializer = default_initializer!();
+        initializer.or_default = Some(&ty);
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: match self.foo {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => <Vec<u8> as ::derive_builder::export::core::default::Default>::default(),
                 },
             )
             .to_string()
@@ -315,14 +695,190 @@ mod tests {
             quote!(#initializer).to_string(),
             quote!(
                 foo: match self.foo {
-                    Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
-                    None => __default.foo,
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => __default.foo,
                 },
             )
             .to_string()
         );
     }
 
+    #[test]
+    fn default_struct_field() {
+        let mut initializer = default_initializer!();
+        initializer.use_default_struct = true;
+        assert!(!initializer.is_default_struct_field());
+
+        initializer.field_enabled = false;
+        assert!(initializer.is_default_struct_field());
+
+        let default_value = DefaultExpression::explicit::<syn::Expr>(parse_quote!(42));
+        initializer.default_value = Some(&default_value);
+        assert!(!initializer.is_default_struct_field());
+    }
+
+    #[test]
+    fn computed() {
+        let computed: syn::Expr = parse_quote!(self.width * self.height(self.depth).max::<u8>());
+        let ty: syn::Type = parse_quote!(u32);
+        let mut initializer = default_initializer!();
+        initializer.field_enabled = false;
+        initializer.field_type = &ty;
+        initializer.computed = Some(&computed);
+
+        assert_eq!(
+            computed_field_accesses(&computed),
+            vec![
+                syn::Ident::new("width", Span::call_site()),
+                syn::Ident::new("depth", Span::call_site()),
+            ]
+        );
+        assert_eq!(
+            initializer.binding().to_string(),
+            quote!(
+                let __field_foo: u32 = __field_width * self.height(__field_depth).max::<u8>();
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn binding() {
+        let ty: syn::Type = parse_quote!(u32);
+        let mut initializer = default_initializer!();
+        initializer.field_type = &ty;
+
+        assert_eq!(
+            initializer.binding().to_string(),
+            quote!(
+                let __field_foo: u32 = match self.foo {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                        ::derive_builder::UninitializedFieldError::from("foo")
+                    )),
+                };
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn assertions() {
+        let positive: syn::Expr = parse_quote!(value > 0);
+        let positive = BlockContents::from(positive);
+        let even: syn::Expr = parse_quote!(value % 2 == 0);
+        let even = BlockContents::from(even);
+        let positive_error: syn::LitStr = parse_quote!("foo must be positive");
+        let even_error: syn::LitStr = parse_quote!("foo must be even");
+        let mut initializer = default_initializer!();
+        initializer.assertions = vec![(&positive, &positive_error), (&even, &even_error)];
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: {
+                    let value = match self.foo {
+                        ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                        ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                            ::derive_builder::UninitializedFieldError::from("foo")
+                        )),
+                    };
+                    if !({ value > 0 }) {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::export::core::convert::Into::into(
+                                ::derive_builder::export::core::string::String::from("foo must be positive")
+                            )
+                        );
+                    }
+                    if !({ value % 2 == 0 }) {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::export::core::convert::Into::into(
+                                ::derive_builder::export::core::string::String::from("foo must be even")
+                            )
+                        );
+                    }
+                    value
+                },
+            )
+            .to_string()
+        );
+
+        initializer.alloc = false;
+        initializer.assertions.truncate(1);
+        assert!(quote!(#initializer).to_string().contains(
+            &quote!(::derive_builder::export::core::convert::Into::into(
+                "foo must be positive"
+            ))
+            .to_string()
+        ));
+
+        initializer.unchecked = true;
+        assert!(!quote!(#initializer)
+            .to_string()
+            .contains("foo must be positive"));
+
+        initializer.unchecked = false;
+        initializer.expect = true;
+        assert!(!quote!(#initializer)
+            .to_string()
+            .contains("foo must be positive"));
+    }
+
+    #[test]
+    fn unchecked() {
+        let mut initializer = default_initializer!();
+        initializer.unchecked = true;
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: match self.foo {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => unsafe { ::derive_builder::export::core::hint::unreachable_unchecked() },
+                },
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn expect() {
+        let mut initializer = default_initializer!();
+        initializer.expect = true;
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: match self.foo {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => ::derive_builder::export::core::panic!("`foo` must be initialized"),
+                },
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn value() {
+        let mut initializer = default_initializer!();
+        initializer.conversion = FieldConversion::Value;
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(
+                foo: ::derive_builder::export::core::clone::Clone::clone(&self.foo),
+            )
+            .to_string()
+        );
+
+        initializer.builder_pattern = BuilderPattern::Owned;
+
+        assert_eq!(
+            quote!(#initializer).to_string(),
+            quote!(foo: self.foo,).to_string()
+        );
+    }
+
     #[test]
     fn setter_disabled() {
         let mut initializer = default_initializer!();
@@ -342,8 +898,8 @@ mod tests {
             quote!(#initializer).to_string(),
             quote!(
                 foo: match self.foo {
-                    Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
-                    None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
+                    ::derive_builder::export::core::option::Option::Some(ref value) => ::derive_builder::export::core::clone::Clone::clone(value),
+                    ::derive_builder::export::core::option::Option::None => return ::derive_builder::export::core::result::Result::Err(::derive_builder::export::core::convert::Into::into(
                         ::derive_builder::UninitializedFieldError::from("foo")
                     )),
                 },
@@ -0,0 +1,215 @@
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+use BuilderFieldType;
+
+/// Conversion of a built struct back into its builder, implementing `quote::ToTokens`.
+///
+/// Every builder field is set from the field of the struct, which is moved into it.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, IntoBuilder, IntoBuilderField};
+/// # fn main() {
+/// #    let mut into_builder = default_into_builder!();
+/// #    into_builder.push_field(IntoBuilderField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#into_builder).to_string(), quote!(
+/// impl From<Foo> for FooBuilder {
+///     fn from(value: Foo) -> Self {
+///         Self {
+///             foo: Some(value.foo),
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct IntoBuilder<'a> {
+    /// Enables code generation for the conversion.
+    pub enabled: bool,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// The identifier of the inherent method that creates an empty builder, used to fill the
+    /// builder fields which do not hold a field of the target struct.
+    pub create_empty: &'a syn::Ident,
+    /// Whether the builder has fields which do not hold a field of the target struct, such as
+    /// `PhantomData` for fields without setters or the cache of a lazy build method.
+    pub has_other_fields: bool,
+    /// Initializers of the builder fields, e.g. `foo: Some(value.foo),`.
+    pub initializers: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A field converted by `IntoBuilder`.
+#[derive(Debug, Clone)]
+pub struct IntoBuilderField<'a> {
+    /// Name of the field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+}
+
+impl<'a> ToTokens for IntoBuilder<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let target_ident = self.target_ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let initializers = &self.initializers;
+        let create_empty = self.create_empty;
+        let other_fields = if self.has_other_fields {
+            quote!(..Self::#create_empty())
+        } else {
+            quote!()
+        };
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::export::core::convert::From<#target_ident #ty_generics>
+                for #builder_ident #ty_generics
+                #where_clause
+            {
+                fn from(value: #target_ident #ty_generics) -> Self {
+                    Self {
+                        #(#initializers)*
+                        #other_fields
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> IntoBuilder<'a> {
+    /// Convert another field into its builder field.
+    pub fn push_field(&mut self, field: IntoBuilderField) -> &mut Self {
+        let ident = field.field_ident;
+        match field.field_type {
+            BuilderFieldType::Optional(_) => self.initializers.push(quote!(
+                #ident: ::derive_builder::export::core::option::Option::Some(value.#ident),
+            )),
+            // The builder field has either the type of the field, or a custom type which
+            // must be convertible from it.
+            BuilderFieldType::Precise(_) => self.initializers.push(quote!(
+                #ident: ::derive_builder::export::core::convert::Into::into(value.#ident),
+            )),
+            BuilderFieldType::Lazy(_) => self.initializers.push(quote!(
+                #ident: ::derive_builder::export::core::option::Option::Some(
+                    ::derive_builder::export::core::boxed::Box::new({
+                        let field = value.#ident;
+                        move || field
+                    })
+                ),
+            )),
+            BuilderFieldType::Phantom(_) => self.has_other_fields = true,
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_into_builder {
+    () => {
+        IntoBuilder {
+            enabled: true,
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            create_empty: Box::leak(Box::new(syn::Ident::new(
+                "create_empty",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            has_other_fields: false,
+            initializers: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn disabled() {
+        let mut into_builder = default_into_builder!();
+        into_builder.enabled = false;
+
+        assert_eq!(quote!(#into_builder).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new("bar", Span::call_site());
+        let marker = syn::Ident::new("marker", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut into_builder = default_into_builder!();
+        into_builder.push_field(IntoBuilderField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+        });
+        into_builder.push_field(IntoBuilderField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+        });
+        into_builder.push_field(IntoBuilderField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+        });
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::convert::From<Foo> for FooBuilder {
+                fn from(value: Foo) -> Self {
+                    Self {
+                        foo: ::derive_builder::export::core::option::Option::Some(value.foo),
+                        bar: ::derive_builder::export::core::convert::Into::into(value.bar),
+                        ..Self::create_empty()
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#into_builder).to_string(), expected.to_string());
+    }
+}
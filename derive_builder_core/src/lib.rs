@@ -17,6 +17,8 @@
 
 #![deny(warnings, missing_docs)]
 #![cfg_attr(test, recursion_limit = "100")]
+// The `darling` derives expand to code which newer versions of clippy flag.
+#![allow(clippy::manual_unwrap_or_default)]
 
 #[macro_use]
 extern crate darling;
@@ -32,33 +34,142 @@ extern crate quote;
 extern crate pretty_assertions;
 
 mod block;
+mod build_into;
 mod build_method;
 mod builder;
 mod builder_field;
+mod debug;
 mod default_expression;
 mod deprecation_notes;
+mod diff;
+mod display;
 mod doc_comment;
+mod enum_builder;
+mod extend;
+mod from_env;
+mod from_parts;
+mod hash;
+mod inherit;
 mod initializer;
+mod into_builder;
 mod macro_options;
+mod newtype;
 mod options;
+mod partial;
+mod parts;
+mod patch;
+mod serialize;
 mod setter;
+mod snapshot;
+mod to_builder;
 
 pub(crate) use block::BlockContents;
+pub(crate) use build_into::{BuildInto, BuildIntoField};
 pub(crate) use build_method::BuildMethod;
 pub(crate) use builder::Builder;
 pub(crate) use builder_field::{BuilderField, BuilderFieldType};
 use darling::FromDeriveInput;
+pub(crate) use debug::{BuilderDebug, DebugField};
 pub(crate) use default_expression::DefaultExpression;
 pub(crate) use deprecation_notes::DeprecationNotes;
+pub(crate) use diff::{BuilderDiff, DiffField};
+pub(crate) use display::{BuilderDisplay, DisplayField};
 pub(crate) use doc_comment::doc_comment_from;
+pub(crate) use enum_builder::EnumBuilder;
+pub(crate) use extend::BuilderExtend;
+pub(crate) use from_env::{EnvVar, FromEnv};
+pub(crate) use from_parts::FromParts;
+pub(crate) use hash::{BuilderHash, HashField};
+pub(crate) use inherit::{BuilderInterface, InheritedSetters};
 pub(crate) use initializer::{FieldConversion, Initializer};
-pub(crate) use options::{BuilderPattern, Each};
+pub(crate) use into_builder::{IntoBuilder, IntoBuilderField};
+pub(crate) use newtype::NewtypeImpl;
+pub(crate) use options::{
+    BuildFnInto, BuildFnReceiver, BuilderPattern, Each, EachDedup, NewtypeInner, Normalize, SetOnce,
+};
+pub(crate) use partial::{BuilderPartial, PartialField};
+pub(crate) use parts::{BuilderParts, PartsField};
+pub(crate) use patch::BuilderPatch;
+pub(crate) use serialize::{BuilderSerialize, SerializeField};
 pub(crate) use setter::Setter;
+pub(crate) use snapshot::{BuilderSnapshot, SnapshotField};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use syn::ext::IdentExt;
+pub(crate) use to_builder::ToBuilder;
 
 const DEFAULT_STRUCT_NAME: &str = "__default";
+const BUILD_CACHE_FIELD_NAME: &str = "__build_cache";
+/// Environment variable enabling `#[builder(code_gen_debug = "...")]` during the build.
+const CODE_GEN_DEBUG_VAR: &str = "DERIVE_BUILDER_DEBUG";
+
+/// Returns an attribute allowing `lints` on the generated item which follows it, unless the
+/// `clippy` feature is enabled to lint the generated code itself.
+fn allow_lints(lints: &[syn::Path]) -> proc_macro2::TokenStream {
+    if cfg!(feature = "clippy") || lints.is_empty() {
+        return quote!();
+    }
+
+    quote!(#[allow(#(#lints),*)])
+}
+
+/// Write the code generated for `ident` to the file at `path`, relative to the directory of the
+/// manifest of the crate being built, if the `DERIVE_BUILDER_DEBUG` environment variable is set.
+fn write_code_gen_debug(
+    path: &syn::LitStr,
+    ident: &syn::Ident,
+    tokens: &proc_macro2::TokenStream,
+) -> Result<(), syn::Error> {
+    if env::var_os(CODE_GEN_DEBUG_VAR).is_none() {
+        return Ok(());
+    }
+
+    let mut file = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+    file.push(path.value());
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    let contents = format!(
+        "// Generated by #[derive(Builder)] for `{}` at {} seconds since the Unix epoch.\n\n{}\n",
+        ident, timestamp, tokens
+    );
+
+    fs::write(&file, contents).map_err(|err| {
+        syn::Error::new_spanned(
+            path,
+            format!(
+                "could not write the generated code to `{}`: {}",
+                file.display(),
+                err
+            ),
+        )
+    })
+}
+
+/// Expand `#[derive(Builder)]` on `input`.
+///
+/// This is what the `Builder` derive of `derive_builder` emits, as a plain function, so that
+/// the generated code can be tested without compiling it through a procedural macro. Errors in
+/// the input are returned as `compile_error!` invocations.
+pub fn expand(input: syn::DeriveInput) -> proc_macro2::TokenStream {
+    builder_for_struct(input)
+}
 
 /// Derive a builder for a struct
-pub fn builder_for_struct(ast: syn::DeriveInput) -> proc_macro2::TokenStream {
+pub fn builder_for_struct(mut ast: syn::DeriveInput) -> proc_macro2::TokenStream {
+    if let syn::Data::Enum(_) = ast.data {
+        return darling::Error::custom(
+            "#[derive(Builder)] does not support enums, use #[derive(EnumBuilder)] to choose a unit variant",
+        )
+        .with_span(&ast.ident)
+        .write_errors();
+    }
+
+    macro_options::forward_struct_attrs(&mut ast.attrs);
+
     let opts = match macro_options::Options::from_derive_input(&ast) {
         Ok(val) => val,
         Err(err) => {
@@ -78,13 +189,441 @@ pub fn builder_for_struct(ast: syn::DeriveInput) -> proc_macro2::TokenStream {
         struct_name = ast.ident
     ));
 
+    if let Some(cache_ty) = opts.build_cache_type() {
+        builder.push_build_cache(cache_ty);
+    }
+
+    if opts.has_field_names() {
+        builder.push_field_names(
+            opts.fields()
+                .map(|field| field.field_ident().unraw().to_string())
+                .collect(),
+        );
+    }
+
+    if let Some(fields) = opts.struct_update_fields() {
+        builder.push_struct_update(&fields, opts.build_cache_type().is_some());
+    }
+
+    let mut from_env = opts.as_from_env();
+    let mut builder_diff = opts.as_builder_diff();
+    let mut builder_debug = opts.as_builder_debug();
+    let mut builder_hash = opts.as_builder_hash();
+    let mut builder_display = opts.as_builder_display();
+    let mut builder_parts = opts.as_builder_parts();
+    let mut builder_partial = opts.as_builder_partial();
+    let mut build_into = opts.as_build_into();
+    let mut builder_snapshot = opts.as_builder_snapshot();
+    let mut builder_serialize = opts.as_builder_serialize();
+    let mut into_builder = opts.as_into_builder();
+    let mut to_builder = opts.as_to_builder();
+    let mut builder_extend = opts.as_builder_extend();
+    let mut builder_patch = opts.as_builder_patch();
+    let mut from_parts = opts.as_from_parts();
+    let mut builder_interface = opts.as_builder_interface();
+
     for field in opts.fields() {
         builder.push_field(field.as_builder_field());
-        builder.push_setter_fn(field.as_setter());
+        if field.uses_default_struct() {
+            builder.push_default_struct_field(field.field_ident());
+        }
+        let setter = field.as_setter();
+        builder_interface.push_setter(&setter);
+        builder_extend.push_setter(&setter);
+        builder_patch.push_setter(&setter);
+        if field.is_required() {
+            from_parts.push_setter(&setter);
+        }
+        builder.push_setter_fn(setter);
+        if let Some((alias, ty)) = field.setter_type_alias() {
+            builder.push_type_alias(&field.setter_vis(), alias, ty);
+        }
+        if let Some(has) = field.has_method_ident() {
+            builder.push_has_method(has, field.field_ident());
+        }
+        if let Some(bound) = field.build_fn_bound() {
+            from_parts.push_bound(bound.clone());
+            build_fn.push_bound(bound);
+        }
+        if field.is_required() {
+            build_fn.push_required_field(field.field_ident());
+        }
+        if let Some(var) = field.as_env_var() {
+            from_env.push_var(var);
+        }
+        builder_diff.push_field(field.as_diff_field());
+        builder_debug.push_field(field.as_debug_field());
+        builder_hash.push_field(field.as_hash_field());
+        builder_display.push_field(field.as_display_field());
+        builder_parts.push_field(field.as_parts_field());
+        builder_partial.push_field(field.as_partial_field());
+        build_into.push_field(field.as_build_into_field());
+        builder_snapshot.push_field(field.as_snapshot_field());
+        builder_serialize.push_field(field.as_serialize_field());
+        into_builder.push_field(field.as_into_builder_field());
+        to_builder.push_field(field.as_into_builder_field());
+    }
+
+    for field in opts.fields_in_build_order() {
         build_fn.push_initializer(field.as_initializer());
     }
 
     builder.push_build_fn(build_fn);
+    builder.push_from_env(from_env);
+    if let Some(inherited) = opts.as_inherited_setters() {
+        builder.push_inherited_setters(inherited);
+    }
+
+    let tokens = quote!(
+        #builder
+        #builder_diff
+        #builder_debug
+        #builder_hash
+        #builder_display
+        #builder_parts
+        #builder_partial
+        #build_into
+        #builder_snapshot
+        #builder_serialize
+        #into_builder
+        #to_builder
+        #builder_extend
+        #builder_patch
+        #from_parts
+        #builder_interface
+    );
+
+    if let Some(path) = opts.code_gen_debug() {
+        if let Err(err) = write_code_gen_debug(path, &ast.ident, &tokens) {
+            let error = err.to_compile_error();
+            return quote!(#tokens #error);
+        }
+    }
+
+    tokens
+}
+
+/// Derive a builder choosing a unit variant of an enum
+pub fn builder_for_enum(ast: syn::DeriveInput) -> proc_macro2::TokenStream {
+    let opts = match macro_options::EnumOptions::from_derive_input(&ast) {
+        Ok(val) => val,
+        Err(err) => {
+            return err.write_errors();
+        }
+    };
+
+    let mut builder = opts.as_enum_builder();
+    for variant in opts.variant_idents() {
+        builder.push_variant(variant);
+    }
 
     quote!(#builder)
 }
+
+/// Implement `derive_builder::Newtype` for a tuple struct with a single field
+pub fn newtype_for_struct(ast: syn::DeriveInput) -> proc_macro2::TokenStream {
+    let inner = match ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(ref fields),
+            ..
+        }) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+        _ => {
+            return darling::Error::custom(
+                "#[derive(Newtype)] requires a tuple struct with a single field",
+            )
+            .with_span(&ast.ident)
+            .write_errors();
+        }
+    };
+
+    let newtype = NewtypeImpl {
+        ident: &ast.ident,
+        generics: &ast.generics,
+        inner,
+    };
+
+    quote!(#newtype)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setter_forwards_all_doc_attributes_in_order() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Lorem {
+                /// First line.
+                /// Second line.
+                #[doc = r"Third \line."]
+                ipsum: u32,
+            }
+        };
+
+        let expected_setter = quote!(
+            /// First line.
+            /// Second line.
+            #[doc = r"Third \line."]
+            #[allow(unused_mut)]
+            pub fn ipsum(&mut self, value: u32) -> &mut Self
+        );
+
+        let output = builder_for_struct(ast).to_string();
+        assert!(
+            output.contains(&expected_setter.to_string()),
+            "setter docs missing or reordered in:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn setters_are_emitted_in_declaration_order() {
+        let ast: syn::DeriveInput = parse_quote! {
+            #[builder(try_setter, impl_has_methods)]
+            struct Lorem {
+                zeta: u32,
+                #[builder(setter(each = "alpha_item"))]
+                alpha: Vec<u32>,
+                #[builder(setter(name = "mid"))]
+                omega: u32,
+                beta: u32,
+            }
+        };
+
+        let output = builder_for_struct(ast.clone()).to_string();
+        assert_eq!(output, builder_for_struct(ast).to_string());
+
+        let positions: Vec<usize> = [
+            "zeta",
+            "try_zeta",
+            "has_required_zeta",
+            "alpha",
+            "try_alpha",
+            "alpha_item",
+            "has_required_alpha",
+            "mid",
+            "try_mid",
+            "has_required_omega",
+            "beta",
+            "try_beta",
+            "has_required_beta",
+        ]
+        .iter()
+        .map(|method| {
+            let method = quote::format_ident!("{}", method);
+            output
+                .find(&quote!(fn #method).to_string())
+                .unwrap_or_else(|| panic!("missing `{}` in:\n{}", method, output))
+        })
+        .collect();
+        assert!(
+            positions.windows(2).all(|pair| pair[0] < pair[1]),
+            "methods out of declaration order in:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn setters_visibility_from_struct() {
+        let ast: syn::DeriveInput = parse_quote! {
+            #[builder(setters_visibility_from_struct)]
+            pub(crate) struct Lorem {
+                ipsum: u32,
+                #[builder(vis = "pub(super)")]
+                dolor: u32,
+                pub(in crate::sit) amet: u32,
+            }
+        };
+
+        let output = builder_for_struct(ast).to_string();
+        for expected in &[
+            quote!(pub(crate) struct LoremBuilder),
+            quote!(pub(crate) fn ipsum),
+            quote!(pub(super) fn dolor),
+            quote!(pub(in crate::sit) fn amet),
+            quote!(pub(crate) fn build),
+        ] {
+            assert!(
+                output.contains(&expected.to_string()),
+                "missing `{}` in:\n{}",
+                expected,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn code_gen_debug() {
+        let file = env::temp_dir().join("derive_builder_code_gen_debug.rs");
+        let _ = fs::remove_file(&file);
+        let path = file.to_str().unwrap();
+        let ast: syn::DeriveInput = parse_quote! {
+            #[builder(code_gen_debug = #path)]
+            struct Lorem {
+                ipsum: u32,
+            }
+        };
+
+        env::remove_var(CODE_GEN_DEBUG_VAR);
+        let output = builder_for_struct(ast.clone());
+        assert!(!file.exists());
+
+        env::set_var(CODE_GEN_DEBUG_VAR, "1");
+        assert_eq!(builder_for_struct(ast).to_string(), output.to_string());
+        env::remove_var(CODE_GEN_DEBUG_VAR);
+
+        let contents = fs::read_to_string(&file).unwrap();
+        fs::remove_file(&file).unwrap();
+        let mut lines = contents.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("// Generated by #[derive(Builder)] for `Lorem` at "));
+        assert_eq!(lines.next(), Some(""));
+        assert_eq!(lines.next(), Some(output.to_string().as_str()));
+    }
+
+    #[test]
+    fn single_field_builder_is_transparent() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Lorem<T> {
+                ipsum: T,
+                #[builder(setter(skip))]
+                dolor: u32,
+            }
+        };
+
+        let output = builder_for_struct(ast).to_string();
+        assert!(
+            output.contains(&quote!(#[repr(transparent)] struct LoremBuilder).to_string()),
+            "`repr(transparent)` missing in:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn multi_field_builder_is_not_transparent() {
+        let transparent = quote!(#[repr(transparent)]).to_string();
+        let asts: Vec<syn::DeriveInput> = vec![
+            parse_quote! {
+                struct Lorem {
+                    ipsum: u32,
+                    dolor: u32,
+                }
+            },
+            parse_quote! {
+                #[builder(lazy_build)]
+                struct Lorem {
+                    ipsum: u32,
+                }
+            },
+            parse_quote! {
+                #[builder_struct_attr(repr(C))]
+                struct Lorem {
+                    ipsum: u32,
+                }
+            },
+        ];
+
+        for ast in asts {
+            let output = builder_for_struct(ast).to_string();
+            assert!(
+                !output.contains(&transparent),
+                "unexpected `repr(transparent)` in:\n{}",
+                output
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "clippy"))]
+    fn default_lints_are_allowed() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Lorem {
+                ipsum: u32,
+            }
+        };
+
+        let expected_attr = quote!(#[allow(
+            clippy::clone_on_copy,
+            clippy::let_and_return,
+            clippy::needless_update,
+            clippy::new_without_default,
+            clippy::question_mark,
+            clippy::redundant_clone,
+            clippy::redundant_field_names,
+            clippy::suspicious_else_formatting,
+            clippy::too_many_arguments,
+            clippy::type_complexity,
+            clippy::unit_arg,
+            clippy::useless_conversion,
+            clippy::wrong_self_convention
+        )]);
+
+        let output = builder_for_struct(ast).to_string();
+        assert!(
+            output.contains(&expected_attr.to_string()),
+            "default lints missing in:\n{}",
+            output
+        );
+        assert!(!output.contains(&quote!(clippy::all).to_string()));
+    }
+
+    #[test]
+    #[cfg(not(feature = "clippy"))]
+    fn allowed_lints_are_overridable() {
+        let ast: syn::DeriveInput = parse_quote! {
+            #[builder(allow_lints(clippy::needless_update))]
+            struct Lorem {
+                ipsum: u32,
+            }
+        };
+
+        let output = builder_for_struct(ast).to_string();
+        assert!(output.contains(&quote!(#[allow(clippy::needless_update)]).to_string()));
+        assert!(!output.contains(&quote!(clippy::too_many_arguments).to_string()));
+    }
+
+    #[test]
+    fn no_allowed_lints() {
+        let ast: syn::DeriveInput = parse_quote! {
+            #[builder(allow_lints())]
+            struct Lorem {
+                ipsum: u32,
+            }
+        };
+
+        let output = builder_for_struct(ast).to_string();
+        assert!(
+            !output.contains("clippy"),
+            "unexpected lints in:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn self_in_field_types_names_the_struct() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Lorem<T> {
+                ipsum: Option<Box<Self>>,
+                dolor: <Self as Shape>::Out,
+                sit: Vec<Self::Item>,
+                amet: T,
+            }
+        };
+
+        let output = builder_for_struct(ast).to_string();
+        for expected in &[
+            quote!(ipsum: ::derive_builder::export::core::option::Option<Option<Box<Lorem<T> > > >,),
+            quote!(dolor: ::derive_builder::export::core::option::Option< <Lorem<T> as Shape>::Out>,),
+            quote!(sit: ::derive_builder::export::core::option::Option<Vec< <Lorem<T> >::Item> >,),
+        ] {
+            assert!(
+                output.contains(&expected.to_string()),
+                "missing `{}` in:\n{}",
+                expected,
+                output
+            );
+        }
+    }
+}
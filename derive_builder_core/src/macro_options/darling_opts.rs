@@ -1,19 +1,35 @@
 use std::{borrow::Cow, vec::IntoIter};
 
+use crate::extend::variant_ident;
+use crate::initializer::computed_field_accesses;
+use crate::setter::{
+    extract_type_from_collection, extract_type_from_option, extract_type_from_smart_pointer,
+    extract_type_from_vec, newtype_constructor,
+};
 use crate::BuildMethod;
 
 use darling::util::{Flag, PathList};
 use darling::{self, Error, FromMeta};
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use syn::ext::IdentExt;
 use syn::parse::{ParseStream, Parser};
 use syn::Meta;
 use syn::{self, spanned::Spanned, Attribute, Generics, Ident, Path};
 
 use crate::{
-    BlockContents, Builder, BuilderField, BuilderFieldType, BuilderPattern, DefaultExpression,
-    DeprecationNotes, Each, FieldConversion, Initializer, Setter,
+    doc_comment_from, BlockContents, BuildFnInto, BuildFnReceiver, BuildInto, BuildIntoField,
+    Builder, BuilderDebug, BuilderDiff, BuilderDisplay, BuilderExtend, BuilderField,
+    BuilderFieldType, BuilderHash, BuilderInterface, BuilderPartial, BuilderParts, BuilderPatch,
+    BuilderPattern, BuilderSerialize, BuilderSnapshot, DebugField, DefaultExpression,
+    DeprecationNotes, DiffField, DisplayField, Each, EnvVar, FieldConversion, FromEnv, FromParts,
+    HashField, InheritedSetters, Initializer, IntoBuilder, IntoBuilderField, NewtypeInner,
+    Normalize, PartialField, PartsField, SerializeField, SetOnce, Setter, SnapshotField, ToBuilder,
 };
 
+/// The most fields `#[builder(impl_from_parts)]` accepts in its tuple, like the trait
+/// implementations of tuples in the standard library.
+const MAX_FROM_PARTS: usize = 12;
+
 /// `derive_builder` uses separate sibling keywords to represent
 /// mutually-exclusive visibility states. This trait requires implementers to
 /// expose those property values and provides a method to compute any explicit visibility
@@ -28,7 +44,7 @@ trait Visibility {
     ///
     /// # Panics
     /// This method panics if the input specifies both `public` and `private`.
-    fn as_expressed_vis(&self) -> Option<Cow<syn::Visibility>> {
+    fn as_expressed_vis(&self) -> Option<Cow<'_, syn::Visibility>> {
         let declares_public = self.public().is_present();
         let declares_private = self.private().is_present();
         let declares_explicit = self.explicit().is_some();
@@ -81,9 +97,10 @@ pub struct BuildFn {
     public: Flag,
     private: Flag,
     vis: Option<syn::Visibility>,
-    /// The path to an existing error type that the build method should return.
+    /// The path to an existing error type that the build method should return, or options
+    /// for the generated error type.
     ///
-    /// Setting this will prevent `derive_builder` from generating an error type for the build
+    /// Setting a path will prevent `derive_builder` from generating an error type for the build
     /// method.
     ///
     /// # Type Bounds
@@ -94,7 +111,54 @@ pub struct BuildFn {
     ///   when default values are provided for every field or at the struct level.
     /// * If `validate` is specified, then this type must provide a conversion from the specified
     ///   function's error type.
-    error: Option<Path>,
+    error: Option<BuildFnError>,
+    /// Also emit an `unsafe` build method, suffixed `_unchecked`, which skips checks for
+    /// uninitialized fields and validation.
+    unchecked: bool,
+    /// Also emit a build method, suffixed `_expect`, which panics on uninitialized fields
+    /// rather than returning an error, and skips validation.
+    expect: bool,
+    /// How the build method takes `self`: `"self"`, `"ref"` or `"ref_mut"`. Defaults to
+    /// consuming the builder under the owned pattern and borrowing it otherwise.
+    receiver: Option<BuildFnReceiver>,
+    /// Return a type converted from the target struct with `From`, either the given one or one
+    /// chosen by the caller.
+    into: Option<BuildFnInto>,
+    /// The full return type of the build method, e.g. `anyhow::Result<Foo>`. The error of the
+    /// build is converted into its error type with `?`.
+    result_type: Option<syn::Type>,
+    /// Also emit a build method, suffixed `_into`, which writes the fields set in the builder
+    /// into an existing instance of the struct.
+    into_target: bool,
+}
+
+/// The `error` property of `build_fn`.
+#[derive(Debug, Clone)]
+pub enum BuildFnError {
+    /// `error = "path::to::Error"`: use an existing error type.
+    Existing(Path),
+    /// `error(...)`: customize the generated error type.
+    Generated(GeneratedError),
+}
+
+impl FromMeta for BuildFnError {
+    // Parsing from the literal keeps its span on the path, which is where errors about a
+    // missing `From<UninitializedFieldError>` impl point.
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        Path::from_value(value).map(BuildFnError::Existing)
+    }
+
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        GeneratedError::from_list(items).map(BuildFnError::Generated)
+    }
+}
+
+/// Options for the error type generated for the build method.
+#[derive(Debug, Clone, Default, FromMeta)]
+pub struct GeneratedError {
+    /// Return as soon as the first uninitialized field is found, rather than
+    /// reporting all of them at once.
+    first_only: Flag,
 }
 
 impl Default for BuildFn {
@@ -107,6 +171,12 @@ impl Default for BuildFn {
             private: Default::default(),
             vis: None,
             error: None,
+            unchecked: false,
+            expect: false,
+            receiver: None,
+            into: None,
+            result_type: None,
+            into_target: false,
         }
     }
 }
@@ -131,6 +201,9 @@ pub struct StructLevelFieldMeta {
     public: Flag,
     private: Flag,
     vis: Option<syn::Visibility>,
+    /// Store every field as its plain type, initialized with `Default::default()`, instead of
+    /// an `Option`. The build method then cannot fail.
+    default: Flag,
 }
 
 impl Visibility for StructLevelFieldMeta {
@@ -162,6 +235,20 @@ pub struct FieldLevelFieldMeta {
     builder_type: Option<syn::Type>,
     /// Custom builder field method, for making target struct field value
     build: Option<BlockContents>,
+    /// Function applied to the value of the target field in the build method, after defaulting
+    map: Option<syn::Expr>,
+    /// Expression computing the target field from the other fields in the build method, instead
+    /// of a setter
+    computed: Option<syn::Expr>,
+    /// Conditions the value of the target field must satisfy in the build method
+    #[darling(multiple)]
+    assert: Vec<BlockContents>,
+    /// Messages of the validation errors for failed assertions, one per `assert`
+    #[darling(multiple)]
+    error: Vec<syn::LitStr>,
+    /// Mask the value of the field in the builder's `Debug` and `Display` implementations and
+    /// in its snapshots
+    sensitive: Flag,
 }
 
 impl Visibility for FieldLevelFieldMeta {
@@ -180,10 +267,18 @@ impl Visibility for FieldLevelFieldMeta {
 
 #[derive(Debug, Clone, Default, FromMeta)]
 pub struct StructLevelSetter {
+    #[darling(with = "parse_affix")]
     prefix: Option<Ident>,
+    #[darling(with = "parse_affix")]
+    suffix: Option<Ident>,
+    /// Also emit the unprefixed setter when `prefix` or `suffix` is set.
+    keep_bare: Flag,
     into: Option<bool>,
     strip_option: Option<bool>,
     skip: Option<bool>,
+    /// Doc comment template for setters without documentation; `{field}` is replaced
+    /// with the field name.
+    doc: Option<String>,
 }
 
 impl StructLevelSetter {
@@ -194,12 +289,25 @@ impl StructLevelSetter {
     }
 }
 
+/// Create the `prefix` or `suffix` of a setter name from an attribute's `Meta`, treating an empty
+/// string as unset.
+fn parse_affix(meta: &Meta) -> darling::Result<Option<Ident>> {
+    match String::from_meta(meta)?.as_str() {
+        "" => Ok(None),
+        affix => syn::parse_str::<Ident>(affix)
+            .map(Some)
+            .map_err(|_| darling::Error::unknown_value(affix).with_span(meta)),
+    }
+}
+
 /// Create `Each` from an attribute's `Meta`.
 ///
 /// Two formats are supported:
 ///
 /// * `each = "..."`, which provides the name of the `each` setter and otherwise uses default values
-/// * `each(name = "...")`, which allows setting additional options on the `each` setter
+/// * `each(name = "...")`, which allows setting additional options on the `each` setter, such as
+///   `remove = "..."` for a method removing elements from a `Vec` field, or `dedup` to skip
+///   elements already in it
 fn parse_each(meta: &Meta) -> darling::Result<Option<Each>> {
     if let Meta::NameValue(mnv) = meta {
         if let syn::Lit::Str(v) = &mnv.lit {
@@ -220,7 +328,12 @@ fn parse_each(meta: &Meta) -> darling::Result<Option<Each>> {
 /// name overrides.
 #[derive(Debug, Clone, Default, FromMeta)]
 pub struct FieldLevelSetter {
+    #[darling(with = "parse_affix")]
     prefix: Option<Ident>,
+    #[darling(with = "parse_affix")]
+    suffix: Option<Ident>,
+    /// Also emit the unprefixed setter when `prefix` or `suffix` is set.
+    keep_bare: Flag,
     name: Option<Ident>,
     into: Option<bool>,
     strip_option: Option<bool>,
@@ -228,6 +341,54 @@ pub struct FieldLevelSetter {
     custom: Option<bool>,
     #[darling(with = "parse_each")]
     each: Option<Each>,
+    /// Fall back to `<FieldType as Default>::default()` if the field is unset, adding
+    /// a `FieldType: Default` bound to the build method.
+    or_default: Flag,
+    /// Reject a second call to the setter, either with an error or a panic.
+    once: Option<SetOnce>,
+    /// Make the setter generic over `AsRef<str>` for `String` fields.
+    as_ref: Flag,
+    /// Make the setter return the previous value of the builder field instead of the builder.
+    return_previous: Flag,
+    /// Normalize the value of a `String` field before storing it.
+    normalize: Option<Normalize>,
+    /// Validate the value in the setter, which then returns a `Result` with the builder's
+    /// error type.
+    validate: Option<syn::Expr>,
+    /// Make the setter generic over `TryInto<T>`, returning a `Result` with the conversion
+    /// error.
+    try_into: Flag,
+    /// Make the setter accept the inner type of a tuple newtype, wrapping it automatically.
+    newtype_inner: Option<NewtypeInner>,
+    /// Make the setter accept the pointee of a `Box`, `Rc` or `Arc` field, wrapping it
+    /// automatically.
+    boxed: Flag,
+    /// Make the setter accept any `IntoIterator` whose items convert into the items of a
+    /// collection field, collecting them.
+    into_iter: Flag,
+    /// Name the setter's parameter type with a type alias. A bare identifier emits the alias
+    /// next to the builder; a longer path refers to an existing alias.
+    type_alias: Option<Path>,
+    /// Doc comment for the setter, overriding the struct-level template; `{field}` is
+    /// replaced with the field name.
+    doc: Option<String>,
+    /// Closure computing the value of an unset field in the build method. The builder field
+    /// then holds a boxed closure, which the setter fills with the value it is given.
+    lazy: Option<syn::Expr>,
+    /// Deprecation note emitted as `#[deprecated(note = "...")]` on the setter.
+    deprecated: Option<syn::LitStr>,
+    /// Also emit `try_add_` and `saturating_add_` methods adding to an integer field.
+    checked_add: Flag,
+    /// Closure with one typed parameter, which the setter takes and converts into the value
+    /// to store.
+    transform: Option<syn::Expr>,
+    /// Also emit a `with_` method passing the field to a closure to modify it in place,
+    /// starting from its default value.
+    with_mut: Flag,
+    /// Also emit a `maybe_` setter taking the `Option` of an `Option<T>` field as is, and strip
+    /// the `Option` from the primary setter unless `strip_option` says otherwise.
+    #[darling(with = "parse_option_setter")]
+    option_setter: Option<OptionSetter>,
 }
 
 impl FieldLevelSetter {
@@ -251,10 +412,29 @@ impl FieldLevelSetter {
         }
 
         if self.prefix.is_some()
+            || self.suffix.is_some()
+            || self.keep_bare.is_present()
             || self.name.is_some()
             || self.into.is_some()
             || self.strip_option.is_some()
             || self.each.is_some()
+            || self.once.is_some()
+            || self.as_ref.is_present()
+            || self.return_previous.is_present()
+            || self.normalize.is_some()
+            || self.validate.is_some()
+            || self.try_into.is_present()
+            || self.newtype_inner.is_some()
+            || self.boxed.is_present()
+            || self.into_iter.is_present()
+            || self.type_alias.is_some()
+            || self.doc.is_some()
+            || self.lazy.is_some()
+            || self.deprecated.is_some()
+            || self.checked_add.is_present()
+            || self.transform.is_some()
+            || self.with_mut.is_present()
+            || self.option_setter.is_some()
         {
             return Some(true);
         }
@@ -290,6 +470,8 @@ pub struct Field {
     /// Raw input attributes, for consumption by Field::unnest_attrs.  Do not use elsewhere.
     attrs: Vec<syn::Attribute>,
     ty: syn::Type,
+    /// Visibility of the deriving struct's field.
+    vis: syn::Visibility,
     /// Field-level override for builder pattern.
     /// Note that setting this may force the builder to derive `Clone`.
     pattern: Option<BuilderPattern>,
@@ -316,7 +498,18 @@ pub struct Field {
     ///
     /// This property only captures the first two, the third is computed in `FieldWithDefaults`.
     default: Option<DefaultExpression>,
+    /// The `default` expression evaluates to a `Result`, whose error the build method returns.
+    #[darling(rename = "try")]
+    try_default: Flag,
+    /// Fail to build if this field is never set, even if the struct has a `default`.
+    required: Flag,
     try_setter: Flag,
+    /// Leave this field out of the builder's `from_env` constructor.
+    env_skip: Flag,
+    /// Only compare whether this field is set in the builder's `diff` method.
+    diff_presence_only: Flag,
+    /// Leave this field out of the builder's `Serialize` implementation.
+    serialize_skip: Flag,
     #[darling(default)]
     field: FieldLevelFieldMeta,
     #[darling(skip)]
@@ -361,6 +554,387 @@ impl Field {
             );
         };
 
+        if let Some(ref computed) = self.field.computed {
+            if self.default.is_some()
+                || self.setter.field_enabled() == Some(true)
+                || self.setter.setter_enabled() == Some(true)
+                || self.field.builder_type.is_some()
+                || self.field.build.is_some()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(field(computed = "..."))] cannot be used with `default`, `setter(...)`, `field(type = "...")` or `field(build = "...")`"#,
+                    )
+                    .with_span(computed),
+                );
+            }
+        }
+
+        if self.field.assert.len() != self.field.error.len() {
+            let error = darling::Error::custom(
+                r#"each #[builder(field(assert = "..."))] needs exactly one `error = "..."` message"#,
+            );
+            errors.push(match self.field.assert.first() {
+                Some(assertion) => error.with_span(assertion),
+                None => error.with_span(&self.field.error[0]),
+            });
+        }
+
+        if self.try_default.is_present() {
+            match self.default {
+                Some(DefaultExpression::Explicit(_)) => {}
+                _ => errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(try)] requires #[builder(default = "...")]"#,
+                    )
+                    .with_span(&self.try_default),
+                ),
+            }
+        }
+
+        if self.required.is_present()
+            && (self.default.is_some()
+                || self.setter.or_default.is_present()
+                || self.setter.lazy.is_some()
+                || self.setter.field_enabled() == Some(false)
+                || self.field.builder_type.is_some()
+                || self.field.build.is_some())
+        {
+            errors.push(
+                darling::Error::custom(
+                    r#"#[builder(required)] cannot be used with `default`, `setter(or_default)`, `setter(lazy = "...")`, `setter(skip)`, `field(type = "...")` or `field(build = "...")`"#,
+                )
+                .with_span(&self.required),
+            );
+        }
+
+        if let (Some(field_default), true) = (&self.default, self.setter.or_default.is_present()) {
+            errors.push(
+                darling::Error::custom(
+                    r#"#[builder(default)] and #[builder(setter(or_default))] cannot be used together"#,
+                )
+                .with_span(field_default),
+            );
+        };
+
+        if self.setter.as_ref.is_present() {
+            if self.setter.into == Some(true) {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(as_ref))] and #[builder(setter(into))] cannot be used together",
+                    )
+                    .with_span(&self.setter.as_ref),
+                );
+            }
+
+            if !is_string_type(&self.ty) {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(as_ref))] can only be used on `String` fields",
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+        }
+
+        if self.setter.normalize.is_some() && !is_string_type(&self.ty) {
+            errors.push(
+                darling::Error::custom(
+                    r#"#[builder(setter(normalize = "..."))] can only be used on `String` fields"#,
+                )
+                .with_span(&self.ty),
+            );
+        }
+
+        if let Some(inner) = &self.setter.newtype_inner {
+            // With `#[derive(Newtype)]`, the trait bound checks the field type instead.
+            let explicit = match inner {
+                NewtypeInner::Explicit(_) => true,
+                NewtypeInner::Derived => false,
+            };
+            if explicit && newtype_constructor(&self.ty).is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(newtype_inner = "..."))] requires the field type to be a tuple struct path"#,
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+
+            if self.setter.strip_option.is_some() || self.setter.as_ref.is_present() {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(newtype_inner))] cannot be used with `strip_option` or `as_ref`",
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+        }
+
+        if self.setter.boxed.is_present() {
+            let pointer_ty = extract_type_from_option(&self.ty).unwrap_or(&self.ty);
+            if extract_type_from_smart_pointer(&self.ty).is_none()
+                && extract_type_from_smart_pointer(pointer_ty).is_none()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(boxed))] can only be used on `Box`, `Rc` or `Arc` fields",
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+
+            if self.setter.newtype_inner.is_some() || self.setter.as_ref.is_present() {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(boxed))] cannot be used with `newtype_inner` or `as_ref`",
+                    )
+                    .with_span(&self.setter.boxed),
+                );
+            }
+        }
+
+        if self.setter.into_iter.is_present() {
+            let items_ty = extract_type_from_option(&self.ty).unwrap_or(&self.ty);
+            if extract_type_from_collection(items_ty).is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(into_iter))] can only be used on collections with a single type argument, such as `Vec<T>`",
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+
+            if self.setter.newtype_inner.is_some()
+                || self.setter.as_ref.is_present()
+                || self.setter.boxed.is_present()
+                || self.setter.type_alias.is_some()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(into_iter))] cannot be used with `newtype_inner`, `as_ref`, `boxed` or `type_alias`",
+                    )
+                    .with_span(&self.setter.into_iter),
+                );
+            }
+        }
+
+        if let Some(ref transform) = self.setter.transform {
+            if closure_param_type(transform).is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(transform = "..."))] requires a closure with one typed parameter, e.g. `|x: String| ...`"#,
+                    )
+                    .with_span(transform),
+                );
+            }
+
+            if self.setter.into == Some(true)
+                || self.setter.as_ref.is_present()
+                || self.setter.newtype_inner.is_some()
+                || self.setter.boxed.is_present()
+                || self.setter.into_iter.is_present()
+                || self.setter.type_alias.is_some()
+                || self.try_setter.is_present()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(transform = "..."))] cannot be used with `into`, `as_ref`, `newtype_inner`, `boxed`, `into_iter`, `type_alias` or `try_setter`"#,
+                    )
+                    .with_span(transform),
+                );
+            }
+        }
+
+        if let Some(ref alias) = self.setter.type_alias {
+            if self.setter.newtype_inner.is_some()
+                || self.setter.as_ref.is_present()
+                || self.setter.boxed.is_present()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(type_alias = "..."))] cannot be used with `newtype_inner`, `as_ref` or `boxed`"#,
+                    )
+                    .with_span(alias),
+                );
+            }
+        }
+
+        if let Some(ref validate) = self.setter.validate {
+            if self.setter.once.is_some()
+                || self.setter.return_previous.is_present()
+                || self.setter.each.is_some()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(validate = "..."))] cannot be used with `once`, `return_previous` or `each`"#,
+                    )
+                    .with_span(validate),
+                );
+            }
+        }
+
+        if self.setter.try_into.is_present()
+            && (self.setter.into == Some(true)
+                || self.setter.as_ref.is_present()
+                || self.setter.newtype_inner.is_some()
+                || self.setter.boxed.is_present()
+                || self.setter.into_iter.is_present()
+                || self.setter.transform.is_some()
+                || self.setter.type_alias.is_some()
+                || self.setter.validate.is_some()
+                || self.setter.once.is_some()
+                || self.setter.return_previous.is_present())
+        {
+            errors.push(
+                darling::Error::custom(
+                    "#[builder(setter(try_into))] cannot be used with `into`, `as_ref`, `newtype_inner`, `boxed`, `into_iter`, `transform`, `type_alias`, `validate`, `once` or `return_previous`",
+                )
+                .with_span(&self.setter.try_into),
+            );
+        }
+
+        if let (true, Some(_)) = (self.setter.return_previous.is_present(), self.setter.once) {
+            errors.push(
+                darling::Error::custom(
+                    "#[builder(setter(return_previous))] and #[builder(setter(once))] cannot be used together",
+                )
+                .with_span(&self.setter.return_previous),
+            );
+        }
+
+        // `each` setters add to the field rather than set it, so there is nothing to set once.
+        if let (Some(_), Some(_)) = (&self.setter.each, self.setter.once) {
+            errors.push(
+                darling::Error::custom(
+                    "#[builder(setter(each(...)))] and #[builder(setter(once))] cannot be used together",
+                )
+                .with_span(&self.ty),
+            );
+        }
+
+        if let (Some(builder_type), Some(_)) = (&self.field.builder_type, self.setter.once) {
+            errors.push(
+                darling::Error::custom(
+                    r#"#[builder(setter(once))] cannot be used with #[builder(field(type="..."))]"#,
+                )
+                .with_span(builder_type),
+            );
+        }
+
+        if let Some(ref lazy) = self.setter.lazy {
+            if self.default.is_some()
+                || self.setter.or_default.is_present()
+                || self.setter.each.is_some()
+                || self.setter.return_previous.is_present()
+                || self.field.builder_type.is_some()
+                || self.field.build.is_some()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(lazy = "..."))] cannot be used with `default`, `or_default`, `each`, `return_previous` or `field(...)`"#,
+                    )
+                    .with_span(lazy),
+                );
+            }
+        }
+
+        if let Some(each) = &self.setter.each {
+            if each.name.is_none() && each.remove.is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(each(...)))] requires `name = "..."` or `remove = "..."`"#,
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+
+            if each.dedup.is_some() {
+                let items_ty = extract_type_from_option(&self.ty).unwrap_or(&self.ty);
+                if each.name.is_none() || extract_type_from_vec(items_ty).is_none() {
+                    errors.push(
+                        darling::Error::custom(
+                            r#"#[builder(setter(each(dedup)))] requires `name = "..."` and can only be used on `Vec` fields"#,
+                        )
+                        .with_span(&self.ty),
+                    );
+                }
+            }
+
+            if let Some(remove) = &each.remove {
+                let items_ty = extract_type_from_option(&self.ty).unwrap_or(&self.ty);
+                if extract_type_from_vec(items_ty).is_none() {
+                    errors.push(
+                        darling::Error::custom(
+                            r#"#[builder(setter(each(remove = "...")))] can only be used on `Vec` fields"#,
+                        )
+                        .with_span(remove),
+                    );
+                }
+            }
+        }
+
+        if self.setter.checked_add.is_present() {
+            if !is_integer_type(&self.ty) {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(checked_add))] can only be used on primitive integer fields",
+                    )
+                    .with_span(&self.ty),
+                );
+            } else if self.setter.lazy.is_some() || self.field.builder_type.is_some() {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(checked_add))] cannot be used with `lazy` or `field(type = "...")`"#,
+                    )
+                    .with_span(&self.setter.checked_add),
+                );
+            }
+        }
+
+        if self.setter.with_mut.is_present() {
+            let has_default = (self.default.is_some() && !self.try_default.is_present())
+                || self.setter.or_default.is_present();
+            if !has_default {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(with_mut))] requires `default` or `setter(or_default)` to initialize an unset field",
+                    )
+                    .with_span(&self.setter.with_mut),
+                );
+            } else if self.setter.lazy.is_some() || self.field.builder_type.is_some() {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(with_mut))] cannot be used with `lazy` or `field(type = "...")`"#,
+                    )
+                    .with_span(&self.setter.with_mut),
+                );
+            }
+        }
+
+        if self.setter.option_setter.is_some() {
+            if extract_type_from_option(&self.ty).is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        "#[builder(setter(option_setter))] can only be used on `Option` fields",
+                    )
+                    .with_span(&self.ty),
+                );
+            } else if self.setter.lazy.is_some()
+                || self.setter.once.is_some()
+                || self.setter.validate.is_some()
+                || self.setter.return_previous.is_present()
+                || self.field.builder_type.is_some()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        r#"#[builder(setter(option_setter))] cannot be used with `lazy`, `once`, `validate`, `return_previous` or `field(type = "...")`"#,
+                    )
+                    .with_span(&self.ty),
+                );
+            }
+        }
+
         errors.handle(distribute_and_unnest_attrs(
             &mut self.attrs,
             &mut [
@@ -369,10 +943,114 @@ impl Field {
             ],
         ));
 
+        // A field-level `setter(doc)` replaces the doc comment of the field on the setter.
+        if self.setter.doc.is_some() {
+            self.setter_attrs.retain(|attr| !attr.path.is_ident("doc"));
+        }
+
+        if let Some(ref note) = self.setter.deprecated {
+            self.setter_attrs
+                .push(parse_quote_spanned!(note.span()=> #[deprecated(note = #note)]));
+        }
+
         errors.finish_with(self)
     }
 }
 
+/// Get the type of the only parameter of the closure `expr`, if it is declared.
+fn closure_param_type(expr: &syn::Expr) -> Option<&syn::Type> {
+    let closure = match expr {
+        syn::Expr::Closure(closure) if closure.inputs.len() == 1 => closure,
+        _ => return None,
+    };
+    match closure.inputs.first() {
+        Some(syn::Pat::Type(pat)) => Some(&pat.ty),
+        _ => None,
+    }
+}
+
+/// Check whether `path` names the `Debug` trait, e.g. `Debug` or `std::fmt::Debug`.
+fn is_debug_path(path: &Path) -> bool {
+    path.segments.last().map(|segment| &segment.ident) == Some(&Ident::new("Debug", path.span()))
+}
+
+/// Check whether `ty` names a primitive integer type, which has `checked_add` and
+/// `saturating_add` methods.
+fn is_integer_type(ty: &syn::Type) -> bool {
+    let ident = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => match path.get_ident() {
+            Some(ident) => ident,
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ]
+    .iter()
+    .any(|name| ident == name)
+}
+
+/// Check whether `ty` names `String`, either directly or through the `std` or `alloc` paths.
+fn is_string_type(ty: &syn::Type) -> bool {
+    let path = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path,
+        _ => return false,
+    };
+
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| match segment.arguments {
+            syn::PathArguments::None => Some(segment.ident.to_string()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>();
+
+    match segments {
+        Some(segments) => {
+            let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+            segments == ["String"]
+                || segments == ["std", "string", "String"]
+                || segments == ["alloc", "string", "String"]
+        }
+        None => false,
+    }
+}
+
+/// Replace every `Self` in `tokens` with `target`, recording in `found` whether there was one.
+///
+/// `Self::Assoc` becomes `<Target>::Assoc`, as `Target<T>::Assoc` is not a valid type.
+fn replace_self(tokens: TokenStream, target: &TokenStream, found: &mut bool) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut replaced = TokenStream::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ref ident) if ident == "Self" => {
+                *found = true;
+                let is_path_prefix = match tokens.peek() {
+                    Some(TokenTree::Punct(punct)) => punct.as_char() == ':',
+                    _ => false,
+                };
+                if is_path_prefix {
+                    replaced.extend(quote!(<#target>));
+                } else {
+                    replaced.extend(target.clone());
+                }
+            }
+            TokenTree::Group(ref group) => {
+                let stream = replace_self(group.stream(), target, found);
+                let mut new_group = Group::new(group.delimiter(), stream);
+                new_group.set_span(group.span());
+                replaced.extend(Some(TokenTree::Group(new_group)));
+            }
+            token => replaced.extend(Some(token)),
+        }
+    }
+    replaced
+}
+
 /// Divide a list of attributes into multiple partially-overlapping output lists.
 ///
 /// Some attributes from the macro input will be added to the output in multiple places;
@@ -467,6 +1145,84 @@ fn unnest_from_one_attribute(attr: syn::Attribute) -> darling::Result<Attribute>
     Ok(attr)
 }
 
+/// Rewrite each `struct_attr(...)` entry of the `#[builder(...)]` attributes in `attrs` into
+/// `#[builder_struct_attr(...)]` attributes, one per comma-separated attribute it contains.
+///
+/// The contents of `struct_attr(...)` are arbitrary token trees, such as
+/// `doc = concat!("a", "b")`, which `syn::Meta` cannot represent, so they are taken out before
+/// darling parses the rest of `#[builder(...)]` and left for `distribute_and_unnest_attrs`.
+pub fn forward_struct_attrs(attrs: &mut Vec<Attribute>) {
+    let mut rewritten = Vec::with_capacity(attrs.len());
+    for mut attr in attrs.drain(..) {
+        let group = match single_paren_group(attr.tokens.clone()) {
+            Some(group) if attr.path.is_ident("builder") => group,
+            _ => {
+                rewritten.push(attr);
+                continue;
+            }
+        };
+
+        let mut kept = Vec::new();
+        let mut forwarded = Vec::new();
+        for item in split_top_level_commas(group.stream()) {
+            let mut tokens = item.clone().into_iter();
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(TokenTree::Ident(ref ident)), Some(TokenTree::Group(ref inner)), None)
+                    if ident == "struct_attr" && inner.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let span = ident.span();
+                    forwarded.extend(split_top_level_commas(inner.stream()).into_iter().map(
+                        |content| -> Attribute {
+                            parse_quote_spanned!(span=> #[builder_struct_attr(#content)])
+                        },
+                    ));
+                }
+                _ => kept.push(item),
+            }
+        }
+
+        if !forwarded.is_empty() {
+            let mut stream = Group::new(Delimiter::Parenthesis, quote!(#(#kept),*));
+            stream.set_span(group.span());
+            attr.tokens = TokenTree::Group(stream).into();
+        }
+        rewritten.push(attr);
+        rewritten.extend(forwarded);
+    }
+    *attrs = rewritten;
+}
+
+/// The parenthesized group making up all of `tokens`, if any.
+fn single_paren_group(tokens: TokenStream) -> Option<Group> {
+    let mut tokens = tokens.into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::Parenthesis => {
+            Some(group)
+        }
+        _ => None,
+    }
+}
+
+/// Split `tokens` at the commas which are not nested in a group, dropping empty entries.
+fn split_top_level_commas(tokens: TokenStream) -> Vec<TokenStream> {
+    let mut items = Vec::new();
+    let mut current = Vec::new();
+    for token in tokens {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' => {
+                if !current.is_empty() {
+                    items.push(current.drain(..).collect());
+                }
+            }
+            token => current.push(token),
+        }
+    }
+    if !current.is_empty() {
+        items.push(current.into_iter().collect());
+    }
+    items
+}
+
 impl Visibility for Field {
     fn public(&self) -> &Flag {
         &self.public
@@ -481,66 +1237,336 @@ impl Visibility for Field {
     }
 }
 
-fn default_create_empty() -> Ident {
-    Ident::new("create_empty", Span::call_site())
+/// Options for `#[builder(impl_display)]`.
+#[derive(Debug, Clone, FromMeta)]
+#[darling(default)]
+pub struct ImplDisplay {
+    /// Print the values of set fields whose type implements `Display`, rather than just `set`.
+    show_values: bool,
 }
 
-#[derive(Debug, Clone, FromDeriveInput)]
-#[darling(
-    attributes(builder),
-    forward_attrs(cfg, allow, builder_struct_attr, builder_impl_attr),
-    supports(struct_named),
-    and_then = "Self::unnest_attrs"
-)]
-pub struct Options {
-    ident: Ident,
+impl Default for ImplDisplay {
+    fn default() -> Self {
+        ImplDisplay { show_values: true }
+    }
+}
 
-    /// DO NOT USE.
-    ///
-    /// Initial receiver for forwarded attributes from the struct; these are split
-    /// into `Options::struct_attrs` and `Options::impl_attrs` before `FromDeriveInput`
-    /// returns.
-    attrs: Vec<Attribute>,
+/// Create `ImplDisplay` from an attribute's `Meta`, accepting both the `impl_display` word and
+/// the `impl_display(show_values = ...)` list.
+fn parse_impl_display(meta: &Meta) -> darling::Result<Option<ImplDisplay>> {
+    if let Meta::Path(_) = meta {
+        Ok(Some(ImplDisplay::default()))
+    } else {
+        ImplDisplay::from_meta(meta).map(Some)
+    }
+}
 
-    #[darling(skip)]
-    struct_attrs: Vec<Attribute>,
+/// Options for `#[builder(impl_extend)]`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(default)]
+pub struct ImplExtend {
+    /// Emit `parse_from_str` on the field enum, parsing values with `FromStr`.
+    parse: bool,
+}
 
-    #[darling(skip)]
-    impl_attrs: Vec<Attribute>,
+/// Create `ImplExtend` from an attribute's `Meta`, accepting both the `impl_extend` word and
+/// the `impl_extend(parse)` list.
+fn parse_impl_extend(meta: &Meta) -> darling::Result<Option<ImplExtend>> {
+    if let Meta::Path(_) = meta {
+        Ok(Some(ImplExtend::default()))
+    } else {
+        ImplExtend::from_meta(meta).map(Some)
+    }
+}
 
-    /// The visibility of the deriving struct. Do not confuse this with `#[builder(vis = "...")]`,
-    /// which is received by `Options::visibility`.
-    vis: syn::Visibility,
+/// Options for `#[builder(build_partial)]`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(default)]
+pub struct BuildPartial {
+    /// Traits to derive on the partial struct.
+    derive: PathList,
+}
 
-    generics: Generics,
+/// Create `BuildPartial` from an attribute's `Meta`, accepting both the `build_partial` word
+/// and the `build_partial(derive(...))` list.
+fn parse_build_partial(meta: &Meta) -> darling::Result<Option<BuildPartial>> {
+    if let Meta::Path(_) = meta {
+        Ok(Some(BuildPartial::default()))
+    } else {
+        BuildPartial::from_meta(meta).map(Some)
+    }
+}
 
-    /// The name of the generated builder. Defaults to `#{ident}Builder`.
-    name: Option<Ident>,
+/// Options for `#[builder(no_std)]`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(default)]
+pub struct NoStd {
+    /// Store validation messages as `&'static str` rather than `String` in the generated
+    /// error type, so that the generated code never needs an allocator.
+    static_str_errors: Flag,
+}
 
-    #[darling(default)]
-    pattern: BuilderPattern,
+/// Create `NoStd` from an attribute's `Meta`, accepting both the `no_std` word and the
+/// `no_std(static_str_errors)` list.
+fn parse_no_std(meta: &Meta) -> darling::Result<Option<NoStd>> {
+    if let Meta::Path(_) = meta {
+        Ok(Some(NoStd::default()))
+    } else {
+        NoStd::from_meta(meta).map(Some)
+    }
+}
 
-    #[darling(default)]
-    build_fn: BuildFn,
+/// Options for `#[builder(impl_from_env_prefixed(env_prefix = "..."))]`.
+#[derive(Debug, Clone, FromMeta)]
+pub struct FromEnvPrefixed {
+    /// Prefix of the environment variable names, joined to the field name with `_`.
+    env_prefix: syn::LitStr,
+}
 
-    /// Additional traits to derive on the builder.
-    #[darling(default)]
-    derive: PathList,
+/// Options for `#[builder(impl_has_methods)]`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(default)]
+pub struct ImplHasMethods {
+    /// Prefix of the method names, `has_` unless given.
+    prefix: Option<Ident>,
+}
 
-    custom_constructor: Flag,
+/// Create `ImplHasMethods` from an attribute's `Meta`, accepting both the `impl_has_methods`
+/// word and the `impl_has_methods(prefix = "...")` list.
+fn parse_impl_has_methods(meta: &Meta) -> darling::Result<Option<ImplHasMethods>> {
+    if let Meta::Path(_) = meta {
+        Ok(Some(ImplHasMethods::default()))
+    } else {
+        ImplHasMethods::from_meta(meta).map(Some)
+    }
+}
 
-    /// The ident of the inherent method which takes no arguments and returns
-    /// an instance of the builder with all fields empty.
-    #[darling(default = "default_create_empty")]
-    create_empty: Ident,
+/// Options for `#[builder(setter(option_setter))]`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(default)]
+pub struct OptionSetter {
+    /// Prefix of the setter name, `maybe_` unless given.
+    prefix: Option<Ident>,
+}
 
-    /// Setter options applied to all field setters in the struct.
-    #[darling(default)]
+/// Create `OptionSetter` from an attribute's `Meta`, accepting both the `option_setter` word
+/// and the `option_setter(prefix = "...")` list.
+fn parse_option_setter(meta: &Meta) -> darling::Result<Option<OptionSetter>> {
+    if let Meta::Path(_) = meta {
+        Ok(Some(OptionSetter::default()))
+    } else {
+        OptionSetter::from_meta(meta).map(Some)
+    }
+}
+
+/// Options applied to every field of a type, e.g. `#[builder(on(type = "String", into))]`.
+#[derive(Debug, Clone, FromMeta)]
+pub struct TypeRule {
+    /// Type of the fields the rule applies to, matched exactly by its tokens.
+    #[darling(rename = "type")]
+    ty: syn::Type,
+    into: Option<bool>,
+    /// Function applied to the value of the field in the build method, like `field(map)`.
+    transform: Option<syn::Expr>,
+}
+
+/// Sort computed fields so that each comes after the computed fields it reads.
+///
+/// The fields on a cycle, which cannot be sorted, are returned as the error.
+fn sort_computed_fields(mut unsorted: Vec<&Field>) -> Result<Vec<&Field>, Vec<&Field>> {
+    let accesses = |field: &Field| computed_field_accesses(field.field.computed.as_ref().unwrap());
+    let reads = |field: &Field, read: &Field| {
+        accesses(field)
+            .iter()
+            .any(|a| read.ident.as_ref() == Some(a))
+    };
+
+    let mut sorted = Vec::with_capacity(unsorted.len());
+    while !unsorted.is_empty() {
+        let next = unsorted
+            .iter()
+            .position(|&field| !unsorted.iter().any(|&read| reads(field, read)));
+        match next {
+            Some(index) => sorted.push(unsorted.remove(index)),
+            None => break,
+        }
+    }
+
+    if unsorted.is_empty() {
+        return Ok(sorted);
+    }
+
+    // Leave out the fields which only read a cycle without being on one.
+    loop {
+        let next = unsorted
+            .iter()
+            .position(|&field| !unsorted.iter().any(|&other| reads(other, field)));
+        match next {
+            Some(index) => {
+                unsorted.remove(index);
+            }
+            None => return Err(unsorted),
+        }
+    }
+}
+
+/// Record that the setters of `field` generate an item of the given kind named `name`, and
+/// report an error at both fields if an item recorded before has the same name.
+///
+/// Returns whether the name was unique.
+fn check_unique_name<'a>(
+    errors: &mut darling::error::Accumulator,
+    seen: &mut Vec<(Ident, &'static str, &'a Ident)>,
+    name: Ident,
+    kind: &'static str,
+    field: &'a Ident,
+) -> bool {
+    let duplicate = seen
+        .iter()
+        .find(|&(other, _, _)| other.unraw() == name.unraw());
+    let unique = duplicate.is_none();
+    if let Some(&(_, other_kind, other_field)) = duplicate {
+        let message = format!(
+            "the {} of field `{}` and the {} of field `{}` are both named `{}`",
+            other_kind,
+            other_field.unraw(),
+            kind,
+            field.unraw(),
+            name.unraw()
+        );
+        errors.push(Error::custom(&message).with_span(field));
+        if other_field != field {
+            errors.push(Error::custom(&message).with_span(other_field));
+        }
+    }
+    seen.push((name, kind, field));
+    unique
+}
+
+/// Lints allowed on the generated items unless `#[builder(allow_lints(...))]` overrides them.
+///
+/// These are style lints the generated code may trip, depending on the options and the field
+/// types, which the user can do nothing about.
+const DEFAULT_ALLOWED_LINTS: &[&str] = &[
+    "clippy::clone_on_copy",
+    "clippy::let_and_return",
+    "clippy::needless_update",
+    "clippy::new_without_default",
+    "clippy::question_mark",
+    "clippy::redundant_clone",
+    "clippy::redundant_field_names",
+    "clippy::suspicious_else_formatting",
+    "clippy::too_many_arguments",
+    "clippy::type_complexity",
+    "clippy::unit_arg",
+    "clippy::useless_conversion",
+    "clippy::wrong_self_convention",
+];
+
+pub(crate) fn default_allow_lints() -> PathList {
+    PathList::new(
+        DEFAULT_ALLOWED_LINTS
+            .iter()
+            .map(|lint| syn::parse_str::<Path>(lint).expect("default lints are valid paths"))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn default_create_empty() -> Ident {
+    Ident::new("create_empty", Span::call_site())
+}
+
+fn default_builder_method() -> Option<Ident> {
+    Some(Ident::new("builder", Span::call_site()))
+}
+
+/// Create the name of the builder method from an attribute's `Meta`, accepting the
+/// `builder_method` word, which names it `builder`, `builder_method = "..."`, and
+/// `builder_method = false`, which leaves it out.
+fn parse_builder_method(meta: &Meta) -> darling::Result<Option<Ident>> {
+    match meta {
+        Meta::Path(_) => Ok(default_builder_method()),
+        Meta::NameValue(syn::MetaNameValue {
+            lit: syn::Lit::Bool(ref value),
+            ..
+        }) => Ok(if value.value {
+            default_builder_method()
+        } else {
+            None
+        }),
+        _ => Ident::from_meta(meta).map(Some),
+    }
+}
+
+#[derive(Debug, Clone, FromDeriveInput)]
+#[darling(
+    attributes(builder),
+    forward_attrs(cfg, allow, builder_struct_attr, builder_impl_attr),
+    supports(struct_named),
+    and_then = "Self::unnest_attrs"
+)]
+pub struct Options {
+    ident: Ident,
+
+    /// DO NOT USE.
+    ///
+    /// Initial receiver for forwarded attributes from the struct; these are split
+    /// into `Options::struct_attrs` and `Options::impl_attrs` before `FromDeriveInput`
+    /// returns.
+    attrs: Vec<Attribute>,
+
+    #[darling(skip)]
+    struct_attrs: Vec<Attribute>,
+
+    #[darling(skip)]
+    impl_attrs: Vec<Attribute>,
+
+    /// The visibility of the deriving struct. Do not confuse this with `#[builder(vis = "...")]`,
+    /// which is received by `Options::visibility`.
+    vis: syn::Visibility,
+
+    generics: Generics,
+
+    /// The name of the generated builder. Defaults to `#{ident}Builder`.
+    name: Option<Ident>,
+
+    #[darling(default)]
+    pattern: BuilderPattern,
+
+    #[darling(default)]
+    build_fn: BuildFn,
+
+    /// Additional traits to derive on the builder.
+    #[darling(default)]
+    derive: PathList,
+
+    /// Lints allowed on the generated items, replacing `DEFAULT_ALLOWED_LINTS`.
+    #[darling(default = "default_allow_lints")]
+    allow_lints: PathList,
+
+    custom_constructor: Flag,
+
+    /// The ident of the inherent method which takes no arguments and returns
+    /// an instance of the builder with all fields empty.
+    #[darling(default = "default_create_empty")]
+    create_empty: Ident,
+
+    /// The ident of the inherent method on the deriving struct which returns a default builder,
+    /// `builder` unless renamed, or `None` if `builder_method = false` leaves it out.
+    #[darling(default = "default_builder_method", with = "parse_builder_method")]
+    builder_method: Option<Ident>,
+
+    /// Setter options applied to all field setters in the struct.
+    #[darling(default)]
     setter: StructLevelSetter,
 
     /// Struct-level value to use in place of any unfilled fields
     default: Option<DefaultExpression>,
 
+    /// When present, `impl Default` for the builder sets each field from the struct's own
+    /// `Default::default()` value.
+    default_struct: Flag,
+
     public: Flag,
 
     private: Flag,
@@ -551,15 +1577,111 @@ pub struct Options {
     #[darling(rename = "vis")]
     visibility: Option<syn::Visibility>,
 
+    /// When present, setters default to the visibility of the deriving struct rather than `pub`.
+    setters_visibility_from_struct: Flag,
+
     /// The parsed body of the derived struct.
     data: darling::ast::Data<darling::util::Ignored, Field>,
 
-    no_std: Flag,
+    #[darling(with = "parse_no_std")]
+    no_std: Option<NoStd>,
+
+    /// When present, the builder struct and its build method are not marked `#[must_use]`.
+    no_must_use: Flag,
 
     /// When present, emit additional fallible setters alongside each regular
     /// setter.
     try_setter: Flag,
 
+    /// When present, the build method caches its result in the builder and returns
+    /// it until a setter is called again.
+    lazy_build: Flag,
+
+    /// When present, emit a `from_env` constructor reading the fields from environment variables.
+    from_env: Flag,
+
+    /// When present, emit a `from_env` constructor reading the fields from environment variables
+    /// whose prefix is fixed, rather than a parameter.
+    impl_from_env_prefixed: Option<FromEnvPrefixed>,
+
+    /// When present, emit a `diff` method comparing two builders field by field.
+    impl_diff: Flag,
+
+    /// When present, implement `Hash` for the builder.
+    impl_hash: Flag,
+
+    /// When present, emit `into_parts` and `from_parts` methods converting the builder to and
+    /// from a tuple struct of its fields.
+    impl_parts: Flag,
+
+    /// When present, emit a `snapshot` method returning a struct of the builder fields formatted
+    /// as strings.
+    impl_snapshot: Flag,
+
+    /// When present, emit a `try_build_partial` method returning a `PartialFoo` struct, which
+    /// holds every field of the target struct as an `Option`.
+    #[darling(with = "parse_build_partial")]
+    build_partial: Option<BuildPartial>,
+
+    /// When present, implement `serde::Serialize` for the builder, leaving out unset fields.
+    impl_serialize: Flag,
+
+    /// When present, implement `From<Target>` for the builder, setting every field from the
+    /// built struct.
+    into_builder: Flag,
+
+    /// When present, emit a `to_builder` method on the target struct, returning a builder set
+    /// from a clone of it.
+    impl_builder_on_original: Flag,
+
+    /// When present, implement `From` a tuple of the required fields for the target struct,
+    /// building it with the builder.
+    impl_from_parts: Flag,
+
+    /// When present, emit a `FIELD_NAMES` constant on the builder listing the struct's fields.
+    field_names: Flag,
+
+    /// When present, emit a `with_base` method filling the unset fields of the builder from
+    /// another builder.
+    struct_update: Flag,
+
+    /// When present, implement `BuilderMethods` for the builder and emit the macro forwarding
+    /// its setters, so that other builders can inherit it.
+    inheritable: Flag,
+
+    /// A builder whose setters are forwarded to the field of the type it builds.
+    inherits: Option<Path>,
+
+    /// The field holding the inherited builder, found by `Options::unnest_attrs`.
+    #[darling(skip)]
+    inherits_field: Option<Ident>,
+
+    /// When present, implement `Display` for the builder, showing which fields are set.
+    #[darling(with = "parse_impl_display")]
+    impl_display: Option<ImplDisplay>,
+
+    /// When present, emit a method per field telling whether the field is set.
+    #[darling(with = "parse_impl_has_methods")]
+    impl_has_methods: Option<ImplHasMethods>,
+
+    /// When present, emit a `FooField` enum and implement `Extend<FooField>` for the builder,
+    /// calling the setter of each field value.
+    #[darling(with = "parse_impl_extend")]
+    impl_extend: Option<ImplExtend>,
+
+    /// When present, emit a `FooPatch` enum with one variant per setter and an `apply` method
+    /// on the builder calling the setter of each patch.
+    patch: Flag,
+
+    /// File to write the generated code to, relative to the directory of the crate's manifest,
+    /// if the `DERIVE_BUILDER_DEBUG` environment variable is set during the build.
+    code_gen_debug: Option<syn::LitStr>,
+
+    /// Options applied to the fields of a type unless the field sets them itself; later rules
+    /// take precedence over earlier ones.
+    #[darling(multiple, rename = "on")]
+    type_rules: Vec<TypeRule>,
+
     #[darling(default)]
     field: StructLevelFieldMeta,
 
@@ -604,8 +1726,711 @@ impl Options {
             .map_struct_fields(|f| errors.handle(f.no_visibility_conflicts()));
         errors.handle(no_visibility_conflict(&self));
 
+        if self.from_env.is_present() && self.no_std.is_some() {
+            errors.push(
+                Error::custom("`from_env` cannot be used with `no_std`").with_span(&self.from_env),
+            );
+        }
+
+        if let Some(ref prefixed) = self.impl_from_env_prefixed {
+            if self.from_env.is_present() {
+                errors.push(
+                    Error::custom("`impl_from_env_prefixed` cannot be used with `from_env`")
+                        .with_span(&prefixed.env_prefix),
+                );
+            }
+
+            if self.no_std.is_some() {
+                errors.push(
+                    Error::custom("`impl_from_env_prefixed` cannot be used with `no_std`")
+                        .with_span(&prefixed.env_prefix),
+                );
+            }
+        }
+
+        self.data.as_ref().map_struct_fields(|f| {
+            let pattern = f.pattern.unwrap_or(self.pattern);
+            if f.setter.return_previous.is_present() && pattern != BuilderPattern::Mutable {
+                errors.push(
+                    Error::custom(
+                        r#"#[builder(setter(return_previous))] requires `pattern = "mutable"`"#,
+                    )
+                    .with_span(&f.setter.return_previous),
+                );
+            }
+        });
+
+        if self.field.default.is_present() {
+            let conflict = |option: &str| {
+                Error::custom(format!(
+                    "#[builder(field(default))] and {} cannot be used together",
+                    option
+                ))
+            };
+
+            if let Some(ref default) = self.default {
+                errors.push(conflict("#[builder(default)]").with_span(default));
+            }
+
+            if let Some(ref validate) = self.build_fn.validate {
+                errors.push(
+                    conflict(r#"#[builder(build_fn(validate = "..."))]"#).with_span(validate),
+                );
+            }
+
+            if let Some(ref result_type) = self.build_fn.result_type {
+                errors.push(
+                    conflict(r#"#[builder(build_fn(result_type = "..."))]"#).with_span(result_type),
+                );
+            }
+
+            if self.lazy_build.is_present() {
+                errors.push(conflict("#[builder(lazy_build)]").with_span(&self.lazy_build));
+            }
+
+            self.data.as_ref().map_struct_fields(|f| {
+                if let Some(ref default) = f.default {
+                    errors.push(conflict("#[builder(default)] on a field").with_span(default));
+                }
+
+                if let (Some(_), None) = (f.setter.once, &f.field.builder_type) {
+                    errors.push(conflict("#[builder(setter(once))]").with_span(&f.ty));
+                }
+
+                if let Some(assertion) = f.field.assert.first() {
+                    errors.push(
+                        conflict(r#"#[builder(field(assert = "..."))]"#).with_span(assertion),
+                    );
+                }
+            });
+        }
+
+        if self.build_fn.unchecked {
+            self.data.as_ref().map_struct_fields(|f| {
+                if f.try_default.is_present() {
+                    errors.push(
+                        Error::custom("#[builder(try)] cannot be used with `build_fn(unchecked)`")
+                            .with_span(&f.try_default),
+                    );
+                }
+            });
+        }
+
+        if self.build_fn.expect {
+            self.data.as_ref().map_struct_fields(|f| {
+                if f.try_default.is_present() {
+                    errors.push(
+                        Error::custom("#[builder(try)] cannot be used with `build_fn(expect)`")
+                            .with_span(&f.try_default),
+                    );
+                }
+            });
+        }
+
+        // A `try_` setter has no room for the `AlreadySetError` of a `setter(once)` field next
+        // to the conversion error, but `setter(once(panic))` leaves its return type alone.
+        self.data.as_ref().map_struct_fields(|f| {
+            if f.setter.once == Some(SetOnce::Error)
+                && f.setter.transform.is_none()
+                && (f.try_setter.is_present() || self.try_setter.is_present())
+            {
+                errors.push(
+                    Error::custom(
+                        "#[builder(setter(once))] cannot be used with `try_setter`, use `setter(once(panic))` instead",
+                    )
+                    .with_span(&f.ty),
+                );
+            }
+        });
+
+        errors.handle(self.resolve_self_in_field_types());
+
+        if let Some(inherits) = self.inherits.clone() {
+            errors.handle(self.inherit_field(inherits));
+        }
+
+        if self.lazy_build.is_present() {
+            if self.pattern == BuilderPattern::Owned {
+                errors.push(
+                    Error::custom(r#"`lazy_build` cannot be used with `pattern = "owned"`"#)
+                        .with_span(&self.lazy_build),
+                );
+            }
+
+            if !self.alloc() {
+                errors.push(
+                    Error::custom("`lazy_build` cannot be used with `no_std(static_str_errors)`")
+                        .with_span(&self.lazy_build),
+                );
+            }
+
+            if self.build_fn.receiver.is_some() {
+                errors.push(
+                    Error::custom("`lazy_build` cannot be used with `build_fn(receiver)`")
+                        .with_span(&self.lazy_build),
+                );
+            }
+
+            if self.build_fn.into.is_some() {
+                errors.push(
+                    Error::custom("`lazy_build` cannot be used with `build_fn(into)`")
+                        .with_span(&self.lazy_build),
+                );
+            }
+        }
+
+        if self.impl_snapshot.is_present() && !self.alloc() {
+            errors.push(
+                Error::custom("`impl_snapshot` cannot be used with `no_std(static_str_errors)`")
+                    .with_span(&self.impl_snapshot),
+            );
+        }
+
+        if let (Some(result_type), Some(BuildFnInto::Inferred)) =
+            (&self.build_fn.result_type, &self.build_fn.into)
+        {
+            errors.push(
+                Error::custom(
+                    r#"`build_fn(result_type = "...")` requires the type of `into = "..."`"#,
+                )
+                .with_span(result_type),
+            );
+        }
+
+        if self.field.default.is_present() {
+            for field in self.raw_fields() {
+                if field.required.is_present() {
+                    errors.push(
+                        Error::custom(
+                            "#[builder(required)] cannot be used with the struct-level #[builder(field(default))]",
+                        )
+                        .with_span(&field.required),
+                    );
+                }
+            }
+        }
+
+        if self.impl_from_parts.is_present() {
+            errors.handle(self.check_from_parts());
+        }
+
+        errors.handle(self.check_lazy_setters());
+
+        if self.build_partial.is_some() {
+            errors.handle(self.check_build_partial());
+        }
+
+        if self.default_struct.is_present() {
+            errors.handle(self.check_default_struct());
+        }
+
+        if self.build_fn.into_target {
+            errors.handle(self.check_build_into());
+        }
+
+        if self.impl_extend.is_some() || self.patch.is_present() {
+            errors.handle(self.check_variant_idents());
+        }
+
+        errors.handle(self.check_computed_fields());
+
+        errors.handle(self.check_setter_names());
+
         errors.finish_with(self)
     }
+
+    /// Check that computed fields only read fields of the struct, and are not computed from
+    /// themselves.
+    fn check_computed_fields(&self) -> darling::Result<()> {
+        let mut errors = Error::accumulator();
+        let fields = self.raw_fields();
+        let computed: Vec<&Field> = fields
+            .iter()
+            .cloned()
+            .filter(|f| f.field.computed.is_some())
+            .collect();
+
+        for field in &computed {
+            let expr = field.field.computed.as_ref().unwrap();
+            for accessed in computed_field_accesses(expr) {
+                if !fields.iter().any(|f| f.ident.as_ref() == Some(&accessed)) {
+                    errors.push(
+                        Error::custom(format!(
+                            "#[builder(field(computed = \"...\"))] reads unknown field `{}`",
+                            accessed
+                        ))
+                        .with_span(expr),
+                    );
+                }
+            }
+        }
+
+        if let Err(cycle) = sort_computed_fields(computed) {
+            for field in cycle {
+                errors.push(
+                    Error::custom(format!(
+                        "`{}` is computed from itself, directly or through other computed fields",
+                        field.ident.as_ref().unwrap().unraw()
+                    ))
+                    .with_span(field.field.computed.as_ref().unwrap()),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that no two methods or type aliases generated for the setters of the fields share
+    /// a name, which would otherwise fail to compile with a confusing error.
+    fn check_setter_names(&self) -> darling::Result<()> {
+        let mut errors = Error::accumulator();
+        let mut methods = Vec::new();
+        let mut aliases = Vec::new();
+
+        for field in self.fields().filter(|field| field.setter_enabled()) {
+            let field_ident = field.field_ident();
+            let setter = field.setter_ident();
+            // The `try_` setter of a duplicate setter would only repeat the error.
+            let unique = check_unique_name(
+                &mut errors,
+                &mut methods,
+                setter.clone(),
+                "setter",
+                field_ident,
+            );
+            if unique
+                && field.try_setter()
+                && field.setter_once() != Some(SetOnce::Error)
+                && field.field.setter.validate.is_none()
+                && !field.field.setter.try_into.is_present()
+            {
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    format_ident!("try_{}", setter),
+                    "`try_` setter",
+                    field_ident,
+                );
+            }
+            if let Some(bare) = field.setter_bare_ident() {
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    bare,
+                    "unprefixed setter",
+                    field_ident,
+                );
+            }
+            if let Some(option_setter) = field.setter_option_ident() {
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    option_setter,
+                    "`maybe_` setter",
+                    field_ident,
+                );
+            }
+            if let Some((with_mut, _)) = field.setter_with_mut() {
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    with_mut,
+                    "`with_` method",
+                    field_ident,
+                );
+            }
+            if let Some((try_add, saturating_add)) = field.setter_add_idents() {
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    try_add,
+                    "`try_add_` method",
+                    field_ident,
+                );
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    saturating_add,
+                    "`saturating_add_` method",
+                    field_ident,
+                );
+            }
+            if let Some(ref each) = field.field.setter.each {
+                if let Some(ref name) = each.name {
+                    check_unique_name(
+                        &mut errors,
+                        &mut methods,
+                        name.clone(),
+                        "`each` setter",
+                        field_ident,
+                    );
+                }
+                if let Some(ref remove) = each.remove {
+                    check_unique_name(
+                        &mut errors,
+                        &mut methods,
+                        remove.clone(),
+                        "`each(remove)` method",
+                        field_ident,
+                    );
+                }
+            }
+            if let Some((alias, _)) = field.setter_type_alias() {
+                check_unique_name(
+                    &mut errors,
+                    &mut aliases,
+                    alias.clone(),
+                    "setter type alias",
+                    field_ident,
+                );
+            }
+        }
+
+        // Fields with custom setters are stored in the builder as well.
+        for field in self.fields() {
+            if let Some(has) = field.has_method_ident() {
+                check_unique_name(
+                    &mut errors,
+                    &mut methods,
+                    has,
+                    "`has_` method",
+                    field.field_ident(),
+                );
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that `#[builder(impl_from_parts)]` can pass every required field to its setter
+    /// and build the target struct.
+    fn check_from_parts(&self) -> darling::Result<()> {
+        let span = self.impl_from_parts.span();
+        if self.build_fn.skip {
+            return Err(Error::custom(
+                "#[builder(impl_from_parts)] cannot be used with `build_fn(skip)`",
+            )
+            .with_span(&span));
+        }
+
+        if self.lazy_build.is_present() {
+            return Err(Error::custom(
+                "#[builder(impl_from_parts)] cannot be used with `lazy_build`",
+            )
+            .with_span(&span));
+        }
+
+        if self.build_fn.into.is_some() {
+            return Err(Error::custom(
+                "#[builder(impl_from_parts)] cannot be used with `build_fn(into)`",
+            )
+            .with_span(&span));
+        }
+
+        if self.build_fn.result_type.is_some() {
+            return Err(Error::custom(
+                "#[builder(impl_from_parts)] cannot be used with `build_fn(result_type)`",
+            )
+            .with_span(&span));
+        }
+
+        let mut errors = Error::accumulator();
+        let mut required = 0;
+        for field in self.fields().filter(|field| field.is_required()) {
+            required += 1;
+            if field.as_setter().extend_value_type().is_none() {
+                errors.push(
+                    Error::custom(
+                        "#[builder(impl_from_parts)] requires an infallible setter for every field without a default",
+                    )
+                    .with_span(field.field_ident()),
+                );
+            }
+        }
+
+        if required > MAX_FROM_PARTS {
+            errors.push(
+                Error::custom(format!(
+                    "#[builder(impl_from_parts)] supports at most {} fields without a default, found {}",
+                    MAX_FROM_PARTS, required
+                ))
+                .with_span(&span),
+            );
+        }
+
+        errors.finish()
+    }
+
+    /// Check that every builder field of a `build_partial` builder holds an `Option`, which tells
+    /// whether the field is set.
+    fn check_build_partial(&self) -> darling::Result<()> {
+        let conflict =
+            |option: &str| Error::custom(format!("`build_partial` cannot be used with {}", option));
+
+        if self.field.default.is_present() {
+            return Err(conflict("#[builder(field(default))]").with_span(&self.field.default));
+        }
+
+        let mut errors = Error::accumulator();
+        for field in self.raw_fields() {
+            if let Some(ref ty) = field.field.builder_type {
+                errors.push(conflict(r#"#[builder(field(type = "..."))]"#).with_span(ty));
+            } else if let Some(ref lazy) = field.setter.lazy {
+                errors.push(conflict(r#"#[builder(setter(lazy = "..."))]"#).with_span(lazy));
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that the fields with setters map to distinct variants of the enums emitted by
+    /// `impl_extend` and `patch`.
+    fn check_variant_idents(&self) -> darling::Result<()> {
+        let mut errors = Error::accumulator();
+        let mut variants: Vec<(Ident, &Ident)> = Vec::new();
+        for field in self.fields().filter(|field| field.setter_enabled()) {
+            let field_ident = field.field_ident();
+            let variant = variant_ident(field_ident);
+            match variants.iter().find(|other| other.0 == variant) {
+                Some(other) => errors.push(
+                    Error::custom(format!(
+                        "`{}` and `{}` both map to the enum variant `{}`; rename one of them",
+                        other.1, field_ident, variant
+                    ))
+                    .with_span(field_ident),
+                ),
+                None => variants.push((variant, field_ident)),
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that every field of a `build_fn(into_target)` builder is cloned from the builder
+    /// as is, rather than converted or computed in the build method.
+    fn check_build_into(&self) -> darling::Result<()> {
+        let conflict = |option: &str| {
+            Error::custom(format!(
+                "`build_fn(into_target)` cannot be used with {}",
+                option
+            ))
+        };
+
+        let mut errors = Error::accumulator();
+        for field in self.fields() {
+            let raw = field.field;
+            if let Some(ref ty) = raw.field.builder_type {
+                errors.push(conflict(r#"#[builder(field(type = "..."))]"#).with_span(ty));
+            } else if let Some(ref build) = raw.field.build {
+                errors.push(conflict(r#"#[builder(field(build = "..."))]"#).with_span(build));
+            } else if let Some(ref computed) = raw.field.computed {
+                errors.push(conflict(r#"#[builder(field(computed = "..."))]"#).with_span(computed));
+            } else if let Some(map) = field.field_map() {
+                errors.push(conflict("a mapped field").with_span(map));
+            } else if let Some(assertion) = raw.field.assert.first() {
+                errors.push(conflict(r#"#[builder(field(assert = "..."))]"#).with_span(assertion));
+            } else if let Some(ref lazy) = raw.setter.lazy {
+                errors.push(conflict(r#"#[builder(setter(lazy = "..."))]"#).with_span(lazy));
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Check that a `default_struct` builder has an `impl Default` to fill, with builder fields
+    /// holding an `Option` of the struct's fields.
+    fn check_default_struct(&self) -> darling::Result<()> {
+        let conflict = |option: &str| {
+            Error::custom(format!("`default_struct` cannot be used with {}", option))
+        };
+
+        let mut errors = Error::accumulator();
+        if self.custom_constructor.is_present() {
+            errors.push(conflict("`custom_constructor`").with_span(&self.custom_constructor));
+        }
+
+        if self.field.default.is_present() {
+            errors.push(conflict("#[builder(field(default))]").with_span(&self.field.default));
+        }
+
+        errors.finish()
+    }
+
+    /// Check that the builder can hold the closures of `setter(lazy)` fields, which are neither
+    /// `Clone` nor `Hash`, and that its build method takes it by value to call them.
+    fn check_lazy_setters(&self) -> darling::Result<()> {
+        let conflict = |option: &str| {
+            Error::custom(format!(
+                r#"#[builder(setter(lazy = "..."))] cannot be used with {}"#,
+                option
+            ))
+        };
+
+        let fields = self.raw_fields();
+        let lazies = fields
+            .iter()
+            .filter_map(|field| field.setter.lazy.as_ref())
+            .collect::<Vec<_>>();
+        if lazies.is_empty() {
+            return Ok(());
+        }
+
+        let mut errors = Error::accumulator();
+
+        // The closures of lazy fields can be neither cloned nor compared.
+        for path in self.derive.iter() {
+            if let Some(segment) = path.segments.last() {
+                if segment.ident == "Clone" || segment.ident == "PartialEq" || segment.ident == "Eq"
+                {
+                    errors.push(conflict(&format!("`derive({})`", segment.ident)).with_span(path));
+                }
+            }
+        }
+
+        for lazy in lazies {
+            if self.requires_clone() {
+                errors.push(
+                    Error::custom(
+                        r#"#[builder(setter(lazy = "..."))] requires `pattern = "owned"`"#,
+                    )
+                    .with_span(lazy),
+                );
+            } else if let Some(BuildFnReceiver::Ref) | Some(BuildFnReceiver::RefMut) =
+                self.build_fn.receiver
+            {
+                errors.push(
+                    conflict(r#"`build_fn(receiver = "...")` other than `"self"`"#).with_span(lazy),
+                );
+            }
+
+            if self.field.default.is_present() {
+                errors.push(conflict("`field(default)`").with_span(lazy));
+            }
+
+            if self.impl_hash.is_present() {
+                errors.push(conflict("`impl_hash`").with_span(lazy));
+            }
+
+            if self.from_env.is_present() {
+                errors.push(conflict("`from_env`").with_span(lazy));
+            }
+
+            if self.impl_from_env_prefixed.is_some() {
+                errors.push(conflict("`impl_from_env_prefixed`").with_span(lazy));
+            }
+
+            if !self.alloc() {
+                errors.push(conflict("`no_std(static_str_errors)`").with_span(lazy));
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Replace `Self` in the types of the fields with the deriving struct, which it stands for in
+    /// the struct but not in the generated code, where it would name the builder.
+    ///
+    /// Types which do not mention `Self` keep their tokens unchanged.
+    fn resolve_self_in_field_types(&mut self) -> darling::Result<()> {
+        let ident = &self.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let target = quote!(#ident #ty_generics);
+        let fields = match self.data {
+            darling::ast::Data::Struct(ref mut fields) => &mut fields.fields,
+            darling::ast::Data::Enum(_) => unreachable!("Only structs supported"),
+        };
+
+        let mut errors = Error::accumulator();
+        for field in fields {
+            let ty = &field.ty;
+            let mut found = false;
+            let tokens = replace_self(quote!(#ty), &target, &mut found);
+            if found {
+                if let Some(ty) = errors.handle(syn::parse2(tokens).map_err(Error::from)) {
+                    field.ty = ty;
+                }
+            }
+        }
+
+        errors.finish()
+    }
+
+    /// Turn the field whose type `inherits` builds into a field holding that builder, as if it
+    /// was declared with `#[builder(field(type = "...", build = "..."))]`.
+    fn inherit_field(&mut self, inherits: Path) -> darling::Result<()> {
+        let builder_ident = match inherits.segments.last() {
+            Some(segment) => segment.ident.clone(),
+            None => return Err(Error::custom("expected a builder name").with_span(&inherits)),
+        };
+        let builder_name = builder_ident.unraw().to_string();
+        let target_name =
+            if builder_name.len() > "Builder".len() && builder_name.ends_with("Builder") {
+                &builder_name[..builder_name.len() - "Builder".len()]
+            } else {
+                return Err(Error::custom(
+                r#"#[builder(inherits = "...")] expects the name of a builder ending in `Builder`"#,
+            )
+            .with_span(&inherits));
+            };
+
+        if builder_ident == self.builder_ident() {
+            return Err(Error::custom("a builder cannot inherit from itself").with_span(&inherits));
+        }
+
+        if self.lazy_build.is_present() {
+            return Err(Error::custom(
+                r#"#[builder(inherits = "...")] cannot be used with `lazy_build`"#,
+            )
+            .with_span(&inherits));
+        }
+
+        let fields = match self.data {
+            darling::ast::Data::Struct(ref mut fields) => &mut fields.fields,
+            darling::ast::Data::Enum(_) => unreachable!("Only structs supported"),
+        };
+        let field = fields.iter_mut().find(|f| match f.ty {
+            syn::Type::Path(ref ty) => ty
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident == target_name)
+                .unwrap_or(false),
+            _ => false,
+        });
+        let field = match field {
+            Some(field) => field,
+            None => {
+                return Err(Error::custom(format!(
+                    "no field of type `{}` to inherit the setters of `{}` from",
+                    target_name, builder_name
+                ))
+                .with_span(&inherits))
+            }
+        };
+
+        if field.field.builder_type.is_some() || field.field.build.is_some() {
+            return Err(Error::custom(
+                r#"#[builder(inherits = "...")] cannot be used with #[builder(field(type = "..."))] on the inherited field"#,
+            )
+            .with_span(&field.ty));
+        }
+
+        let field_ident = field
+            .ident
+            .clone()
+            .expect("Tuple structs are not supported");
+        let build: syn::Expr = parse_quote!({
+            let built: <#inherits as ::derive_builder::BuilderMethods>::Target = self
+                .#field_ident
+                .build()
+                .map_err(|e| ::derive_builder::export::core::string::ToString::to_string(&e))?;
+            built
+        });
+        field.field.builder_type = Some(parse_quote!(#inherits));
+        field.field.build = Some(build.into());
+        self.inherits_field = Some(field_ident);
+        Ok(())
+    }
 }
 
 /// Accessors for parsed properties.
@@ -619,7 +2444,7 @@ impl Options {
     }
 
     pub fn builder_error_ident(&self) -> Path {
-        if let Some(existing) = self.build_fn.error.as_ref() {
+        if let Some(existing) = self.existing_error() {
             existing.clone()
         } else if let Some(ref custom) = self.name {
             format_ident!("{}Error", custom).into()
@@ -628,16 +2453,71 @@ impl Options {
         }
     }
 
+    /// The names of the type parameters of generic setters, `VALUE` and `FROM_VALUE` unless the
+    /// struct declares a generic parameter with either name.
+    pub fn setter_value_params(&self) -> (Ident, Ident) {
+        let collides = self.generics.params.iter().any(|param| {
+            let ident = match *param {
+                syn::GenericParam::Type(ref ty) => &ty.ident,
+                syn::GenericParam::Const(ref konst) => &konst.ident,
+                syn::GenericParam::Lifetime(_) => return false,
+            };
+            ident == "VALUE" || ident == "FROM_VALUE"
+        });
+
+        if collides {
+            (
+                Ident::new("__BuilderValue", Span::call_site()),
+                Ident::new("__BuilderFromValue", Span::call_site()),
+            )
+        } else {
+            (
+                Ident::new("VALUE", Span::call_site()),
+                Ident::new("FROM_VALUE", Span::call_site()),
+            )
+        }
+    }
+
+    /// The existing error type the build method returns, if one was given.
+    pub fn existing_error(&self) -> Option<&Path> {
+        match self.build_fn.error {
+            Some(BuildFnError::Existing(ref path)) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Whether the generated code may allocate, which `no_std(static_str_errors)` rules out.
+    pub fn alloc(&self) -> bool {
+        match self.no_std {
+            Some(ref no_std) => !no_std.static_str_errors.is_present(),
+            None => true,
+        }
+    }
+
+    /// Whether the build method reports every uninitialized field at once.
+    ///
+    /// This needs the generated error type, which can only hold a list of fields if an
+    /// allocator is available.
+    pub fn report_all_missing(&self) -> bool {
+        match self.build_fn.error {
+            None => self.alloc(),
+            Some(BuildFnError::Generated(ref generated)) => {
+                self.alloc() && !generated.first_only.is_present()
+            }
+            Some(BuildFnError::Existing(_)) => false,
+        }
+    }
+
     /// The visibility of the builder struct.
     /// If a visibility was declared in attributes, that will be used;
     /// otherwise the struct's own visibility will be used.
-    pub fn builder_vis(&self) -> Cow<syn::Visibility> {
+    pub fn builder_vis(&self) -> Cow<'_, syn::Visibility> {
         self.as_expressed_vis().unwrap_or(Cow::Borrowed(&self.vis))
     }
 
     /// Get the visibility of the emitted `build` method.
     /// This defaults to the visibility of the parent builder, but can be overridden.
-    pub fn build_method_vis(&self) -> Cow<syn::Visibility> {
+    pub fn build_method_vis(&self) -> Cow<'_, syn::Visibility> {
         self.build_fn
             .as_expressed_vis()
             .unwrap_or_else(|| self.builder_vis())
@@ -657,56 +2537,460 @@ impl Options {
         self.pattern.requires_clone() || self.fields().any(|f| f.pattern().requires_clone())
     }
 
-    /// Get an iterator over the input struct's fields which pulls fallback
-    /// values from struct-level settings.
-    pub fn fields(&self) -> FieldIter {
-        FieldIter(self, self.raw_fields().into_iter())
+    /// Get an iterator over the input struct's fields which pulls fallback
+    /// values from struct-level settings.
+    pub fn fields(&self) -> FieldIter<'_> {
+        FieldIter(self, self.raw_fields().into_iter())
+    }
+
+    /// Get an iterator over the fields in the order the build method initializes them: fields
+    /// computed from other fields come last, each after the computed fields it reads.
+    pub fn fields_in_build_order(&self) -> FieldIter<'_> {
+        let (computed, mut fields): (Vec<_>, Vec<_>) = self
+            .raw_fields()
+            .into_iter()
+            .partition(|f| f.field.computed.is_some());
+        // A cycle is reported by `check_computed_fields`.
+        fields.extend(sort_computed_fields(computed).unwrap_or_else(|cycle| cycle));
+        FieldIter(self, fields.into_iter())
+    }
+
+    pub fn field_count(&self) -> usize {
+        self.raw_fields().len()
+    }
+
+    /// The file to write the generated code to, see `#[builder(code_gen_debug = "...")]`.
+    pub fn code_gen_debug(&self) -> Option<&syn::LitStr> {
+        self.code_gen_debug.as_ref()
+    }
+
+    /// Whether the builder gets a `FIELD_NAMES` constant.
+    pub fn has_field_names(&self) -> bool {
+        self.field_names.is_present()
+    }
+
+    /// Whether some field is marked `#[builder(field(sensitive))]`.
+    fn has_sensitive_fields(&self) -> bool {
+        self.raw_fields()
+            .iter()
+            .any(|field| field.field.sensitive.is_present())
+    }
+
+    /// Whether `Debug` is implemented for the builder by `BuilderDebug`, masking the sensitive
+    /// fields, rather than derived.
+    fn masks_debug(&self) -> bool {
+        self.has_sensitive_fields() && self.derive.iter().any(is_debug_path)
+    }
+
+    /// Warnings about options which compile, but likely do not do what the user wants.
+    fn deprecation_notes(&self) -> DeprecationNotes {
+        let mut notes = DeprecationNotes::default();
+        let derived_debug = self
+            .struct_attrs
+            .iter()
+            .find_map(|attr| match attr.parse_meta() {
+                Ok(Meta::List(ref list)) if list.path.is_ident("derive") => {
+                    list.nested.iter().find_map(|nested| match *nested {
+                        syn::NestedMeta::Meta(Meta::Path(ref path)) if is_debug_path(path) => {
+                            Some(path.span())
+                        }
+                        _ => None,
+                    })
+                }
+                _ => None,
+            });
+        if let Some(span) = derived_debug.filter(|_| self.has_sensitive_fields()) {
+            notes.push_spanned(
+                "`#[builder_struct_attr(derive(Debug))]` prints the fields marked \
+                 `#[builder(field(sensitive))]`; use `#[builder(derive(Debug))]` to mask them"
+                    .to_string(),
+                span,
+            );
+        }
+        notes
+    }
+
+    /// The builder fields the `with_base` method fills from the base builder, if enabled: those
+    /// holding an `Option`, which tells whether they are set.
+    pub fn struct_update_fields(&self) -> Option<Vec<&Ident>> {
+        if !self.struct_update.is_present() {
+            return None;
+        }
+
+        Some(
+            self.fields()
+                .filter(|field| match field.field_type() {
+                    BuilderFieldType::Optional(_) | BuilderFieldType::Lazy(_) => true,
+                    BuilderFieldType::Precise(_) | BuilderFieldType::Phantom(_) => false,
+                })
+                .map(|field| field.field_ident())
+                .collect(),
+        )
+    }
+
+    /// Whether the builder struct wraps a single field, not counting the `PhantomData` of
+    /// fields without setters, so it can be `#[repr(transparent)]`. A `repr` of the user's own
+    /// on the builder struct takes precedence.
+    fn builder_is_transparent(&self) -> bool {
+        let has_repr = self
+            .struct_attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("repr"));
+        !has_repr
+            && !self.lazy_build.is_present()
+            && self.fields().filter(|field| field.field_enabled()).count() == 1
     }
 
-    pub fn field_count(&self) -> usize {
-        self.raw_fields().len()
+    /// The type of the builder's cached build result, if `lazy_build` is enabled.
+    pub fn build_cache_type(&self) -> Option<syn::Type> {
+        if !self.lazy_build.is_present() {
+            return None;
+        }
+
+        let ident = &self.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        Some(syn::parse_quote!(::derive_builder::export::core::sync::Arc<#ident #ty_generics>))
     }
 }
 
 /// Converters to codegen structs
 impl Options {
-    pub fn as_builder(&self) -> Builder {
+    pub fn as_builder(&self) -> Builder<'_> {
         Builder {
             enabled: true,
             ident: self.builder_ident(),
             pattern: self.pattern,
-            derives: &self.derive,
+            derives: self
+                .derive
+                .iter()
+                .filter(|path| !self.masks_debug() || !is_debug_path(path))
+                .collect(),
             struct_attrs: &self.struct_attrs,
             impl_attrs: &self.impl_attrs,
             impl_default: !self.custom_constructor.is_present(),
+            default_struct_initializers: Vec::new(),
             create_empty: self.create_empty.clone(),
+            target_ident: &self.ident,
+            builder_method: self.builder_method.as_ref(),
+            target_visibility: &self.vis,
             generics: Some(&self.generics),
             visibility: self.builder_vis(),
             fields: Vec::with_capacity(self.field_count()),
             field_initializers: Vec::with_capacity(self.field_count()),
             functions: Vec::with_capacity(self.field_count()),
-            generate_error: self.build_fn.error.is_none(),
+            type_aliases: Vec::new(),
+            generate_error: self.existing_error().is_none(),
             must_derive_clone: self.requires_clone(),
             doc_comment: None,
-            deprecation_notes: Default::default(),
-            std: !self.no_std.is_present(),
+            deprecation_notes: self.deprecation_notes(),
+            std: self.no_std.is_none(),
+            alloc: self.alloc(),
+            must_use: !self.no_must_use.is_present(),
+            transparent: self.builder_is_transparent(),
+            allowed_lints: &self.allow_lints,
         }
     }
 
-    pub fn as_build_method(&self) -> BuildMethod {
+    pub fn as_build_method(&self) -> BuildMethod<'_> {
         let (_, ty_generics, _) = self.generics.split_for_impl();
         BuildMethod {
             enabled: !self.build_fn.skip,
             ident: &self.build_fn.name,
             visibility: self.build_method_vis(),
             pattern: self.pattern,
+            receiver: self.build_fn.receiver,
             target_ty: &self.ident,
             target_ty_generics: Some(ty_generics),
             error_ty: self.builder_error_ident(),
             initializers: Vec::with_capacity(self.field_count()),
+            bind_fields: self.raw_fields().iter().any(|f| f.field.computed.is_some()),
+            bound_initializers: Vec::new(),
+            unchecked: self.build_fn.unchecked,
+            unchecked_initializers: Vec::new(),
+            expect: self.build_fn.expect,
+            expect_initializers: Vec::new(),
             doc_comment: None,
             default_struct: self.default.as_ref(),
+            default_struct_update: false,
+            validate_fn: self.build_fn.validate.as_ref(),
+            must_use: !self.no_must_use.is_present(),
+            bounds: Vec::new(),
+            lazy: self.lazy_build.is_present(),
+            report_all_missing: self.report_all_missing(),
+            required_fields: Vec::new(),
+            infallible: self.field.default.is_present(),
+            into: self.build_fn.into.as_ref(),
+            result_type: self.build_fn.result_type.as_ref(),
+        }
+    }
+
+    pub fn as_builder_diff(&self) -> BuilderDiff<'_> {
+        BuilderDiff {
+            enabled: self.impl_diff.is_present(),
+            ident: format_ident!("{}Diff", self.builder_ident()),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            visibility: self.builder_vis(),
+            field_idents: Vec::with_capacity(self.field_count()),
+            comparisons: Vec::with_capacity(self.field_count()),
+            bounds: Vec::new(),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_debug(&self) -> BuilderDebug<'_> {
+        BuilderDebug {
+            enabled: self.masks_debug(),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            fields: Vec::with_capacity(self.field_count()),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_hash(&self) -> BuilderHash<'_> {
+        BuilderHash {
+            enabled: self.impl_hash.is_present(),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            field_idents: Vec::with_capacity(self.field_count()),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_serialize(&self) -> BuilderSerialize<'_> {
+        BuilderSerialize {
+            enabled: self.impl_serialize.is_present(),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            lens: Vec::with_capacity(self.field_count()),
+            fields: Vec::with_capacity(self.field_count()),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_display(&self) -> BuilderDisplay<'_> {
+        BuilderDisplay {
+            enabled: self.impl_display.is_some(),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            show_values: self
+                .impl_display
+                .as_ref()
+                .map(|display| display.show_values)
+                .unwrap_or(false),
+            fields: Vec::with_capacity(self.field_count()),
+            writes_values: false,
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_extend(&self) -> BuilderExtend<'_> {
+        BuilderExtend {
+            enabled: self.impl_extend.is_some(),
+            ident: format_ident!("{}Field", self.ident),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            pattern: self.pattern,
+            visibility: self.builder_vis(),
+            create_empty: &self.create_empty,
+            parse: self
+                .impl_extend
+                .as_ref()
+                .map(|extend| extend.parse)
+                .unwrap_or(false),
+            variants: Vec::with_capacity(self.field_count()),
+            arms: Vec::with_capacity(self.field_count()),
+            parse_arms: Vec::with_capacity(self.field_count()),
+            value_types: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_patch(&self) -> BuilderPatch<'_> {
+        BuilderPatch {
+            enabled: self.patch.is_present(),
+            ident: format_ident!("{}Patch", self.ident),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            pattern: self.pattern,
+            visibility: self.builder_vis(),
+            create_empty: &self.create_empty,
+            variants: Vec::with_capacity(self.field_count()),
+            arms: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_parts(&self) -> BuilderParts<'_> {
+        BuilderParts {
+            enabled: self.impl_parts.is_present(),
+            ident: format_ident!("{}Parts", self.builder_ident()),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            visibility: self.builder_vis(),
+            create_empty: &self.create_empty,
+            has_other_fields: self.lazy_build.is_present(),
+            field_idents: Vec::with_capacity(self.field_count()),
+            field_types: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_partial(&self) -> BuilderPartial<'_> {
+        BuilderPartial {
+            enabled: self.build_partial.is_some(),
+            ident: format_ident!("Partial{}", self.ident),
+            target_ident: &self.ident,
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            visibility: self.builder_vis(),
+            method_visibility: self.build_method_vis(),
+            pattern: self.pattern,
+            error_ty: self.builder_error_ident(),
+            report_all_missing: self.report_all_missing(),
+            derives: self
+                .build_partial
+                .as_ref()
+                .map(|partial| &partial.derive[..])
+                .unwrap_or(&[]),
+            field_idents: Vec::with_capacity(self.field_count()),
+            field_types: Vec::with_capacity(self.field_count()),
+            values: Vec::with_capacity(self.field_count()),
+            required_fields: Vec::new(),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_build_into(&self) -> BuildInto<'_> {
+        BuildInto {
+            enabled: self.build_fn.into_target,
+            ident: format_ident!("{}_into", self.build_fn.name),
+            visibility: self.build_method_vis(),
+            target_ident: &self.ident,
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            error_ty: self.builder_error_ident(),
             validate_fn: self.build_fn.validate.as_ref(),
+            report_all_missing: self.report_all_missing(),
+            required_fields: Vec::new(),
+            assignments: Vec::with_capacity(self.field_count()),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    /// Get the rules applying to fields of type `ty`, the last declared first.
+    fn type_rules_for(&self, ty: &syn::Type) -> impl Iterator<Item = &TypeRule> + '_ {
+        let ty = quote!(#ty).to_string();
+        self.type_rules.iter().rev().filter(move |rule| {
+            let rule_ty = &rule.ty;
+            quote!(#rule_ty).to_string() == ty
+        })
+    }
+
+    pub fn as_builder_snapshot(&self) -> BuilderSnapshot<'_> {
+        BuilderSnapshot {
+            enabled: self.impl_snapshot.is_present(),
+            ident: format_ident!("{}Snapshot", self.builder_ident()),
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            visibility: self.builder_vis(),
+            field_idents: Vec::with_capacity(self.field_count()),
+            values: Vec::with_capacity(self.field_count()),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_into_builder(&self) -> IntoBuilder<'_> {
+        IntoBuilder {
+            enabled: self.into_builder.is_present(),
+            target_ident: &self.ident,
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            create_empty: &self.create_empty,
+            has_other_fields: self.lazy_build.is_present(),
+            initializers: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_to_builder(&self) -> ToBuilder<'_> {
+        ToBuilder {
+            enabled: self.impl_builder_on_original.is_present(),
+            target_ident: &self.ident,
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            visibility: self.builder_vis(),
+            create_empty: &self.create_empty,
+            has_other_fields: self.lazy_build.is_present(),
+            initializers: Vec::with_capacity(self.field_count()),
+            bounds: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_from_parts(&self) -> FromParts<'_> {
+        FromParts {
+            enabled: self.impl_from_parts.is_present(),
+            target_ident: &self.ident,
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            create_empty: &self.create_empty,
+            build_fn: &self.build_fn.name,
+            infallible: self.field.default.is_present(),
+            bounds: Vec::new(),
+            part_idents: Vec::new(),
+            part_types: Vec::new(),
+            setter_calls: Vec::new(),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    pub fn as_builder_interface(&self) -> BuilderInterface<'_> {
+        BuilderInterface {
+            enabled: self.inheritable.is_present(),
+            target_ident: &self.ident,
+            builder_ident: self.builder_ident(),
+            generics: Some(&self.generics),
+            owned: Vec::with_capacity(self.field_count()),
+            mutable: Vec::with_capacity(self.field_count()),
+            immutable: Vec::with_capacity(self.field_count()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+
+    /// Returns the forwarders for the setters of the inherited builder, if there is one.
+    pub fn as_inherited_setters(&self) -> Option<InheritedSetters<'_>> {
+        let builder_ident = &self.inherits.as_ref()?.segments.last()?.ident;
+        Some(InheritedSetters {
+            builder_ident,
+            field_ident: self.inherits_field.as_ref()?,
+            pattern: self.pattern,
+        })
+    }
+
+    /// The prefix of the environment variables read by `from_env`, if it is fixed by
+    /// `impl_from_env_prefixed`.
+    fn env_prefix(&self) -> Option<String> {
+        self.impl_from_env_prefixed
+            .as_ref()
+            .map(|prefixed| prefixed.env_prefix.value())
+    }
+
+    pub fn as_from_env(&self) -> FromEnv<'_> {
+        FromEnv {
+            enabled: self.from_env.is_present() || self.impl_from_env_prefixed.is_some(),
+            visibility: self.builder_vis(),
+            create_empty: &self.create_empty,
+            prefix: self.env_prefix(),
+            has_required: false,
+            vars: Vec::with_capacity(self.field_count()),
         }
     }
 }
@@ -723,25 +3007,44 @@ pub struct FieldWithDefaults<'a> {
 impl<'a> FieldWithDefaults<'a> {
     /// Check if this field should emit a setter.
     pub fn setter_enabled(&self) -> bool {
-        self.field
-            .setter
-            .setter_enabled()
-            .or_else(|| self.parent.setter.enabled())
-            .unwrap_or(true)
+        self.field.field.computed.is_none()
+            && self
+                .field
+                .setter
+                .setter_enabled()
+                .or_else(|| self.parent.setter.enabled())
+                .unwrap_or(true)
     }
 
     pub fn field_enabled(&self) -> bool {
-        self.field
-            .setter
-            .field_enabled()
-            .or_else(|| self.parent.setter.enabled())
-            .unwrap_or(true)
+        self.field.field.computed.is_none()
+            && self
+                .field
+                .setter
+                .field_enabled()
+                .or_else(|| self.parent.setter.enabled())
+                .unwrap_or(true)
     }
 
     /// Check if this field should emit a fallible setter.
     /// This depends on the `TryFrom` trait, which hasn't yet stabilized.
+    ///
+    /// A `try_` setter would bypass `setter(transform)`, so there is none for such fields.
     pub fn try_setter(&self) -> bool {
-        self.field.try_setter.is_present() || self.parent.try_setter.is_present()
+        self.field.setter.transform.is_none()
+            && (self.field.try_setter.is_present() || self.parent.try_setter.is_present())
+    }
+
+    /// Get the closure converting the setter's parameter into the value to store, with the type
+    /// of its parameter, if any.
+    pub fn setter_transform(&self) -> Option<(&syn::Expr, &syn::Type)> {
+        let transform = self.field.setter.transform.as_ref()?;
+        closure_param_type(transform).map(|ty| (transform, ty))
+    }
+
+    /// Get how the setter should react to being called more than once, if at all.
+    pub fn setter_once(&self) -> Option<SetOnce> {
+        self.field.setter.once
     }
 
     /// Get the prefix that should be applied to the field name to produce
@@ -754,29 +3057,173 @@ impl<'a> FieldWithDefaults<'a> {
             .or(self.parent.setter.prefix.as_ref())
     }
 
+    /// Get the suffix that should be appended to the field name to produce
+    /// the setter ident, if any.
+    pub fn setter_suffix(&self) -> Option<&Ident> {
+        self.field
+            .setter
+            .suffix
+            .as_ref()
+            .or(self.parent.setter.suffix.as_ref())
+    }
+
     /// Get the ident of the emitted setter method
     pub fn setter_ident(&self) -> syn::Ident {
         if let Some(ref custom) = self.field.setter.name {
             return custom.clone();
         }
 
-        let ident = &self.field.ident;
+        let mut ident = self.field.ident.clone().unwrap();
+
+        if let Some(prefix) = self.setter_prefix() {
+            ident = format_ident!("{}_{}", prefix, ident);
+        }
+
+        if let Some(suffix) = self.setter_suffix() {
+            ident = format_ident!("{}_{}", ident, suffix);
+        }
+
+        ident
+    }
+
+    /// Get the ident of the unprefixed setter emitted alongside the prefixed one, if any.
+    pub fn setter_bare_ident(&self) -> Option<syn::Ident> {
+        let keep_bare =
+            self.field.setter.keep_bare.is_present() || self.parent.setter.keep_bare.is_present();
+        let ident = self.field.ident.as_ref()?;
+        if keep_bare && self.setter_ident() != *ident {
+            Some(ident.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Get the idents of the `try_add_` and `saturating_add_` methods emitted for
+    /// `setter(checked_add)`, if any.
+    pub fn setter_add_idents(&self) -> Option<(syn::Ident, syn::Ident)> {
+        if !self.field.setter.checked_add.is_present() {
+            return None;
+        }
+
+        let ident = self.field_ident();
+        Some((
+            format_ident!("try_add_{}", ident),
+            format_ident!("saturating_add_{}", ident),
+        ))
+    }
+
+    /// Name of the method telling whether the field is set, see
+    /// `#[builder(impl_has_methods)]`: `has_foo`, or `has_required_foo` if building fails
+    /// without it.
+    ///
+    /// Fields the builder does not store as an `Option` have no such method.
+    pub fn has_method_ident(&self) -> Option<syn::Ident> {
+        let has_methods = self.parent.impl_has_methods.as_ref()?;
+        match self.field_type() {
+            BuilderFieldType::Optional(_) | BuilderFieldType::Lazy(_) => {}
+            BuilderFieldType::Precise(_) | BuilderFieldType::Phantom(_) => return None,
+        }
+
+        let prefix = has_methods
+            .prefix
+            .clone()
+            .unwrap_or_else(|| format_ident!("has_"));
+        let ident = self.field_ident();
+        Some(if self.is_required() {
+            format_ident!("{}required_{}", prefix, ident)
+        } else {
+            format_ident!("{}{}", prefix, ident)
+        })
+    }
+
+    /// Get the ident of the `with_` method emitted for `setter(with_mut)`, and the default value
+    /// it initializes an unset field with, if any.
+    pub fn setter_with_mut(&self) -> Option<(syn::Ident, TokenStream)> {
+        if !self.field.setter.with_mut.is_present() {
+            return None;
+        }
+
+        let default = match (&self.field.default, self.or_default()) {
+            (Some(default), _) => quote!(#default),
+            (None, Some(ty)) => {
+                quote!(<#ty as ::derive_builder::export::core::default::Default>::default())
+            }
+            (None, None) => return None,
+        };
+        Some((format_ident!("with_{}", self.field_ident()), default))
+    }
 
-        if let Some(ref prefix) = self.setter_prefix() {
-            return format_ident!("{}_{}", prefix, ident.as_ref().unwrap());
+    /// Get the ident of the `maybe_` setter emitted for `setter(option_setter)`, if any.
+    pub fn setter_option_ident(&self) -> Option<syn::Ident> {
+        let option_setter = self.field.setter.option_setter.as_ref()?;
+        if !self.setter_enabled() {
+            return None;
         }
 
-        ident.clone().unwrap()
+        let prefix = option_setter
+            .prefix
+            .clone()
+            .unwrap_or_else(|| format_ident!("maybe_"));
+        Some(format_ident!("{}{}", prefix, self.field_ident().unraw()))
     }
 
     /// Checks if the emitted setter should be generic over types that impl
     /// `Into<FieldType>`.
     pub fn setter_into(&self) -> bool {
-        self.field
-            .setter
-            .into
-            .or(self.parent.setter.into)
-            .unwrap_or_default()
+        !self.setter_as_ref()
+            && self
+                .field
+                .setter
+                .into
+                .or_else(|| {
+                    self.parent
+                        .type_rules_for(&self.field.ty)
+                        .find_map(|rule| rule.into)
+                })
+                .or(self.parent.setter.into)
+                .unwrap_or_default()
+    }
+
+    /// Get the function applied to the value of the field in the build method, if any.
+    pub fn field_map(&self) -> Option<&'a syn::Expr> {
+        self.field.field.map.as_ref().or_else(|| {
+            self.parent
+                .type_rules_for(&self.field.ty)
+                .find_map(|rule| rule.transform.as_ref())
+        })
+    }
+
+    /// Checks if the emitted setter should be generic over types that impl `AsRef<str>`.
+    pub fn setter_as_ref(&self) -> bool {
+        self.field.setter.as_ref.is_present()
+    }
+
+    /// Get the inner type the emitted setter accepts for a newtype field, if any.
+    pub fn setter_newtype_inner(&self) -> Option<&NewtypeInner> {
+        self.field.setter.newtype_inner.as_ref()
+    }
+
+    /// Get the doc comment generated for the setter, if any.
+    ///
+    /// A field-level `setter(doc)` is always used, replacing the doc comment of the field,
+    /// while the struct-level template only applies to setters which have no documentation
+    /// of their own.
+    pub fn setter_doc_comment(&self) -> Option<syn::Attribute> {
+        let template = match self.field.setter.doc {
+            Some(ref doc) => doc,
+            None if self
+                .field
+                .setter_attrs
+                .iter()
+                .any(|a| a.path.is_ident("doc")) =>
+            {
+                return None
+            }
+            None => self.parent.setter.doc.as_ref()?,
+        };
+
+        let field_name = self.field_ident().unraw().to_string();
+        Some(doc_comment_from(template.replace("{field}", &field_name)))
     }
 
     /// Checks if the emitted setter should strip the wrapper Option over types that impl
@@ -785,28 +3232,45 @@ impl<'a> FieldWithDefaults<'a> {
         self.field
             .setter
             .strip_option
+            .or_else(|| self.field.setter.option_setter.as_ref().map(|_| true))
             .or(self.parent.setter.strip_option)
             .unwrap_or_default()
     }
 
     /// Get the visibility of the emitted setter, if there will be one.
-    pub fn setter_vis(&self) -> Cow<syn::Visibility> {
+    ///
+    /// Without an explicit declaration, setters for fields with restricted visibility such as
+    /// `pub(super)` get the same visibility, so the builder does not expose more than the
+    /// deriving struct does; all other setters are public.
+    pub fn setter_vis(&self) -> Cow<'_, syn::Visibility> {
         self.field
             .as_expressed_vis()
             .or_else(|| self.parent.as_expressed_vis())
-            .unwrap_or_else(|| Cow::Owned(syn::parse_quote!(pub)))
+            .or_else(|| match self.field.vis {
+                syn::Visibility::Restricted(_) | syn::Visibility::Crate(_) => {
+                    Some(Cow::Borrowed(&self.field.vis))
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| {
+                if self.parent.setters_visibility_from_struct.is_present() {
+                    Cow::Borrowed(&self.parent.vis)
+                } else {
+                    Cow::Owned(syn::parse_quote!(pub))
+                }
+            })
     }
 
     /// Get the ident of the input field. This is also used as the ident of the
     /// emitted field.
-    pub fn field_ident(&self) -> &syn::Ident {
+    pub fn field_ident(&self) -> &'a syn::Ident {
         self.field
             .ident
             .as_ref()
             .expect("Tuple structs are not supported")
     }
 
-    pub fn field_vis(&self) -> Cow<syn::Visibility> {
+    pub fn field_vis(&self) -> Cow<'_, syn::Visibility> {
         self.field
             .field
             .as_expressed_vis()
@@ -831,6 +3295,10 @@ impl<'a> FieldWithDefaults<'a> {
             BuilderFieldType::Phantom(&self.field.ty)
         } else if let Some(custom_ty) = self.field.field.builder_type.as_ref() {
             BuilderFieldType::Precise(custom_ty)
+        } else if self.parent.field.default.is_present() {
+            BuilderFieldType::Precise(&self.field.ty)
+        } else if self.field.setter.lazy.is_some() {
+            BuilderFieldType::Lazy(&self.field.ty)
         } else {
             BuilderFieldType::Optional(&self.field.ty)
         }
@@ -840,6 +3308,7 @@ impl<'a> FieldWithDefaults<'a> {
         match (&self.field.field.builder_type, &self.field.field.build) {
             (_, Some(block)) => FieldConversion::Block(block),
             (Some(_), None) => FieldConversion::Move,
+            (None, None) if self.parent.field.default.is_present() => FieldConversion::Value,
             (None, None) => FieldConversion::OptionOrDefault,
         }
     }
@@ -848,8 +3317,89 @@ impl<'a> FieldWithDefaults<'a> {
         self.field.pattern.unwrap_or(self.parent.pattern)
     }
 
+    /// How the build method reads this field: moved out of a consumed builder, cloned otherwise.
+    ///
+    /// This follows the receiver of the build method, which a field-level `pattern` does not
+    /// change.
+    fn build_pattern(&self) -> BuilderPattern {
+        let receiver = self
+            .parent
+            .build_fn
+            .receiver
+            .unwrap_or_else(|| BuildFnReceiver::from_pattern(self.parent.pattern));
+        match receiver {
+            BuildFnReceiver::Owned => BuilderPattern::Owned,
+            BuildFnReceiver::Ref | BuildFnReceiver::RefMut => BuilderPattern::Mutable,
+        }
+    }
+
+    /// Get the type which must implement `Default` for `setter(or_default)`, if requested.
+    pub fn or_default(&self) -> Option<&syn::Type> {
+        if self.field.setter.or_default.is_present() {
+            Some(&self.field.ty)
+        } else {
+            None
+        }
+    }
+
+    /// Get the bound the build method needs for this field, if any.
+    ///
+    /// A default of `T::default()` for a type parameter `T` needs `T: Default`, which the
+    /// struct does not have to declare.
+    pub fn build_fn_bound(&self) -> Option<syn::WherePredicate> {
+        self.or_default()
+            .map(|ty| parse_quote!(#ty: ::derive_builder::export::core::default::Default))
+            .or_else(|| {
+                let ty = self.field.default.as_ref()?.default_call_type()?;
+                if self
+                    .parent
+                    .generics
+                    .type_params()
+                    .any(|param| param.ident == *ty)
+                {
+                    Some(parse_quote!(#ty: ::derive_builder::export::core::default::Default))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Whether building fails if this field is never set.
+    pub fn is_required(&self) -> bool {
+        if self.field.required.is_present() {
+            return true;
+        }
+
+        self.field_enabled()
+            && !self.parent.field.default.is_present()
+            && self.field.field.builder_type.is_none()
+            && self.field.field.build.is_none()
+            && self.field.default.is_none()
+            && self.parent.default.is_none()
+            && self.or_default().is_none()
+            && self.field.setter.lazy.is_none()
+    }
+
+    /// Whether `impl Default` for a `default_struct` builder sets this field from the struct's
+    /// default value. Fields with a default of their own keep it, and required fields stay unset.
+    pub fn uses_default_struct(&self) -> bool {
+        self.parent.default_struct.is_present()
+            && self.field.default.is_none()
+            && !self.field.try_default.is_present()
+            && self.or_default().is_none()
+            && !self.field.required.is_present()
+            && match self.field_type() {
+                BuilderFieldType::Optional(_) => true,
+                BuilderFieldType::Precise(_)
+                | BuilderFieldType::Lazy(_)
+                | BuilderFieldType::Phantom(_) => false,
+            }
+    }
+
     pub fn use_parent_default(&self) -> bool {
-        self.field.default.is_none() && self.parent.default.is_some()
+        self.field.default.is_none()
+            && self.parent.default.is_some()
+            && !self.field.required.is_present()
     }
 
     pub fn deprecation_notes(&self) -> &DeprecationNotes {
@@ -861,6 +3411,7 @@ impl<'a> FieldWithDefaults<'a> {
 impl<'a> FieldWithDefaults<'a> {
     /// Returns a `Setter` according to the options.
     pub fn as_setter(&'a self) -> Setter<'a> {
+        let (value_param, from_value_param) = self.parent.setter_value_params();
         Setter {
             setter_enabled: self.setter_enabled(),
             try_setter: self.try_setter(),
@@ -874,6 +3425,172 @@ impl<'a> FieldWithDefaults<'a> {
             strip_option: self.setter_strip_option(),
             deprecation_notes: self.deprecation_notes(),
             each: self.field.setter.each.as_ref(),
+            clear_build_cache: self.parent.lazy_build.is_present(),
+            once: self.setter_once(),
+            as_ref: self.setter_as_ref(),
+            newtype_inner: self.setter_newtype_inner(),
+            boxed: self.field.setter.boxed.is_present(),
+            into_iter: self.field.setter.into_iter.is_present(),
+            transform: self.setter_transform(),
+            type_alias: self.field.setter.type_alias.as_ref(),
+            doc_comment: self.setter_doc_comment(),
+            bare_ident: self.setter_bare_ident(),
+            add_idents: self.setter_add_idents(),
+            with_mut: self.setter_with_mut(),
+            option_ident: self.setter_option_ident(),
+            return_previous: self.field.setter.return_previous.is_present(),
+            normalize: self.field.setter.normalize.as_ref(),
+            validate: self
+                .field
+                .setter
+                .validate
+                .as_ref()
+                .map(|validate| (validate, self.parent.builder_error_ident())),
+            try_into: self.field.setter.try_into.is_present(),
+            value_param,
+            from_value_param,
+        }
+    }
+
+    /// The type alias to emit for the setter's parameter, with the type it stands for, if the
+    /// setter names its parameter type with a bare identifier.
+    pub fn setter_type_alias(&'a self) -> Option<(&'a syn::Ident, &'a syn::Type)> {
+        let ident = self.field.setter.type_alias.as_ref()?.get_ident()?;
+        if !self.setter_enabled() {
+            return None;
+        }
+
+        let ty = match self.field_type() {
+            BuilderFieldType::Optional(ty)
+            | BuilderFieldType::Precise(ty)
+            | BuilderFieldType::Lazy(ty) => ty,
+            BuilderFieldType::Phantom(_) => return None,
+        };
+        if self.setter_strip_option() {
+            return Some((ident, extract_type_from_option(ty).unwrap_or(ty)));
+        }
+
+        Some((ident, ty))
+    }
+
+    /// Returns an `EnvVar` according to the options, if the struct has a `from_env` constructor
+    /// which should read this field.
+    pub fn as_env_var(&'a self) -> Option<EnvVar<'a>> {
+        if !(self.parent.from_env.is_present() || self.parent.impl_from_env_prefixed.is_some())
+            || self.field.env_skip.is_present()
+            || !self.field_enabled()
+        {
+            return None;
+        }
+
+        let (builder_ty, builder_field_is_option) = match self.field_type() {
+            BuilderFieldType::Optional(ty) => (ty, true),
+            BuilderFieldType::Precise(ty) | BuilderFieldType::Phantom(ty) => (ty, false),
+            // Rejected by `Options::check_lazy_setters`.
+            BuilderFieldType::Lazy(_) => return None,
+        };
+        let mut wrap_in_some = builder_field_is_option as usize;
+        let mut parse_ty = builder_ty;
+        // The variable holds the value of an `Option`, set or not by the presence of the
+        // variable, with or without `strip_option`.
+        if let Some(inner) = extract_type_from_option(builder_ty) {
+            parse_ty = inner;
+            wrap_in_some += 1;
+        }
+
+        Some(EnvVar {
+            field_ident: self.field_ident(),
+            parse_ty,
+            wrap_in_some,
+            required: self.is_required(),
+            prefix: self.parent.env_prefix(),
+        })
+    }
+
+    /// Returns a `DiffField` according to the options.
+    pub fn as_diff_field(&'a self) -> DiffField<'a> {
+        DiffField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            presence_only: self.field.diff_presence_only.is_present(),
+        }
+    }
+
+    /// Returns a `DebugField` according to the options.
+    pub fn as_debug_field(&'a self) -> DebugField<'a> {
+        DebugField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            sensitive: self.field.field.sensitive.is_present(),
+        }
+    }
+
+    /// Returns a `HashField` according to the options.
+    pub fn as_hash_field(&'a self) -> HashField<'a> {
+        HashField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+        }
+    }
+
+    /// Returns a `DisplayField` according to the options.
+    pub fn as_display_field(&'a self) -> DisplayField<'a> {
+        DisplayField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            sensitive: self.field.field.sensitive.is_present(),
+        }
+    }
+
+    /// Returns a `PartsField` according to the options.
+    pub fn as_parts_field(&'a self) -> PartsField<'a> {
+        PartsField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+        }
+    }
+
+    /// Returns a `PartialField` according to the options.
+    pub fn as_partial_field(&'a self) -> PartialField<'a> {
+        PartialField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            required: self.is_required(),
+        }
+    }
+
+    pub fn as_build_into_field(&'a self) -> BuildIntoField<'a> {
+        BuildIntoField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            required: self.is_required(),
+        }
+    }
+
+    /// Returns a `SnapshotField` according to the options.
+    pub fn as_snapshot_field(&'a self) -> SnapshotField<'a> {
+        SnapshotField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            sensitive: self.field.field.sensitive.is_present(),
+        }
+    }
+
+    /// Returns a `SerializeField` according to the options.
+    pub fn as_serialize_field(&'a self) -> SerializeField<'a> {
+        SerializeField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
+            sensitive: self.field.field.sensitive.is_present(),
+            skip: self.field.serialize_skip.is_present(),
+        }
+    }
+
+    /// Returns an `IntoBuilderField` according to the options.
+    pub fn as_into_builder_field(&'a self) -> IntoBuilderField<'a> {
+        IntoBuilderField {
+            field_ident: self.field_ident(),
+            field_type: self.field_type(),
         }
     }
 
@@ -886,16 +3603,27 @@ impl<'a> FieldWithDefaults<'a> {
         Initializer {
             field_enabled: self.field_enabled(),
             field_ident: self.field_ident(),
-            builder_pattern: self.pattern(),
+            field_type: &self.field.ty,
+            builder_pattern: self.build_pattern(),
             default_value: self.field.default.as_ref(),
+            try_default: self.field.try_default.is_present(),
+            or_default: self.or_default(),
             use_default_struct: self.use_parent_default(),
             conversion: self.conversion(),
-            custom_error_type_span: self
-                .parent
-                .build_fn
-                .error
-                .as_ref()
-                .map(|err_ty| err_ty.span()),
+            custom_error_type_span: self.parent.existing_error().map(|err_ty| err_ty.span()),
+            unchecked: false,
+            expect: false,
+            map: self.field_map(),
+            lazy: self.field.setter.lazy.as_ref(),
+            computed: self.field.field.computed.as_ref(),
+            assertions: self
+                .field
+                .field
+                .assert
+                .iter()
+                .zip(&self.field.field.error)
+                .collect(),
+            alloc: self.parent.alloc(),
         }
     }
 
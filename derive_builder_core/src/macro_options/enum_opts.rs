@@ -0,0 +1,78 @@
+use darling::util::PathList;
+use darling::{self, Error};
+use syn::{self, Ident};
+
+use super::darling_opts::default_allow_lints;
+use crate::EnumBuilder;
+
+/// Options of `#[derive(EnumBuilder)]`, which only supports enums with unit variants.
+#[derive(Debug, Clone, FromDeriveInput)]
+#[darling(
+    attributes(builder),
+    supports(enum_any),
+    and_then = "Self::check_variants"
+)]
+pub struct EnumOptions {
+    ident: Ident,
+
+    vis: syn::Visibility,
+
+    /// Lints allowed on the generated items, see `Options::allow_lints`.
+    #[darling(default = "default_allow_lints")]
+    allow_lints: PathList,
+
+    /// The parsed body of the derived enum.
+    data: darling::ast::Data<Variant, darling::util::Ignored>,
+}
+
+#[derive(Debug, Clone, FromVariant)]
+pub struct Variant {
+    ident: Ident,
+    fields: darling::ast::Fields<darling::util::Ignored>,
+}
+
+impl EnumOptions {
+    fn check_variants(self) -> darling::Result<Self> {
+        let mut errors = Error::accumulator();
+
+        if self.variants().is_empty() {
+            errors.push(
+                Error::custom("#[derive(EnumBuilder)] requires at least one variant")
+                    .with_span(&self.ident),
+            );
+        }
+
+        for variant in self.variants() {
+            if !variant.fields.is_unit() {
+                errors.push(
+                    Error::custom("#[derive(EnumBuilder)] only supports unit variants")
+                        .with_span(&variant.ident),
+                );
+            }
+        }
+
+        errors.finish_with(self)
+    }
+
+    fn variants(&self) -> &[Variant] {
+        match self.data {
+            darling::ast::Data::Enum(ref variants) => variants,
+            darling::ast::Data::Struct(_) => unreachable!("Only enums supported"),
+        }
+    }
+
+    pub fn variant_idents(&self) -> impl Iterator<Item = &Ident> {
+        self.variants().iter().map(|variant| &variant.ident)
+    }
+
+    pub fn as_enum_builder(&self) -> EnumBuilder<'_> {
+        EnumBuilder {
+            ident: format_ident!("{}Builder", self.ident),
+            target_ident: &self.ident,
+            visibility: &self.vis,
+            methods: Vec::with_capacity(self.variants().len()),
+            arms: Vec::with_capacity(self.variants().len()),
+            allowed_lints: &self.allow_lints,
+        }
+    }
+}
@@ -12,5 +12,7 @@
 //!    `FieldOptions` instances.
 
 mod darling_opts;
+mod enum_opts;
 
-pub use self::darling_opts::Options;
+pub use self::darling_opts::{forward_struct_attrs, Options};
+pub use self::enum_opts::EnumOptions;
@@ -0,0 +1,105 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+/// Implementation of `derive_builder::Newtype` for a tuple struct with a single field,
+/// implementing `quote::ToTokens`.
+///
+/// # Examples
+///
+/// Will expand to something like the following:
+///
+/// ```rust,ignore
+/// impl ::derive_builder::Newtype for Kilograms {
+///     type Inner = f32;
+///
+///     fn from_inner(inner: Self::Inner) -> Self {
+///         Kilograms(inner)
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NewtypeImpl<'a> {
+    /// Name of the newtype.
+    pub ident: &'a syn::Ident,
+    /// Generics of the newtype.
+    pub generics: &'a syn::Generics,
+    /// Type of the single field of the newtype.
+    pub inner: &'a syn::Type,
+}
+
+impl<'a> ToTokens for NewtypeImpl<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = self.ident;
+        let inner = self.inner;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::Newtype for #ident #ty_generics #where_clause {
+                type Inner = #inner;
+
+                fn from_inner(inner: Self::Inner) -> Self {
+                    #ident(inner)
+                }
+            }
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let ident: syn::Ident = parse_quote!(Kilograms);
+        let generics = syn::Generics::default();
+        let inner: syn::Type = parse_quote!(f32);
+        let newtype = NewtypeImpl {
+            ident: &ident,
+            generics: &generics,
+            inner: &inner,
+        };
+
+        assert_eq!(
+            quote!(#newtype).to_string(),
+            quote!(
+                impl ::derive_builder::Newtype for Kilograms {
+                    type Inner = f32;
+
+                    fn from_inner(inner: Self::Inner) -> Self {
+                        Kilograms(inner)
+                    }
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn generic() {
+        let ident: syn::Ident = parse_quote!(Tagged);
+        let generics: syn::Generics = parse_quote!(<T: Clone>);
+        let inner: syn::Type = parse_quote!(T);
+        let newtype = NewtypeImpl {
+            ident: &ident,
+            generics: &generics,
+            inner: &inner,
+        };
+
+        assert_eq!(
+            quote!(#newtype).to_string(),
+            quote!(
+                impl<T: Clone> ::derive_builder::Newtype for Tagged<T> {
+                    type Inner = T;
+
+                    fn from_inner(inner: Self::Inner) -> Self {
+                        Tagged(inner)
+                    }
+                }
+            )
+            .to_string()
+        );
+    }
+}
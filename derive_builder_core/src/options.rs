@@ -34,15 +34,181 @@ impl Default for BuilderPattern {
     }
 }
 
+/// How the build method takes `self`, overriding the receiver implied by the
+/// builder pattern.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum BuildFnReceiver {
+    /// E.g. `fn build(self) -> Result<Foo, FooBuilderError>`, written `receiver = "self"`.
+    Owned,
+    /// E.g. `fn build(&self) -> Result<Foo, FooBuilderError>`, written `receiver = "ref"`.
+    Ref,
+    /// E.g. `fn build(&mut self) -> Result<Foo, FooBuilderError>`, written
+    /// `receiver = "ref_mut"`.
+    RefMut,
+}
+
+impl BuildFnReceiver {
+    /// The receiver of a build method following `pattern`.
+    pub fn from_pattern(pattern: BuilderPattern) -> Self {
+        match pattern {
+            BuilderPattern::Owned => BuildFnReceiver::Owned,
+            BuilderPattern::Mutable | BuilderPattern::Immutable => BuildFnReceiver::Ref,
+        }
+    }
+}
+
+impl darling::FromMeta for BuildFnReceiver {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "self" => Ok(BuildFnReceiver::Owned),
+            "ref" => Ok(BuildFnReceiver::Ref),
+            "ref_mut" => Ok(BuildFnReceiver::RefMut),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+/// What the build method returns in place of the target struct, converting it with `From`.
+#[derive(Debug, Clone)]
+pub enum BuildFnInto {
+    /// Any type implementing `From<Foo>`, chosen by the caller, written `into`.
+    Inferred,
+    /// The given type, which must implement `From<Foo>`, written `into = "Arc<Foo>"`.
+    Explicit(Box<syn::Type>),
+}
+
+impl darling::FromMeta for BuildFnInto {
+    fn from_word() -> darling::Result<Self> {
+        Ok(BuildFnInto::Inferred)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(|ty| BuildFnInto::Explicit(Box::new(ty)))
+            .map_err(|_| darling::Error::unknown_value(value))
+    }
+}
+
 #[derive(Debug, Clone, FromMeta)]
 pub struct Each {
-    pub name: syn::Ident,
+    pub name: Option<syn::Ident>,
     #[darling(default)]
     pub into: bool,
+    /// Name of a method removing all elements equal to its argument from a `Vec` field.
+    pub remove: Option<syn::Ident>,
+    /// Skip elements already in a `Vec` field in the `each` setter.
+    pub dedup: Option<EachDedup>,
 }
 
 impl From<syn::Ident> for Each {
     fn from(name: syn::Ident) -> Self {
-        Self { name, into: false }
+        Self {
+            name: Some(name),
+            into: false,
+            remove: None,
+            dedup: None,
+        }
+    }
+}
+
+/// How an `each` setter recognizes elements already in a `Vec` field, see
+/// `#[builder(setter(each(dedup)))]`.
+#[derive(Debug, Clone)]
+pub enum EachDedup {
+    /// Compare the elements with `PartialEq`, written `dedup`.
+    Eq,
+    /// Compare the keys a `Fn(&T) -> K` at the given path returns for the elements, written
+    /// `dedup = "path::to::fn"`.
+    Key(syn::Path),
+}
+
+impl darling::FromMeta for EachDedup {
+    fn from_word() -> darling::Result<Self> {
+        Ok(EachDedup::Eq)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(EachDedup::Key)
+            .map_err(|_| darling::Error::unknown_value(value))
+    }
+}
+
+/// How a `setter(once)` setter reacts when its field already has a value.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SetOnce {
+    /// Return `Err(AlreadySetError)` from the setter, written `setter(once)`.
+    Error,
+    /// Panic in the setter, written `setter(once(panic))`.
+    Panic,
+}
+
+impl darling::FromMeta for SetOnce {
+    fn from_word() -> darling::Result<Self> {
+        Ok(SetOnce::Error)
+    }
+
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        if items.len() > 1 {
+            return Err(darling::Error::too_many_items(1).with_span(&items[1]));
+        }
+
+        match items.first() {
+            None => Ok(SetOnce::Error),
+            Some(syn::NestedMeta::Meta(syn::Meta::Path(path))) if path.is_ident("panic") => {
+                Ok(SetOnce::Panic)
+            }
+            Some(item) => Err(darling::Error::custom("expected `panic`").with_span(item)),
+        }
+    }
+}
+
+/// The inner type accepted by the setter of a tuple newtype field, see
+/// `#[builder(setter(newtype_inner))]`.
+#[derive(Debug, Clone)]
+pub enum NewtypeInner {
+    /// Read from the `derive_builder::Newtype` impl of the field type, written
+    /// `newtype_inner`.
+    Derived,
+    /// Spelled out, written `newtype_inner = "Type"`.
+    Explicit(Box<syn::Type>),
+}
+
+impl darling::FromMeta for NewtypeInner {
+    fn from_word() -> darling::Result<Self> {
+        Ok(NewtypeInner::Derived)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(|ty| NewtypeInner::Explicit(Box::new(ty)))
+            .map_err(|_| darling::Error::unknown_value(value))
+    }
+}
+
+/// String normalization applied by a setter before storing the value, see
+/// `#[builder(setter(normalize = "..."))]`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Normalize {
+    /// Remove leading and trailing whitespace, written `normalize = "trim"`.
+    Trim,
+    /// Convert to lower case, written `normalize = "lowercase"`.
+    Lowercase,
+    /// Convert to upper case, written `normalize = "uppercase"`.
+    Uppercase,
+    /// Call a `Fn(&str) -> String` at the given path, written `normalize = "path::to::fn"`.
+    Custom(syn::Path),
+}
+
+impl darling::FromMeta for Normalize {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "trim" => Ok(Normalize::Trim),
+            "lowercase" => Ok(Normalize::Lowercase),
+            "uppercase" => Ok(Normalize::Uppercase),
+            _ => syn::parse_str(value)
+                .map(Normalize::Custom)
+                .map_err(|_| darling::Error::unknown_value(value)),
+        }
     }
 }
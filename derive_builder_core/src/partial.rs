@@ -0,0 +1,421 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+use BuilderFieldType;
+use BuilderPattern;
+
+/// Partially built struct, implementing `quote::ToTokens`.
+///
+/// Emits a `PartialFoo` struct holding every field of `Foo` as an `Option`, and a
+/// `try_build_partial` method on the builder producing it from the fields set so far. Only
+/// the required fields must be set.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, BuilderPartial, PartialField};
+/// # fn main() {
+/// #    let mut partial = default_builder_partial!();
+/// #    partial.push_field(PartialField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        required: true,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#partial).to_string(), quote!(
+/// pub struct PartialFoo {
+///     pub foo: Option<String>,
+/// }
+///
+/// impl FooBuilder {
+///     pub fn try_build_partial(&self) -> Result<PartialFoo, FooBuilderError> {
+///         if self.foo.is_none() {
+///             return Err(Into::into(UninitializedFieldError::from("foo")));
+///         }
+///         Ok(PartialFoo {
+///             foo: Clone::clone(&self.foo),
+///         })
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderPartial<'a> {
+    /// Enables code generation for the partial struct and method.
+    pub enabled: bool,
+    /// Name of the partial struct.
+    pub ident: syn::Ident,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the partial struct, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// Visibility of the `try_build_partial` method.
+    pub method_visibility: Cow<'a, syn::Visibility>,
+    /// How the method takes the builder: by value for `Owned`, by reference otherwise.
+    pub pattern: BuilderPattern,
+    /// Type returned in case of a missing required field.
+    pub error_ty: syn::Path,
+    /// Check all required fields up front and report every missing one in a single
+    /// `UninitializedFields` error, rather than failing on the first.
+    pub report_all_missing: bool,
+    /// Traits to derive on the partial struct, see `#[builder(build_partial(derive(...)))]`.
+    pub derives: &'a [syn::Path],
+    /// Names of the target fields in the partial struct.
+    pub field_idents: Vec<syn::Ident>,
+    /// Types of the target fields, each wrapped in an `Option` in the partial struct.
+    pub field_types: Vec<TokenStream>,
+    /// Expressions reading each field from the builder, evaluating to `Option<T>`.
+    pub values: Vec<TokenStream>,
+    /// Fields which must be set for the method to succeed.
+    pub required_fields: Vec<syn::Ident>,
+    /// Bounds of the method, requiring the cloned field types to implement `Clone`.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A target field populated by `BuilderPartial`.
+#[derive(Debug, Clone)]
+pub struct PartialField<'a> {
+    /// Name of the target field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Whether `try_build_partial` fails if this field is unset.
+    pub required: bool,
+}
+
+impl<'a> ToTokens for BuilderPartial<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let method_vis = &self.method_visibility;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let struct_generics = impl_generics.as_ref();
+        let derives = if self.derives.is_empty() {
+            quote!()
+        } else {
+            let derives = self.derives;
+            quote!(#[derive(#(#derives),*)])
+        };
+        let field_idents = &self.field_idents;
+        let field_types = &self.field_types;
+        let values = &self.values;
+        let error_ty = &self.error_ty;
+        let self_param = match self.pattern {
+            BuilderPattern::Owned => quote!(self),
+            BuilderPattern::Mutable | BuilderPattern::Immutable => quote!(&self),
+        };
+        let required = &self.required_fields;
+        let names = required.iter().map(|ident| ident.unraw().to_string());
+        let check_missing = if self.report_all_missing && !required.is_empty() {
+            quote!(
+                let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+                #(
+                    if self.#required.is_none() {
+                        __missing_fields.push(#names);
+                    }
+                )*
+                if !__missing_fields.is_empty() {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        #error_ty::UninitializedFields(__missing_fields)
+                    );
+                }
+            )
+        } else {
+            quote!(#(
+                if self.#required.is_none() {
+                    return ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::export::core::convert::Into::into(
+                            ::derive_builder::UninitializedFieldError::from(#names)
+                        )
+                    );
+                }
+            )*)
+        };
+        let bounds = if self.bounds.is_empty() {
+            quote!()
+        } else {
+            let bounds = &self.bounds;
+            quote!(where #(#bounds),*)
+        };
+        let struct_doc = format!(
+            "A `{}` with every field wrapped in an `Option`, see `{}::try_build_partial`.",
+            self.target_ident, builder_ident
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #struct_doc]
+            #derives
+            #vis struct #ident #struct_generics #where_clause {
+                #(
+                    /// `None` if the field is unset.
+                    pub #field_idents: ::derive_builder::export::core::option::Option<#field_types>,
+                )*
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                /// Build from the fields set so far, leaving the unset ones `None` rather than
+                /// defaulting them.
+                ///
+                /// # Errors
+                ///
+                /// If a required field is unset.
+                #method_vis fn try_build_partial(#self_param)
+                    -> ::derive_builder::export::core::result::Result<#ident #ty_generics, #error_ty>
+                    #bounds
+                {
+                    #check_missing
+                    ::derive_builder::export::core::result::Result::Ok(#ident {
+                        #(#field_idents: #values,)*
+                    })
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderPartial<'a> {
+    /// Add another target field to the partial struct.
+    pub fn push_field(&mut self, field: PartialField) -> &mut Self {
+        // Fields are only checked by `Options::check_build_partial` if this is enabled.
+        if !self.enabled {
+            return self;
+        }
+
+        let ident = field.field_ident;
+        let (ty, value) = match field.field_type {
+            BuilderFieldType::Optional(ty) => {
+                if field.required {
+                    self.required_fields.push(ident.clone());
+                }
+                let value = match self.pattern {
+                    BuilderPattern::Owned => quote!(self.#ident),
+                    BuilderPattern::Mutable | BuilderPattern::Immutable => {
+                        self.bounds
+                            .push(parse_quote!(#ty: ::derive_builder::export::core::clone::Clone));
+                        quote!(::derive_builder::export::core::clone::Clone::clone(&self.#ident))
+                    }
+                };
+                (ty, value)
+            }
+            // Fields without a setter are never set.
+            BuilderFieldType::Phantom(ty) => (
+                ty,
+                quote!(::derive_builder::export::core::option::Option::None),
+            ),
+            // Rejected by `Options::check_build_partial`.
+            BuilderFieldType::Precise(_) | BuilderFieldType::Lazy(_) => {
+                unreachable!("partial builds only support fields holding an `Option`")
+            }
+        };
+        self.field_idents.push(ident.clone());
+        self.field_types.push(quote!(#ty));
+        self.values.push(value);
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_partial {
+    () => {
+        BuilderPartial {
+            enabled: true,
+            ident: syn::Ident::new("PartialFoo", ::proc_macro2::Span::call_site()),
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            method_visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            pattern: BuilderPattern::Mutable,
+            error_ty: parse_quote!(FooBuilderError),
+            report_all_missing: false,
+            derives: &[],
+            field_idents: vec![],
+            field_types: vec![],
+            values: vec![],
+            required_fields: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn disabled() {
+        let mut partial = default_builder_partial!();
+        partial.enabled = false;
+
+        assert_eq!(quote!(#partial).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new("bar", Span::call_site());
+        let marker = syn::Ident::new("marker", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let number: syn::Type = parse_quote!(u32);
+        let derives: Vec<syn::Path> = vec![parse_quote!(Debug)];
+        let mut partial = default_builder_partial!();
+        partial.derives = &derives;
+        partial.push_field(PartialField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            required: true,
+        });
+        partial.push_field(PartialField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Optional(&number),
+            required: false,
+        });
+        partial.push_field(PartialField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&number),
+            required: false,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            #[doc = "A `Foo` with every field wrapped in an `Option`, see `FooBuilder::try_build_partial`."]
+            #[derive(Debug)]
+            pub struct PartialFoo {
+                /// `None` if the field is unset.
+                pub foo: ::derive_builder::export::core::option::Option<String>,
+                /// `None` if the field is unset.
+                pub bar: ::derive_builder::export::core::option::Option<u32>,
+                /// `None` if the field is unset.
+                pub marker: ::derive_builder::export::core::option::Option<u32>,
+            }
+
+            impl FooBuilder {
+                /// Build from the fields set so far, leaving the unset ones `None` rather than
+                /// defaulting them.
+                ///
+                /// # Errors
+                ///
+                /// If a required field is unset.
+                pub fn try_build_partial(&self)
+                    -> ::derive_builder::export::core::result::Result<PartialFoo, FooBuilderError>
+                    where
+                        String: ::derive_builder::export::core::clone::Clone,
+                        u32: ::derive_builder::export::core::clone::Clone
+                {
+                    if self.foo.is_none() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::export::core::convert::Into::into(
+                                ::derive_builder::UninitializedFieldError::from("foo")
+                            )
+                        );
+                    }
+                    ::derive_builder::export::core::result::Result::Ok(PartialFoo {
+                        foo: ::derive_builder::export::core::clone::Clone::clone(&self.foo),
+                        bar: ::derive_builder::export::core::clone::Clone::clone(&self.bar),
+                        marker: ::derive_builder::export::core::option::Option::None,
+                    })
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#partial).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn owned_and_report_all_missing() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let mut partial = default_builder_partial!();
+        partial.pattern = BuilderPattern::Owned;
+        partial.report_all_missing = true;
+        partial.push_field(PartialField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            required: true,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            #[doc = "A `Foo` with every field wrapped in an `Option`, see `FooBuilder::try_build_partial`."]
+            pub struct PartialFoo {
+                /// `None` if the field is unset.
+                pub foo: ::derive_builder::export::core::option::Option<String>,
+            }
+
+            impl FooBuilder {
+                /// Build from the fields set so far, leaving the unset ones `None` rather than
+                /// defaulting them.
+                ///
+                /// # Errors
+                ///
+                /// If a required field is unset.
+                pub fn try_build_partial(self)
+                    -> ::derive_builder::export::core::result::Result<PartialFoo, FooBuilderError>
+                {
+                    let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+                    if self.foo.is_none() {
+                        __missing_fields.push("foo");
+                    }
+                    if !__missing_fields.is_empty() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            FooBuilderError::UninitializedFields(__missing_fields)
+                        );
+                    }
+                    ::derive_builder::export::core::result::Result::Ok(PartialFoo {
+                        foo: self.foo,
+                    })
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#partial).to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,247 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+use BuilderFieldType;
+
+/// Conversion of a builder into and from its fields, implementing `quote::ToTokens`.
+///
+/// Emits a `FooBuilderParts` tuple struct with one element per builder field, and the
+/// `into_parts` and `from_parts` methods converting between it and the builder.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, BuilderParts, PartsField};
+/// # fn main() {
+/// #    let mut parts = default_builder_parts!();
+/// #    parts.push_field(PartsField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#parts).to_string(), quote!(
+/// pub struct FooBuilderParts(pub Option<String>);
+///
+/// impl FooBuilder {
+///     pub fn into_parts(self) -> FooBuilderParts {
+///         FooBuilderParts(self.foo)
+///     }
+///
+///     pub fn from_parts(parts: FooBuilderParts) -> Self {
+///         Self {
+///             foo: parts.0,
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderParts<'a> {
+    /// Enables code generation for the parts struct and methods.
+    pub enabled: bool,
+    /// Name of the parts struct.
+    pub ident: syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the parts struct and methods, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// The identifier of the inherent method that creates an empty builder, used by
+    /// `from_parts` to fill the builder fields which are not part of the parts struct.
+    pub create_empty: &'a syn::Ident,
+    /// Whether the builder has fields which are not part of the parts struct, such as
+    /// `PhantomData` for fields without setters or the cache of a lazy build method.
+    pub has_other_fields: bool,
+    /// Names of the builder fields in the parts struct.
+    pub field_idents: Vec<syn::Ident>,
+    /// Types of the builder fields in the parts struct.
+    pub field_types: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field converted by `BuilderParts`.
+#[derive(Debug, Clone)]
+pub struct PartsField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+}
+
+impl<'a> ToTokens for BuilderParts<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let struct_generics = impl_generics.as_ref();
+        let field_idents = &self.field_idents;
+        let field_types = &self.field_types;
+        let indices = (0..self.field_idents.len()).map(syn::Index::from);
+        let create_empty = self.create_empty;
+        let other_fields = if self.has_other_fields {
+            quote!(..Self::#create_empty())
+        } else {
+            quote!()
+        };
+        let struct_doc = format!(
+            "The fields of a `{}` in declaration order, see `{}::into_parts`.",
+            builder_ident, builder_ident
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #struct_doc]
+            #vis struct #ident #struct_generics (#(pub #field_types),*) #where_clause;
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                /// Split this builder into its fields.
+                #vis fn into_parts(self) -> #ident #ty_generics {
+                    #ident(#(self.#field_idents),*)
+                }
+
+                /// Create a builder from fields previously returned by `into_parts`.
+                #vis fn from_parts(parts: #ident #ty_generics) -> Self {
+                    Self {
+                        #(#field_idents: parts.#indices,)*
+                        #other_fields
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderParts<'a> {
+    /// Add another builder field to the parts struct.
+    pub fn push_field(&mut self, field: PartsField) -> &mut Self {
+        match field.field_type {
+            BuilderFieldType::Phantom(_) => self.has_other_fields = true,
+            _ => {
+                let ty = &field.field_type;
+                self.field_idents.push(field.field_ident.clone());
+                self.field_types.push(quote!(#ty));
+            }
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_parts {
+    () => {
+        BuilderParts {
+            enabled: true,
+            ident: syn::Ident::new("FooBuilderParts", ::proc_macro2::Span::call_site()),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            create_empty: Box::leak(Box::new(syn::Ident::new(
+                "create_empty",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            has_other_fields: false,
+            field_idents: vec![],
+            field_types: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn disabled() {
+        let mut parts = default_builder_parts!();
+        parts.enabled = false;
+
+        assert_eq!(quote!(#parts).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new("bar", Span::call_site());
+        let marker = syn::Ident::new("marker", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut parts = default_builder_parts!();
+        parts.push_field(PartsField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+        });
+        parts.push_field(PartsField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+        });
+        parts.push_field(PartsField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+        });
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            #[doc = "The fields of a `FooBuilder` in declaration order, see `FooBuilder::into_parts`."]
+            pub struct FooBuilderParts(
+                pub ::derive_builder::export::core::option::Option<String>,
+                pub Vec<u8>
+            );
+        ));
+
+        expected.append_all(quote!(
+            impl FooBuilder {
+                /// Split this builder into its fields.
+                pub fn into_parts(self) -> FooBuilderParts {
+                    FooBuilderParts(self.foo, self.bar)
+                }
+
+                /// Create a builder from fields previously returned by `into_parts`.
+                pub fn from_parts(parts: FooBuilderParts) -> Self {
+                    Self {
+                        foo: parts.0,
+                        bar: parts.1,
+                        ..Self::create_empty()
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#parts).to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use builder::impl_bounds;
+use extend::{phantom_variant, set_value, variant_ident};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+use BuilderPattern;
+use Setter;
+
+/// Updates of a builder from a list of values, implementing `quote::ToTokens`.
+///
+/// Emits a `FooPatch` enum with one variant per setter, holding the value to set, and an
+/// `apply` method on the builder calling the setter of each variant in turn.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderPatch, Setter};
+/// # fn main() {
+/// #    let mut patch = default_builder_patch!();
+/// #    patch.push_setter(&default_setter!());
+/// #
+/// #    assert_eq!(quote!(#patch).to_string(), quote!(
+/// pub enum FooPatch {
+///     Foo(Foo),
+/// }
+///
+/// impl FooBuilder {
+///     pub fn apply<I: IntoIterator<Item = FooPatch>>(&mut self, patches: I) {
+///         for patch in patches {
+///             match patch {
+///                 FooPatch::Foo(value) => {
+///                     self.foo(value);
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderPatch<'a> {
+    /// Enables code generation for the enum and the `apply` method.
+    pub enabled: bool,
+    /// Name of the patch enum.
+    pub ident: syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Builder pattern of the builder struct, which decides the bounds of `apply` like those of
+    /// the setters.
+    pub pattern: BuilderPattern,
+    /// Visibility of the patch enum and the `apply` method, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// The identifier of the inherent method that creates an empty builder, used to call the
+    /// setters of the owned pattern.
+    pub create_empty: &'a syn::Ident,
+    /// Variants of the patch enum, e.g. `Foo(u32),`.
+    pub variants: Vec<TokenStream>,
+    /// Match arms calling the setter of each variant.
+    pub arms: Vec<TokenStream>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+impl<'a> ToTokens for BuilderPatch<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let enum_generics = impl_generics.as_ref();
+        let bounded_generics = impl_bounds(self.generics, self.pattern);
+        let (bounded_impl_generics, _, bounded_where_clause) = bounded_generics.split_for_impl();
+        let mut variants = self.variants.clone();
+        let mut arms = self.arms.clone();
+        if let Some((variant, arm)) = phantom_variant(ident, self.generics) {
+            variants.push(variant);
+            arms.push(arm);
+        }
+        let enum_doc = format!(
+            "An update of one field of a `{}`, see `{}::apply`.",
+            builder_ident, builder_ident
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #enum_doc]
+            #vis enum #ident #enum_generics #where_clause {
+                #(#variants)*
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #bounded_impl_generics #builder_ident #ty_generics #bounded_where_clause {
+                /// Apply the patches in order, calling the setter of the field of each.
+                #vis fn apply<I: ::derive_builder::export::core::iter::IntoIterator<Item = #ident #ty_generics>>(
+                    &mut self,
+                    patches: I,
+                ) {
+                    for patch in patches {
+                        match patch {
+                            #(#arms)*
+                        }
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderPatch<'a> {
+    /// Add a variant calling another setter.
+    pub fn push_setter(&mut self, setter: &Setter) -> &mut Self {
+        let ty = match setter.extend_value_type() {
+            Some(ty) => ty,
+            None => return self,
+        };
+
+        let enum_ident = &self.ident;
+        let variant = variant_ident(setter.field_ident);
+        let set = set_value(setter, self.create_empty);
+
+        self.variants.push(quote!(
+            #[allow(missing_docs)]
+            #variant(#ty),
+        ));
+        self.arms.push(quote!(
+            #enum_ident::#variant(value) => {
+                #set
+            }
+        ));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_patch {
+    () => {
+        BuilderPatch {
+            enabled: true,
+            ident: syn::Ident::new("FooPatch", ::proc_macro2::Span::call_site()),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            pattern: Default::default(),
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            create_empty: Box::leak(Box::new(syn::Ident::new(
+                "create_empty",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            variants: vec![],
+            arms: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use default_setter;
+    use BuilderFieldType;
+    use BuilderPattern;
+
+    #[test]
+    fn disabled() {
+        let mut patch = default_builder_patch!();
+        patch.enabled = false;
+
+        assert_eq!(quote!(#patch).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn generic() {
+        let generics: syn::Generics = parse_quote!(<'a, T>);
+        let mut patch = default_builder_patch!();
+        patch.generics = Some(&generics);
+        patch.push_setter(&default_setter!());
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            #[doc = "An update of one field of a `FooBuilder`, see `FooBuilder::apply`."]
+            pub enum FooPatch<'a, T> {
+                #[allow(missing_docs)]
+                Foo(Foo),
+                #[doc(hidden)]
+                __Phantom(
+                    ::derive_builder::export::core::convert::Infallible,
+                    ::derive_builder::export::core::marker::PhantomData<&'a ()>,
+                    ::derive_builder::export::core::marker::PhantomData<T>
+                ),
+            }
+        ));
+
+        expected.append_all(quote!(
+            impl<'a, T: ::derive_builder::export::core::clone::Clone> FooBuilder<'a, T> {
+                /// Apply the patches in order, calling the setter of the field of each.
+                pub fn apply<I: ::derive_builder::export::core::iter::IntoIterator<Item = FooPatch<'a, T> >>(
+                    &mut self,
+                    patches: I,
+                ) {
+                    for patch in patches {
+                        match patch {
+                            FooPatch::Foo(value) => {
+                                self.foo(value);
+                            }
+                            FooPatch::__Phantom(never, ..) => match never {},
+                        }
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#patch).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn setters() {
+        let ident = syn::Ident::new("dolor_sit", ::proc_macro2::Span::call_site());
+        let ty: syn::Type = parse_quote!(u16);
+        let mut owned = default_setter!();
+        owned.ident = ident.clone();
+        owned.field_ident = &ident;
+        owned.field_type = BuilderFieldType::Optional(&ty);
+        owned.pattern = BuilderPattern::Owned;
+        let mut patch = default_builder_patch!();
+        patch.push_setter(&default_setter!());
+        patch.push_setter(&owned);
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            #[doc = "An update of one field of a `FooBuilder`, see `FooBuilder::apply`."]
+            pub enum FooPatch {
+                #[allow(missing_docs)]
+                Foo(Foo),
+                #[allow(missing_docs)]
+                DolorSit(u16),
+            }
+        ));
+
+        expected.append_all(quote!(
+            impl FooBuilder {
+                /// Apply the patches in order, calling the setter of the field of each.
+                pub fn apply<I: ::derive_builder::export::core::iter::IntoIterator<Item = FooPatch>>(
+                    &mut self,
+                    patches: I,
+                ) {
+                    for patch in patches {
+                        match patch {
+                            FooPatch::Foo(value) => {
+                                self.foo(value);
+                            }
+                            FooPatch::DolorSit(value) => {
+                                let builder = ::derive_builder::export::core::mem::replace(
+                                    self,
+                                    Self::create_empty()
+                                );
+                                *self = builder.dolor_sit(value);
+                            }
+                        }
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#patch).to_string(), expected.to_string());
+    }
+}
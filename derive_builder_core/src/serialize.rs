@@ -0,0 +1,332 @@
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+
+use BuilderFieldType;
+
+/// Implementation of `serde::Serialize` for the builder, implementing `quote::ToTokens`.
+///
+/// Serializes the builder as a struct of its fields in declaration order, leaving out unset
+/// fields. Every serialized field type is bounded by `Serialize`, with the bound spanned at the
+/// field type, so that a field which cannot be serialized is reported where it is declared.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, BuilderSerialize, SerializeField};
+/// # fn main() {
+/// #    let mut serialize = default_builder_serialize!();
+/// #    serialize.push_field(SerializeField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        sensitive: false,
+/// #        skip: false,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#serialize).to_string(), quote!(
+/// impl Serialize for FooBuilder
+/// where
+///     String: Serialize
+/// {
+///     fn serialize<__S: Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+///         let len = usize::from(self.foo.is_some());
+///         let mut state = Serializer::serialize_struct(serializer, "FooBuilder", len)?;
+///         match self.foo {
+///             Some(ref value) => { SerializeStruct::serialize_field(&mut state, "foo", value)? }
+///             None => { SerializeStruct::skip_field(&mut state, "foo")? }
+///         }
+///         SerializeStruct::end(state)
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderSerialize<'a> {
+    /// Enables code generation for the `Serialize` implementation.
+    pub enabled: bool,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Expressions counting the serialized fields, each evaluating to `usize`.
+    pub lens: Vec<TokenStream>,
+    /// Statements serializing each field into `state`.
+    pub fields: Vec<TokenStream>,
+    /// Bounds requiring each serialized field type to implement `Serialize`.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field serialized by `BuilderSerialize`.
+#[derive(Debug, Clone)]
+pub struct SerializeField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Serialize the value of the field as `null`, see `#[builder(field(sensitive))]`.
+    pub sensitive: bool,
+    /// Leave the field out, see `#[builder(serialize_skip)]`.
+    pub skip: bool,
+}
+
+impl<'a> ToTokens for BuilderSerialize<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let mut predicates: Vec<&syn::WherePredicate> = where_clause
+            .and_then(|clause| clause)
+            .map(|clause| clause.predicates.iter().collect())
+            .unwrap_or_default();
+        predicates.extend(&self.bounds);
+        let where_clause = if predicates.is_empty() {
+            quote!()
+        } else {
+            quote!(where #(#predicates),*)
+        };
+        let name = builder_ident.unraw().to_string();
+        let len = if self.lens.is_empty() {
+            quote!(0)
+        } else {
+            let lens = &self.lens;
+            quote!(#(#lens)+*)
+        };
+        let fields = &self.fields;
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics ::derive_builder::export::serde::Serialize
+                for #builder_ident #ty_generics
+                #where_clause
+            {
+                fn serialize<__S: ::derive_builder::export::serde::Serializer>(&self, serializer: __S)
+                    -> ::derive_builder::export::core::result::Result<__S::Ok, __S::Error>
+                {
+                    let len = #len;
+                    let mut state = ::derive_builder::export::serde::Serializer::serialize_struct(
+                        serializer,
+                        #name,
+                        len
+                    )?;
+                    #(#fields)*
+                    ::derive_builder::export::serde::ser::SerializeStruct::end(state)
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderSerialize<'a> {
+    /// Serialize another builder field.
+    pub fn push_field(&mut self, field: SerializeField) -> &mut Self {
+        let ident = field.field_ident;
+        let name = ident.unraw().to_string();
+        let serialize_field = |value: TokenStream| {
+            quote!(::derive_builder::export::serde::ser::SerializeStruct::serialize_field(
+                &mut state,
+                #name,
+                #value
+            )?)
+        };
+        let null = serialize_field(quote!(
+            &::derive_builder::export::core::option::Option::None::<()>
+        ));
+        let ty = match field.field_type {
+            _ if field.skip => return self,
+            // The closure of a lazy field cannot be serialized.
+            BuilderFieldType::Lazy(_) | BuilderFieldType::Phantom(_) => return self,
+            BuilderFieldType::Optional(ty) => {
+                let some = if field.sensitive {
+                    quote!(::derive_builder::export::core::option::Option::Some(_) => { #null })
+                } else {
+                    let value = serialize_field(quote!(value));
+                    quote!(::derive_builder::export::core::option::Option::Some(ref value) => { #value })
+                };
+                self.lens.push(quote!(usize::from(self.#ident.is_some())));
+                self.fields.push(quote!(
+                    match self.#ident {
+                        #some
+                        ::derive_builder::export::core::option::Option::None => {
+                            ::derive_builder::export::serde::ser::SerializeStruct::skip_field(
+                                &mut state,
+                                #name
+                            )?
+                        }
+                    }
+                ));
+                ty
+            }
+            BuilderFieldType::Precise(ty) => {
+                self.lens.push(quote!(1));
+                if field.sensitive {
+                    self.fields.push(quote!(#null;));
+                } else {
+                    let value = serialize_field(quote!(&self.#ident));
+                    self.fields.push(quote!(#value;));
+                }
+                ty
+            }
+        };
+        if !field.sensitive {
+            let bound = quote_spanned!(ty.span()=> #ty: ::derive_builder::export::serde::Serialize);
+            self.bounds.push(parse_quote!(#bound));
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_serialize {
+    () => {
+        BuilderSerialize {
+            enabled: true,
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            lens: vec![],
+            fields: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut serialize = default_builder_serialize!();
+        serialize.enabled = false;
+
+        assert_eq!(quote!(#serialize).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", ::proc_macro2::Span::call_site());
+        let bar = syn::Ident::new("bar", ::proc_macro2::Span::call_site());
+        let baz = syn::Ident::new("baz", ::proc_macro2::Span::call_site());
+        let skipped = syn::Ident::new("skipped", ::proc_macro2::Span::call_site());
+        let marker = syn::Ident::new("marker", ::proc_macro2::Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut serialize = default_builder_serialize!();
+        serialize.push_field(SerializeField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: false,
+            skip: false,
+        });
+        serialize.push_field(SerializeField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+            sensitive: false,
+            skip: false,
+        });
+        serialize.push_field(SerializeField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+            sensitive: false,
+            skip: false,
+        });
+        serialize.push_field(SerializeField {
+            field_ident: &baz,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: true,
+            skip: false,
+        });
+        serialize.push_field(SerializeField {
+            field_ident: &skipped,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: false,
+            skip: true,
+        });
+
+        let mut expected = quote!();
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::export::serde::Serialize for FooBuilder
+            where
+                String: ::derive_builder::export::serde::Serialize,
+                Vec<u8>: ::derive_builder::export::serde::Serialize
+            {
+                fn serialize<__S: ::derive_builder::export::serde::Serializer>(&self, serializer: __S)
+                    -> ::derive_builder::export::core::result::Result<__S::Ok, __S::Error>
+                {
+                    let len = usize::from(self.foo.is_some()) + 1 + usize::from(self.baz.is_some());
+                    let mut state = ::derive_builder::export::serde::Serializer::serialize_struct(
+                        serializer,
+                        "FooBuilder",
+                        len
+                    )?;
+                    match self.foo {
+                        ::derive_builder::export::core::option::Option::Some(ref value) => {
+                            ::derive_builder::export::serde::ser::SerializeStruct::serialize_field(
+                                &mut state,
+                                "foo",
+                                value
+                            )?
+                        }
+                        ::derive_builder::export::core::option::Option::None => {
+                            ::derive_builder::export::serde::ser::SerializeStruct::skip_field(
+                                &mut state,
+                                "foo"
+                            )?
+                        }
+                    }
+                    ::derive_builder::export::serde::ser::SerializeStruct::serialize_field(
+                        &mut state,
+                        "bar",
+                        &self.bar
+                    )?;
+                    match self.baz {
+                        ::derive_builder::export::core::option::Option::Some(_) => {
+                            ::derive_builder::export::serde::ser::SerializeStruct::serialize_field(
+                                &mut state,
+                                "baz",
+                                &::derive_builder::export::core::option::Option::None::<()>
+                            )?
+                        }
+                        ::derive_builder::export::core::option::Option::None => {
+                            ::derive_builder::export::serde::ser::SerializeStruct::skip_field(
+                                &mut state,
+                                "baz"
+                            )?
+                        }
+                    }
+                    ::derive_builder::export::serde::ser::SerializeStruct::end(state)
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#serialize).to_string(), expected.to_string());
+    }
+}
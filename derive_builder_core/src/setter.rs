@@ -2,13 +2,19 @@
 use std::borrow::Cow;
 
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, TokenStreamExt};
+use quote::{format_ident, ToTokens, TokenStreamExt};
 use syn;
+use syn::ext::IdentExt;
 
 use BuilderFieldType;
 use BuilderPattern;
 use DeprecationNotes;
 use Each;
+use EachDedup;
+use NewtypeInner;
+use Normalize;
+use SetOnce;
+use BUILD_CACHE_FIELD_NAME;
 
 /// Setter for the struct fields in the build method, implementing
 /// `quote::ToTokens`.
@@ -68,6 +74,54 @@ pub struct Setter<'a> {
     pub deprecation_notes: &'a DeprecationNotes,
     /// Emit extend method.
     pub each: Option<&'a Each>,
+    /// Reset the builder's cached build result, see `#[builder(lazy_build)]`.
+    pub clear_build_cache: bool,
+    /// Reject setting a field which already has a value.
+    pub once: Option<SetOnce>,
+    /// Make the setter generic over `AsRef<str>`, storing an owned `String`.
+    pub as_ref: bool,
+    /// Make the setter generic over `Into<inner>`, wrapping the value in the tuple newtype
+    /// which is the field type.
+    pub newtype_inner: Option<&'a NewtypeInner>,
+    /// Make the setter generic over `Into<T>` for a `Box<T>`, `Rc<T>` or `Arc<T>` field,
+    /// wrapping the value in the smart pointer.
+    pub boxed: bool,
+    /// Make the setter generic over `IntoIterator` with items `Into<T>` for a collection of `T`,
+    /// collecting the converted items.
+    pub into_iter: bool,
+    /// Closure converting the parameter of the setter into the value to store, and the type of
+    /// its parameter, which the setter takes.
+    pub transform: Option<(&'a syn::Expr, &'a syn::Type)>,
+    /// Path of a type alias naming the parameter type, used in place of the field type.
+    pub type_alias: Option<&'a syn::Path>,
+    /// Doc-comment generated for this setter fn, emitted before `attrs`.
+    pub doc_comment: Option<syn::Attribute>,
+    /// Unprefixed name of an additional setter fn delegating to this one.
+    pub bare_ident: Option<syn::Ident>,
+    /// Names of the `try_add_` and `saturating_add_` methods adding to an integer field, see
+    /// `#[builder(setter(checked_add))]`.
+    pub add_idents: Option<(syn::Ident, syn::Ident)>,
+    /// Name of the `with_` method modifying the field in place, and the value it initializes an
+    /// unset field with, see `#[builder(setter(with_mut))]`.
+    pub with_mut: Option<(syn::Ident, TokenStream)>,
+    /// Name of an additional setter taking the `Option` of an `Option<T>` field as is, so that
+    /// passing `None` clears the field, see `#[builder(setter(option_setter))]`.
+    pub option_ident: Option<syn::Ident>,
+    /// Take `&mut self` and return the previous value of the builder field instead of the builder.
+    pub return_previous: bool,
+    /// Normalize the converted `String` value before storing it.
+    pub normalize: Option<&'a Normalize>,
+    /// Validation run on the converted value before storing it, and the error type of the
+    /// setter, which must be convertible from the validation error.
+    pub validate: Option<(&'a syn::Expr, syn::Path)>,
+    /// Make the setter generic over `TryInto<T>`, returning a `Result` with the conversion
+    /// error instead of the builder.
+    pub try_into: bool,
+    /// Name of the type parameter of generic setters, e.g. `VALUE`.
+    pub value_param: syn::Ident,
+    /// Name of the type parameter converted into `value_param` by `each(into)` setters, e.g.
+    /// `FROM_VALUE`.
+    pub from_value_param: syn::Ident,
 }
 
 impl<'a> ToTokens for Setter<'a> {
@@ -77,146 +131,477 @@ impl<'a> ToTokens for Setter<'a> {
             let vis = &self.visibility;
             let field_ident = self.field_ident;
             let ident = &self.ident;
+            let value_param = &self.value_param;
+            let from_value_param = &self.from_value_param;
+            let doc_comment = &self.doc_comment;
             let attrs = self.attrs;
             let deprecation_notes = self.deprecation_notes;
-
-            let self_param: TokenStream;
-            let return_ty: TokenStream;
-            let self_into_return_ty: TokenStream;
-
-            match pattern {
-                BuilderPattern::Owned => {
-                    self_param = quote!(self);
-                    return_ty = quote!(Self);
-                    self_into_return_ty = quote!(self);
-                }
-                BuilderPattern::Mutable => {
-                    self_param = quote!(&mut self);
-                    return_ty = quote!(&mut Self);
-                    self_into_return_ty = quote!(self);
-                }
-                BuilderPattern::Immutable => {
-                    self_param = quote!(&self);
-                    return_ty = quote!(Self);
-                    self_into_return_ty =
-                        quote!(::derive_builder::export::core::clone::Clone::clone(self));
-                }
-            };
-
-            let ty_params: TokenStream;
-            let param_ty: TokenStream;
-            let mut into_value: TokenStream;
-
-            let (field_type, builder_field_is_option) = self.field_type.setter_type_info();
-
-            let (ty, stripped_option) = {
-                if self.strip_option {
-                    match extract_type_from_option(field_type) {
-                        Some(ty) => (ty, true),
-                        None => (field_type, false),
-                    }
-                } else {
-                    (field_type, false)
-                }
+            let field_name = field_ident.unraw().to_string();
+            let clear_build_cache = if self.clear_build_cache {
+                let cache = syn::Ident::new(BUILD_CACHE_FIELD_NAME, Span::call_site());
+                quote!(new.#cache = ::derive_builder::export::core::option::Option::None;)
+            } else {
+                quote!()
             };
 
-            if self.generic_into {
-                ty_params = quote!(<VALUE: ::derive_builder::export::core::convert::Into<#ty>>);
-                param_ty = quote!(VALUE);
-                into_value = quote!(value.into());
-            } else {
-                ty_params = quote!();
-                param_ty = quote!(#ty);
-                into_value = quote!(value);
+            let (self_param, return_ty, self_into_return_ty) = receiver(pattern);
+            let (field_type, builder_field_is_option, ty, stripped_option) = self.value_types();
+            let (ty_params, param_ty, mut into_value) = self.parameter(ty);
+            if let Some(normalize) = self.normalize {
+                into_value = normalize_expression(normalize, into_value);
             }
+            let converted = into_value.clone();
+            let mut stored_value = quote!(value);
             // If both `stripped_option` and `builder_field_is_option`, the target field is `Option<field_type>`,
             // the builder field is `Option<Option<field_type>>`, and the setter takes `file_type`, so we must wrap it twice.
             if stripped_option {
                 into_value = wrap_expression_in_some(into_value);
+                stored_value = wrap_expression_in_some(stored_value);
             }
+            into_value = box_if_lazy(&self.field_type, field_type, into_value);
+            stored_value = box_if_lazy(&self.field_type, field_type, stored_value);
+            let replace_previous = if builder_field_is_option {
+                quote!(::derive_builder::export::core::option::Option::replace(&mut new.#field_ident, #into_value))
+            } else {
+                quote!(::derive_builder::export::core::mem::replace(&mut new.#field_ident, #into_value))
+            };
             if builder_field_is_option {
                 into_value = wrap_expression_in_some(into_value);
+                stored_value = wrap_expression_in_some(stored_value);
             }
 
-            tokens.append_all(quote!(
-                #(#attrs)*
-                #[allow(unused_mut)]
-                #vis fn #ident #ty_params (#self_param, value: #param_ty)
-                    -> #return_ty
-                {
-                    #deprecation_notes
-                    let mut new = #self_into_return_ty;
-                    new.#field_ident = #into_value;
-                    new
+            let already_set = quote!(::derive_builder::AlreadySetError::from(#field_name));
+            let setter_return_ty = if self.return_previous {
+                if builder_field_is_option {
+                    quote!(::derive_builder::export::core::option::Option<#field_type>)
+                } else {
+                    quote!(#field_type)
                 }
-            ));
+            } else if self.once == Some(SetOnce::Error) {
+                quote!(::derive_builder::export::core::result::Result<#return_ty, ::derive_builder::AlreadySetError>)
+            } else if let Some((_, ref error_ty)) = self.validate {
+                quote!(::derive_builder::export::core::result::Result<#return_ty, #error_ty>)
+            } else if self.try_into {
+                quote!(::derive_builder::export::core::result::Result<#return_ty, #value_param::Error>)
+            } else {
+                quote!(#return_ty)
+            };
+            match self.once {
+                _ if self.return_previous => tokens.append_all(quote!(
+                    #doc_comment
+                    #(#attrs)*
+                    #vis fn #ident #ty_params (&mut self, value: #param_ty)
+                        -> #setter_return_ty
+                    {
+                        #deprecation_notes
+                        let new = self;
+                        #clear_build_cache
+                        #replace_previous
+                    }
+                )),
+                _ if self.validate.is_some() => {
+                    let validate = self.validate.as_ref().map(|&(validate, _)| validate);
+                    tokens.append_all(quote!(
+                        #doc_comment
+                        #(#attrs)*
+                        #[allow(unused_mut)]
+                        #vis fn #ident #ty_params (#self_param, value: #param_ty)
+                            -> #setter_return_ty
+                        {
+                            #deprecation_notes
+                            let value: #ty = #converted;
+                            (#validate)(&value)?;
+                            let mut new = #self_into_return_ty;
+                            new.#field_ident = #stored_value;
+                            #clear_build_cache
+                            ::derive_builder::export::core::result::Result::Ok(new)
+                        }
+                    ))
+                }
+                _ if self.try_into => tokens.append_all(quote!(
+                    #doc_comment
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #ident #ty_params (#self_param, value: #param_ty)
+                        -> #setter_return_ty
+                    {
+                        #deprecation_notes
+                        let value: #ty = #converted;
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #stored_value;
+                        #clear_build_cache
+                        ::derive_builder::export::core::result::Result::Ok(new)
+                    }
+                )),
+                Some(SetOnce::Error) => tokens.append_all(quote!(
+                    #doc_comment
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #ident #ty_params (#self_param, value: #param_ty)
+                        -> #setter_return_ty
+                    {
+                        #deprecation_notes
+                        if self.#field_ident.is_some() {
+                            return ::derive_builder::export::core::result::Result::Err(#already_set);
+                        }
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #into_value;
+                        #clear_build_cache
+                        ::derive_builder::export::core::result::Result::Ok(new)
+                    }
+                )),
+                Some(SetOnce::Panic) => tokens.append_all(quote!(
+                    #doc_comment
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #ident #ty_params (#self_param, value: #param_ty)
+                        -> #return_ty
+                    {
+                        #deprecation_notes
+                        if self.#field_ident.is_some() {
+                            ::derive_builder::export::core::panic!("{}", #already_set);
+                        }
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #into_value;
+                        #clear_build_cache
+                        new
+                    }
+                )),
+                None => tokens.append_all(quote!(
+                    #doc_comment
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #ident #ty_params (#self_param, value: #param_ty)
+                        -> #return_ty
+                    {
+                        #deprecation_notes
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #into_value;
+                        #clear_build_cache
+                        new
+                    }
+                )),
+            }
+
+            if let Some(ref bare_ident) = self.bare_ident {
+                tokens.append_all(quote!(
+                    #doc_comment
+                    #(#attrs)*
+                    #vis fn #bare_ident #ty_params (#self_param, value: #param_ty)
+                        -> #setter_return_ty
+                    {
+                        self.#ident(value)
+                    }
+                ));
+            }
 
-            if self.try_setter {
+            // A setter returning a validation error has no room for the conversion error, and a
+            // `try_into` setter already converts with `TryInto`, so `try_` variants are only
+            // emitted for other setters. `setter(once)` with `try_setter` is rejected when parsing
+            // the options.
+            if self.try_setter
+                && self.once != Some(SetOnce::Error)
+                && self.validate.is_none()
+                && !self.try_into
+            {
                 let try_ty_params =
-                    quote!(<VALUE: ::derive_builder::export::core::convert::TryInto<#ty>>);
-                let try_ident = syn::Ident::new(&format!("try_{}", ident), Span::call_site());
+                    quote!(<#value_param: ::derive_builder::export::core::convert::TryInto<#ty>>);
+                let try_ident = format_ident!("try_{}", ident, span = Span::call_site());
+
+                let panic_if_set = if self.once == Some(SetOnce::Panic) {
+                    quote!(if self.#field_ident.is_some() {
+                        ::derive_builder::export::core::panic!("{}", #already_set);
+                    })
+                } else {
+                    quote!()
+                };
 
                 let mut converted = quote! {converted};
+                if let Some(normalize) = self.normalize {
+                    converted = normalize_expression(normalize, converted);
+                }
+                converted = box_if_lazy(&self.field_type, field_type, converted);
                 if builder_field_is_option {
                     converted = wrap_expression_in_some(converted);
                 }
 
                 tokens.append_all(quote!(
+                    #doc_comment
                     #(#attrs)*
-                    #vis fn #try_ident #try_ty_params (#self_param, value: VALUE)
-                        -> ::derive_builder::export::core::result::Result<#return_ty, VALUE::Error>
+                    #vis fn #try_ident #try_ty_params (#self_param, value: #value_param)
+                        -> ::derive_builder::export::core::result::Result<#return_ty, #value_param::Error>
                     {
-                        let converted : #ty = value.try_into()?;
+                        let converted: #ty =
+                            ::derive_builder::export::core::convert::TryInto::try_into(value)?;
+                        #panic_if_set
                         let mut new = #self_into_return_ty;
                         new.#field_ident = #converted;
-                        Ok(new)
+                        #clear_build_cache
+                        ::derive_builder::export::core::result::Result::Ok(new)
+                    }
+                ));
+            }
+
+            if let Some((ref try_add, ref saturating_add)) = self.add_idents {
+                // An unset field is set to `delta`, as if it was zero.
+                let add = |sum: TokenStream| {
+                    if builder_field_is_option {
+                        quote!(
+                            let value = match new.#field_ident {
+                                ::derive_builder::export::core::option::Option::Some(current) => #sum,
+                                ::derive_builder::export::core::option::Option::None => delta,
+                            };
+                            new.#field_ident = ::derive_builder::export::core::option::Option::Some(value);
+                        )
+                    } else {
+                        quote!(
+                            let current = new.#field_ident;
+                            new.#field_ident = #sum;
+                        )
+                    }
+                };
+                let add_checked = add(quote!(
+                    match current.checked_add(delta) {
+                        ::derive_builder::export::core::option::Option::Some(sum) => sum,
+                        ::derive_builder::export::core::option::Option::None => {
+                            return ::derive_builder::export::core::result::Result::Err(
+                                ::derive_builder::OverflowError::from(#field_name)
+                            );
+                        }
+                    }
+                ));
+                let add_saturating = add(quote!(current.saturating_add(delta)));
+                let summary = if builder_field_is_option {
+                    format!(
+                        "Add `delta` to `{}`, or set it to `delta` if it is unset.",
+                        field_name
+                    )
+                } else {
+                    format!("Add `delta` to `{}`.", field_name)
+                };
+                let try_add_doc = format!(
+                    "{}\n\nFails without changing the builder if the sum overflows.",
+                    summary
+                );
+                let saturating_add_doc = format!(
+                    "{}\n\nStops at the numeric bounds instead of overflowing.",
+                    summary
+                );
+
+                tokens.append_all(quote!(
+                    #[doc = #try_add_doc]
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #try_add(#self_param, delta: #ty)
+                        -> ::derive_builder::export::core::result::Result<#return_ty, ::derive_builder::OverflowError>
+                    {
+                        #deprecation_notes
+                        let mut new = #self_into_return_ty;
+                        #add_checked
+                        #clear_build_cache
+                        ::derive_builder::export::core::result::Result::Ok(new)
+                    }
+
+                    #[doc = #saturating_add_doc]
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #saturating_add(#self_param, delta: #ty) -> #return_ty {
+                        #deprecation_notes
+                        let mut new = #self_into_return_ty;
+                        #add_saturating
+                        #clear_build_cache
+                        new
+                    }
+                ));
+            }
+
+            if let Some((ref with_mut, ref default)) = self.with_mut {
+                let doc = format!(
+                    "Modify `{}` in place with `f`, starting from its default value if it is unset.",
+                    field_name
+                );
+
+                tokens.append_all(quote!(
+                    #[doc = #doc]
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #with_mut(
+                        #self_param,
+                        f: impl ::derive_builder::export::core::ops::FnOnce(&mut #field_type)
+                    ) -> #return_ty {
+                        #deprecation_notes
+                        let mut new = #self_into_return_ty;
+                        f(new.#field_ident.get_or_insert_with(|| #default));
+                        #clear_build_cache
+                        new
                     }
                 ));
             }
 
-            if let Some(each) = self.each {
-                let ident_each = &each.name;
+            if let Some(ref option_ident) = self.option_ident {
+                let doc = format!(
+                    "Set `{}` to `value` as is, so that `None` clears it.",
+                    field_name
+                );
+                let value = if builder_field_is_option {
+                    wrap_expression_in_some(quote!(value))
+                } else {
+                    quote!(value)
+                };
+
+                tokens.append_all(quote!(
+                    #[doc = #doc]
+                    #(#attrs)*
+                    #[allow(unused_mut)]
+                    #vis fn #option_ident(#self_param, value: #field_type) -> #return_ty {
+                        #deprecation_notes
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #value;
+                        #clear_build_cache
+                        new
+                    }
+                ));
+            }
 
+            let each_name = self
+                .each
+                .and_then(|each| each.name.as_ref().map(|name| (each, name)));
+            if let Some((each, ident_each)) = each_name {
                 // Access the collection to extend, initialising with default value if necessary.
-                let get_initialized_collection = if stripped_option {
+                let initialized_collection = match (builder_field_is_option, stripped_option) {
                     // Outer (builder) Option -> Inner (field) Option -> collection.
-                    quote!(get_or_insert_with(|| Some(
+                    (true, true) => {
+                        quote!(new.#field_ident.get_or_insert_with(|| ::derive_builder::export::core::option::Option::Some(
                         ::derive_builder::export::core::default::Default::default()
                     ))
                     .get_or_insert_with(::derive_builder::export::core::default::Default::default))
-                } else {
-                    // Outer (builder) Option -> collection.
-                    quote!(get_or_insert_with(
+                    }
+                    // One Option, of either the builder or the field -> collection.
+                    (true, false) | (false, true) => quote!(new.#field_ident.get_or_insert_with(
                         ::derive_builder::export::core::default::Default::default
-                    ))
+                    )),
+                    // The builder field is the collection itself.
+                    (false, false) => quote!(new.#field_ident),
                 };
 
-                let ty_params: TokenStream;
-                let param_ty: TokenStream;
-                let into_item: TokenStream;
+                let dedup_item_ty = each
+                    .dedup
+                    .as_ref()
+                    .and_then(|dedup| extract_type_from_vec(ty).map(|item_ty| (dedup, item_ty)));
 
-                if each.into {
-                    ty_params = quote!(<VALUE, FROM_VALUE: ::derive_builder::export::core::convert::Into<VALUE>>);
-                    param_ty = quote!(FROM_VALUE);
-                    into_item = quote!(::derive_builder::export::core::convert::Into::into(item));
+                if let Some((dedup, item_ty)) = dedup_item_ty {
+                    // The element type is known, so the setter is not generic over it.
+                    let (ty_params, param_ty, into_item) = if each.into {
+                        (
+                            quote!(<#from_value_param: ::derive_builder::export::core::convert::Into<#item_ty>>),
+                            quote!(#from_value_param),
+                            quote!(::derive_builder::export::core::convert::Into::into(item)),
+                        )
+                    } else {
+                        (quote!(), quote!(#item_ty), quote!(item))
+                    };
+                    let (where_clause, is_present) = match dedup {
+                        EachDedup::Eq => (
+                            quote!(where #item_ty: ::derive_builder::export::core::cmp::PartialEq),
+                            quote!(items.contains(&item)),
+                        ),
+                        EachDedup::Key(key) => (
+                            quote!(),
+                            quote!(items.iter().any(|x| #key(x) == #key(&item))),
+                        ),
+                    };
+                    let items = if builder_field_is_option || stripped_option {
+                        initialized_collection
+                    } else {
+                        quote!(&mut new.#field_ident)
+                    };
+
+                    tokens.append_all(quote!(
+                        #doc_comment
+                        #(#attrs)*
+                        #[allow(unused_mut)]
+                        #vis fn #ident_each #ty_params(#self_param, item: #param_ty) -> #return_ty
+                        #where_clause
+                        {
+                            #deprecation_notes
+                            let mut new = #self_into_return_ty;
+                            let item: #item_ty = #into_item;
+                            let items = #items;
+                            if !#is_present {
+                                items.push(item);
+                            }
+                            #clear_build_cache
+                            new
+                        }
+                    ));
                 } else {
-                    ty_params = quote!(<VALUE>);
-                    param_ty = quote!(VALUE);
-                    into_item = quote!(item);
+                    let ty_params: TokenStream;
+                    let param_ty: TokenStream;
+                    let into_item: TokenStream;
+
+                    if each.into {
+                        ty_params = quote!(<#value_param, #from_value_param: ::derive_builder::export::core::convert::Into<#value_param>>);
+                        param_ty = quote!(#from_value_param);
+                        into_item =
+                            quote!(::derive_builder::export::core::convert::Into::into(item));
+                    } else {
+                        ty_params = quote!(<#value_param>);
+                        param_ty = quote!(#value_param);
+                        into_item = quote!(item);
+                    }
+
+                    tokens.append_all(quote!(
+                        #doc_comment
+                        #(#attrs)*
+                        #[allow(unused_mut)]
+                        #vis fn #ident_each #ty_params(#self_param, item: #param_ty) -> #return_ty
+                        where
+                            #ty: ::derive_builder::export::core::default::Default + ::derive_builder::export::core::iter::Extend<#value_param>,
+                        {
+                            #deprecation_notes
+                            let mut new = #self_into_return_ty;
+                            #initialized_collection
+                                .extend(::derive_builder::export::core::option::Option::Some(#into_item));
+                            #clear_build_cache
+                            new
+                        }
+                    ));
                 }
+            }
+
+            let each_remove = self.each.and_then(|each| each.remove.as_ref());
+            if let (Some(ident_remove), Some(item_ty)) = (each_remove, extract_type_from_vec(ty)) {
+                let some = quote!(::derive_builder::export::core::option::Option::Some);
+                let retain = quote!(items.retain(|x| x != item););
+                let remove_items = match (builder_field_is_option, stripped_option) {
+                    (true, true) => quote!(
+                        if let #some(#some(ref mut items)) = new.#field_ident {
+                            #retain
+                        }
+                    ),
+                    (true, false) | (false, true) => quote!(
+                        if let #some(ref mut items) = new.#field_ident {
+                            #retain
+                        }
+                    ),
+                    (false, false) => quote!(
+                        let items = &mut new.#field_ident;
+                        #retain
+                    ),
+                };
 
                 tokens.append_all(quote!(
+                    #doc_comment
                     #(#attrs)*
                     #[allow(unused_mut)]
-                    #vis fn #ident_each #ty_params(#self_param, item: #param_ty) -> #return_ty
+                    #vis fn #ident_remove(#self_param, item: &#item_ty) -> #return_ty
                     where
-                        #ty: ::derive_builder::export::core::default::Default + ::derive_builder::export::core::iter::Extend<VALUE>,
+                        #item_ty: ::derive_builder::export::core::cmp::PartialEq,
                     {
                         #deprecation_notes
                         let mut new = #self_into_return_ty;
-                        new.#field_ident
-                            .#get_initialized_collection
-                            .extend(::derive_builder::export::core::option::Option::Some(#into_item));
+                        #remove_items
+                        #clear_build_cache
                         new
                     }
                 ));
@@ -225,17 +610,307 @@ impl<'a> ToTokens for Setter<'a> {
     }
 }
 
+impl<'a> Setter<'a> {
+    /// The types involved in setting the builder field:
+    ///
+    ///  * `.0`: type of the builder field, without its `Option` wrapper
+    ///  * `.1`: whether the builder field is `Option<type>` rather than just `type`
+    ///  * `.2`: type of the value passed to the setter, without `strip_option`'s `Option`
+    ///  * `.3`: whether `strip_option` removed an `Option` from the builder field type
+    fn value_types(&self) -> (&'a syn::Type, bool, &'a syn::Type, bool) {
+        let (field_type, builder_field_is_option) = self.field_type.setter_type_info();
+        if self.strip_option {
+            if let Some(ty) = extract_type_from_option(field_type) {
+                return (field_type, builder_field_is_option, ty, true);
+            }
+        }
+
+        (field_type, builder_field_is_option, field_type, false)
+    }
+
+    /// The inner type and the constructor of the newtype `ty` for `setter(newtype_inner)`.
+    fn newtype(&self, ty: &syn::Type) -> Option<(TokenStream, TokenStream)> {
+        match self.newtype_inner? {
+            NewtypeInner::Derived => Some((
+                quote!(<#ty as ::derive_builder::Newtype>::Inner),
+                quote!(<#ty as ::derive_builder::Newtype>::from_inner),
+            )),
+            NewtypeInner::Explicit(inner) => {
+                newtype_constructor(ty).map(|ctor| (quote!(#inner), quote!(#ctor)))
+            }
+        }
+    }
+
+    /// The type parameters and parameter type of the setter, and the expression converting
+    /// the parameter `value` to `ty`.
+    fn parameter(&self, ty: &syn::Type) -> (TokenStream, TokenStream, TokenStream) {
+        let ty_params: TokenStream;
+        let param_ty: TokenStream;
+        let into_value: TokenStream;
+        let value_param = &self.value_param;
+
+        let newtype = self.newtype(ty);
+
+        let pointee = if self.boxed {
+            extract_type_from_smart_pointer(ty)
+        } else {
+            None
+        };
+
+        let item = if self.into_iter {
+            extract_type_from_collection(ty)
+        } else {
+            None
+        };
+
+        if let Some((transform, param)) = self.transform {
+            ty_params = quote!();
+            param_ty = quote!(#param);
+            into_value = quote!((#transform)(value));
+        } else if let Some(item) = item {
+            let from_value_param = &self.from_value_param;
+            ty_params = quote!(<
+                #value_param: ::derive_builder::export::core::iter::IntoIterator<Item = #from_value_param>,
+                #from_value_param: ::derive_builder::export::core::convert::Into<#item>
+            >);
+            param_ty = quote!(#value_param);
+            into_value = quote!(::derive_builder::export::core::iter::Iterator::collect(
+                ::derive_builder::export::core::iter::Iterator::map(
+                    ::derive_builder::export::core::iter::IntoIterator::into_iter(value),
+                    ::derive_builder::export::core::convert::Into::into
+                )
+            ));
+        } else if let Some(pointee) = pointee {
+            ty_params =
+                quote!(<#value_param: ::derive_builder::export::core::convert::Into<#pointee>>);
+            param_ty = quote!(#value_param);
+            into_value =
+                quote!(<#ty>::new(::derive_builder::export::core::convert::Into::into(value)));
+        } else if let Some((inner, ctor)) = newtype {
+            ty_params =
+                quote!(<#value_param: ::derive_builder::export::core::convert::Into<#inner>>);
+            param_ty = quote!(#value_param);
+            into_value = quote!(#ctor(::derive_builder::export::core::convert::Into::into(value)));
+        } else if self.as_ref {
+            ty_params = quote!(<#value_param: ::derive_builder::export::core::convert::AsRef<str>>);
+            param_ty = quote!(#value_param);
+            into_value = quote!(::derive_builder::export::core::string::String::from(
+                ::derive_builder::export::core::convert::AsRef::<str>::as_ref(&value)
+            ));
+        } else if self.try_into {
+            ty_params =
+                quote!(<#value_param: ::derive_builder::export::core::convert::TryInto<#ty>>);
+            param_ty = quote!(#value_param);
+            into_value = quote!(::derive_builder::export::core::convert::TryInto::try_into(
+                value
+            )?);
+        } else if self.generic_into {
+            let value_ty = self
+                .type_alias
+                .map_or_else(|| quote!(#ty), |alias| quote!(#alias));
+            ty_params =
+                quote!(<#value_param: ::derive_builder::export::core::convert::Into<#value_ty>>);
+            param_ty = quote!(#value_param);
+            into_value = quote!(value.into());
+        } else {
+            ty_params = quote!();
+            param_ty = self
+                .type_alias
+                .map_or_else(|| quote!(#ty), |alias| quote!(#alias));
+            into_value = quote!(value);
+        }
+
+        (ty_params, param_ty, into_value)
+    }
+
+    /// A method forwarding to this setter on the builder field `$field` of another builder,
+    /// with the receiver of `pattern`, see `#[builder(inherits = "...")]`.
+    ///
+    /// Setters which do not return the builder are not forwarded.
+    pub fn forwarder(&self, pattern: BuilderPattern) -> Option<TokenStream> {
+        if !self.setter_enabled
+            || self.return_previous
+            || self.once == Some(SetOnce::Error)
+            || self.validate.is_some()
+            || self.try_into
+        {
+            return None;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let doc_comment = &self.doc_comment;
+        let (self_param, return_ty, self_into_return_ty) = receiver(pattern);
+        let (_, _, ty, _) = self.value_types();
+        let (ty_params, param_ty, _) = self.parameter(ty);
+        let set = match self.pattern {
+            BuilderPattern::Mutable => quote!(new.$field.#ident(value);),
+            BuilderPattern::Immutable => quote!(new.$field = new.$field.#ident(value);),
+            BuilderPattern::Owned => quote!(
+                new.$field = ::derive_builder::export::core::mem::take(&mut new.$field).#ident(value);
+            ),
+        };
+
+        Some(quote!(
+            #doc_comment
+            #[allow(unused_mut)]
+            #vis fn #ident #ty_params (#self_param, value: #param_ty) -> #return_ty {
+                let mut new = #self_into_return_ty;
+                #set
+                new
+            }
+        ))
+    }
+
+    /// The type of the values passed to this setter by the `Extend` impl of
+    /// `#[builder(impl_extend)]` and the `From` impl of `#[builder(impl_from_parts)]`, i.e. the
+    /// type the setter converts its parameter into.
+    ///
+    /// Setters which can fail are not called by either.
+    pub fn extend_value_type(&self) -> Option<TokenStream> {
+        if !self.setter_enabled
+            || self.once == Some(SetOnce::Error)
+            || self.validate.is_some()
+            || self.try_into
+        {
+            return None;
+        }
+
+        let (_, _, ty, _) = self.value_types();
+        let pointee = if self.boxed {
+            extract_type_from_smart_pointer(ty)
+        } else {
+            None
+        };
+        let newtype_inner = self.newtype(ty).map(|(inner, _)| inner);
+
+        if let Some((_, param)) = self.transform {
+            return Some(quote!(#param));
+        }
+
+        Some(match (pointee, newtype_inner, self.type_alias) {
+            (Some(pointee), _, _) => quote!(#pointee),
+            (None, Some(inner), _) => quote!(#inner),
+            (None, None, Some(alias)) => quote!(#alias),
+            (None, None, None) => quote!(#ty),
+        })
+    }
+}
+
+/// How a setter of a builder with `pattern` takes `self`, what it returns, and the expression
+/// producing the builder it returns.
+fn receiver(pattern: BuilderPattern) -> (TokenStream, TokenStream, TokenStream) {
+    match pattern {
+        BuilderPattern::Owned => (quote!(self), quote!(Self), quote!(self)),
+        BuilderPattern::Mutable => (quote!(&mut self), quote!(&mut Self), quote!(self)),
+        BuilderPattern::Immutable => (
+            quote!(&self),
+            quote!(Self),
+            quote!(::derive_builder::export::core::clone::Clone::clone(self)),
+        ),
+    }
+}
+
 /// Returns expression wrapping `bare_value` in `Some`
 fn wrap_expression_in_some(bare_value: impl ToTokens) -> TokenStream {
     quote!( ::derive_builder::export::core::option::Option::Some(#bare_value) )
 }
 
+/// Returns expression boxing a closure which returns the `ty` produced by `value`, if the
+/// builder field is `BuilderFieldType::Lazy`
+fn box_if_lazy(field_type: &BuilderFieldType, ty: &syn::Type, value: TokenStream) -> TokenStream {
+    match field_type {
+        BuilderFieldType::Lazy(_) => quote!(::derive_builder::export::core::boxed::Box::new({
+            let value: #ty = #value;
+            move || value
+        })),
+        _ => value,
+    }
+}
+
+/// Returns expression normalizing the `String` produced by `value`
+fn normalize_expression(normalize: &Normalize, value: TokenStream) -> TokenStream {
+    let normalized = match normalize {
+        Normalize::Trim => quote!(::derive_builder::export::core::string::String::from(
+            value.trim()
+        )),
+        Normalize::Lowercase => quote!(value.to_lowercase()),
+        Normalize::Uppercase => quote!(value.to_uppercase()),
+        Normalize::Custom(path) => quote!(#path(&value)),
+    };
+    quote!({
+        let value: ::derive_builder::export::core::string::String = #value;
+        #normalized
+    })
+}
+
+/// Get the path which constructs a value of the tuple newtype `ty`, i.e. its type path
+/// without generic arguments.
+pub(crate) fn newtype_constructor(ty: &syn::Type) -> Option<syn::Path> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            let mut path = path.clone();
+            if let Some(last) = path.segments.last_mut() {
+                last.arguments = syn::PathArguments::None;
+            }
+            Some(path)
+        }
+        _ => None,
+    }
+}
+
+/// Get the element type of `ty` if it is spelled as `Vec<T>`, with or without a path prefix.
+pub(crate) fn extract_type_from_vec(ty: &syn::Type) -> Option<&syn::Type> {
+    extract_type_argument(ty, &["Vec"])
+}
+
+/// Get the pointee type of `ty` if it is spelled as `Box<T>`, `Rc<T>` or `Arc<T>`, with or
+/// without a path prefix.
+pub(crate) fn extract_type_from_smart_pointer(ty: &syn::Type) -> Option<&syn::Type> {
+    extract_type_argument(ty, &["Box", "Rc", "Arc"])
+}
+
+/// Get the item type of `ty` if it is spelled as a collection with a single type argument, such
+/// as `Vec<T>` or `BTreeSet<T>`.
+pub(crate) fn extract_type_from_collection(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    only_type_argument(segment)
+}
+
+/// Get the only type argument of `ty` if its last path segment is one of `idents`.
+fn extract_type_argument<'a>(ty: &'a syn::Type, idents: &[&str]) -> Option<&'a syn::Type> {
+    let segment = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    if !idents.iter().any(|ident| segment.ident == ident) {
+        return None;
+    }
+    only_type_argument(segment)
+}
+
+/// Get the only generic argument of `segment` if it is a type.
+fn only_type_argument(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(params) if params.args.len() == 1 => {
+            match params.args.first() {
+                Some(syn::GenericArgument::Type(ty)) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 // adapted from https://stackoverflow.com/a/55277337/469066
 // Note that since syn is a parser, it works with tokens.
 // We cannot know for sure that this is an Option.
 // The user could, for example, `type MaybeString = std::option::Option<String>`
 // We cannot handle those arbitrary names.
-fn extract_type_from_option(ty: &syn::Type) -> Option<&syn::Type> {
+pub(crate) fn extract_type_from_option(ty: &syn::Type) -> Option<&syn::Type> {
     use syn::punctuated::Pair;
     use syn::token::Colon2;
     use syn::{GenericArgument, Path, PathArguments, PathSegment};
@@ -288,7 +963,7 @@ macro_rules! default_setter {
             try_setter: false,
             visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
             pattern: BuilderPattern::Mutable,
-            attrs: &vec![],
+            attrs: &[],
             ident: syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
             field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
             field_type: BuilderFieldType::Optional(Box::leak(Box::new(parse_quote!(Foo)))),
@@ -296,6 +971,25 @@ macro_rules! default_setter {
             strip_option: false,
             deprecation_notes: &Default::default(),
             each: None,
+            clear_build_cache: false,
+            once: None,
+            as_ref: false,
+            newtype_inner: None,
+            boxed: false,
+            into_iter: false,
+            transform: None,
+            type_alias: None,
+            doc_comment: None,
+            bare_ident: None,
+            add_idents: None,
+            with_mut: None,
+            option_ident: None,
+            return_previous: false,
+            normalize: None,
+            validate: None,
+            try_into: false,
+            value_param: syn::Ident::new("VALUE", ::proc_macro2::Span::call_site()),
+            from_value_param: syn::Ident::new("FROM_VALUE", ::proc_macro2::Span::call_site()),
         }
     };
 }
@@ -344,39 +1038,41 @@ mod tests {
     }
 
     #[test]
-    fn owned() {
+    fn bare_alias() {
         let mut setter = default_setter!();
-        setter.pattern = BuilderPattern::Owned;
+        setter.ident = syn::Ident::new("with_foo", ::proc_macro2::Span::call_site());
+        setter.bare_ident = Some(syn::Ident::new("foo", ::proc_macro2::Span::call_site()));
 
         assert_eq!(
             quote!(#setter).to_string(),
             quote!(
                 #[allow(unused_mut)]
-                pub fn foo(self, value: Foo) -> Self {
+                pub fn with_foo(&mut self, value: Foo) -> &mut Self {
                     let mut new = self;
                     new.foo = ::derive_builder::export::core::option::Option::Some(value);
                     new
                 }
+
+                pub fn foo(&mut self, value: Foo) -> &mut Self {
+                    self.with_foo(value)
+                }
             )
             .to_string()
         );
     }
 
     #[test]
-    fn private() {
-        let vis = Cow::Owned(syn::Visibility::Inherited);
-
+    fn return_previous() {
         let mut setter = default_setter!();
-        setter.visibility = vis;
+        setter.return_previous = true;
 
+        #[rustfmt::skip]
         assert_eq!(
             quote!(#setter).to_string(),
             quote!(
-                #[allow(unused_mut)]
-                fn foo(&mut self, value: Foo) -> &mut Self {
-                    let mut new = self;
-                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
-                    new
+                pub fn foo(&mut self, value: Foo) -> ::derive_builder::export::core::option::Option<Foo> {
+                    let new = self;
+                    ::derive_builder::export::core::option::Option::replace(&mut new.foo, value)
                 }
             )
             .to_string()
@@ -384,21 +1080,27 @@ mod tests {
     }
 
     #[test]
-    fn generic() {
+    fn normalize() {
+        let normalize = Normalize::Trim;
         let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(Box::leak(Box::new(parse_quote!(String))));
         setter.generic_into = true;
+        setter.normalize = Some(&normalize);
 
         #[rustfmt::skip]
         assert_eq!(
             quote!(#setter).to_string(),
             quote!(
                 #[allow(unused_mut)]
-                pub fn foo<VALUE: ::derive_builder::export::core::convert::Into<Foo>>(
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::Into<String>>(
                     &mut self,
                     value: VALUE
                 ) -> &mut Self {
                     let mut new = self;
-                    new.foo = ::derive_builder::export::core::option::Option::Some(value.into());
+                    new.foo = ::derive_builder::export::core::option::Option::Some({
+                        let value: ::derive_builder::export::core::string::String = value.into();
+                        ::derive_builder::export::core::string::String::from(value.trim())
+                    });
                     new
                 }
             )
@@ -407,21 +1109,561 @@ mod tests {
     }
 
     #[test]
-    fn strip_option() {
-        let ty = parse_quote!(Option<Foo>);
+    fn checked_add() {
         let mut setter = default_setter!();
-        setter.strip_option = true;
-        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.field_type = BuilderFieldType::Precise(Box::leak(Box::new(parse_quote!(u8))));
+        setter.pattern = BuilderPattern::Owned;
+        setter.add_idents = Some((
+            syn::Ident::new("try_add_foo", Span::call_site()),
+            syn::Ident::new("saturating_add_foo", Span::call_site()),
+        ));
 
         #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(self, value: u8) -> Self {
+                    let mut new = self;
+                    new.foo = value;
+                    new
+                }
+
+                #[doc = "Add `delta` to `foo`.\n\nFails without changing the builder if the sum overflows."]
+                #[allow(unused_mut)]
+                pub fn try_add_foo(self, delta: u8)
+                    -> ::derive_builder::export::core::result::Result<Self, ::derive_builder::OverflowError>
+                {
+                    let mut new = self;
+                    let current = new.foo;
+                    new.foo = match current.checked_add(delta) {
+                        ::derive_builder::export::core::option::Option::Some(sum) => sum,
+                        ::derive_builder::export::core::option::Option::None => {
+                            return ::derive_builder::export::core::result::Result::Err(
+                                ::derive_builder::OverflowError::from("foo")
+                            );
+                        }
+                    };
+                    ::derive_builder::export::core::result::Result::Ok(new)
+                }
+
+                #[doc = "Add `delta` to `foo`.\n\nStops at the numeric bounds instead of overflowing."]
+                #[allow(unused_mut)]
+                pub fn saturating_add_foo(self, delta: u8) -> Self {
+                    let mut new = self;
+                    let current = new.foo;
+                    new.foo = current.saturating_add(delta);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn clear_build_cache() {
+        let mut setter = default_setter!();
+        setter.clear_build_cache = true;
+
         assert_eq!(
             quote!(#setter).to_string(),
             quote!(
                 #[allow(unused_mut)]
                 pub fn foo(&mut self, value: Foo) -> &mut Self {
                     let mut new = self;
-                    new.foo = ::derive_builder::export::core::option::Option::Some(
-                        ::derive_builder::export::core::option::Option::Some(value)
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new.__build_cache = ::derive_builder::export::core::option::Option::None;
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn as_ref() {
+        let mut setter = default_setter!();
+        setter.as_ref = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::AsRef<str>>(
+                    &mut self,
+                    value: VALUE
+                ) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::string::String::from(
+                            ::derive_builder::export::core::convert::AsRef::<str>::as_ref(&value)
+                        )
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn newtype_inner() {
+        let ty: syn::Type = parse_quote!(units::Kilograms<f32>);
+        let inner = NewtypeInner::Explicit(Box::new(parse_quote!(f32)));
+        let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.newtype_inner = Some(&inner);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::Into<f32>>(
+                    &mut self,
+                    value: VALUE
+                ) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        units::Kilograms(::derive_builder::export::core::convert::Into::into(value))
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn newtype_inner_derived() {
+        let ty: syn::Type = parse_quote!(units::Kilograms);
+        let inner = NewtypeInner::Derived;
+        let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.newtype_inner = Some(&inner);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::Into<
+                    <units::Kilograms as ::derive_builder::Newtype>::Inner
+                >>(
+                    &mut self,
+                    value: VALUE
+                ) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        <units::Kilograms as ::derive_builder::Newtype>::from_inner(
+                            ::derive_builder::export::core::convert::Into::into(value)
+                        )
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn boxed() {
+        let ty: syn::Type = parse_quote!(std::rc::Rc<String>);
+        let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.boxed = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::Into<String>>(
+                    &mut self,
+                    value: VALUE
+                ) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        <#ty>::new(::derive_builder::export::core::convert::Into::into(value))
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let ty: syn::Type = parse_quote!(Vec<String>);
+        let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.into_iter = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<
+                    VALUE: ::derive_builder::export::core::iter::IntoIterator<Item = FROM_VALUE>,
+                    FROM_VALUE: ::derive_builder::export::core::convert::Into<String>
+                >(
+                    &mut self,
+                    value: VALUE
+                ) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::iter::Iterator::collect(
+                            ::derive_builder::export::core::iter::Iterator::map(
+                                ::derive_builder::export::core::iter::IntoIterator::into_iter(value),
+                                ::derive_builder::export::core::convert::Into::into
+                            )
+                        )
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn with_mut() {
+        let ty: syn::Type = parse_quote!(Vec<String>);
+        let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.setter_enabled = true;
+        setter.with_mut = Some((
+            syn::Ident::new("with_foo", Span::call_site()),
+            quote!(vec![]),
+        ));
+
+        let tokens = quote!(#setter).to_string();
+        assert!(tokens.contains(
+            &quote!(
+                #[doc = "Modify `foo` in place with `f`, starting from its default value if it is unset."]
+                #[allow(unused_mut)]
+                pub fn with_foo(
+                    &mut self,
+                    f: impl ::derive_builder::export::core::ops::FnOnce(&mut Vec<String>)
+                ) -> &mut Self {
+                    let mut new = self;
+                    f(new.foo.get_or_insert_with(|| vec![]));
+                    new
+                }
+            )
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn option_setter() {
+        let ty: syn::Type = parse_quote!(Option<Foo>);
+        let mut setter = default_setter!();
+        setter.field_type = BuilderFieldType::Optional(&ty);
+        setter.strip_option = true;
+        setter.option_ident = Some(syn::Ident::new("maybe_foo", Span::call_site()));
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: Foo) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::option::Option::Some(value)
+                    );
+                    new
+                }
+
+                #[doc = "Set `foo` to `value` as is, so that `None` clears it."]
+                #[allow(unused_mut)]
+                pub fn maybe_foo(&mut self, value: Option<Foo>) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn transform() {
+        let transform: syn::Expr = parse_quote!(|x: &str| Foo::parse(x));
+        let param: syn::Type = parse_quote!(&str);
+        let mut setter = default_setter!();
+        setter.transform = Some((&transform, &param));
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: &str) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        (|x: &str| Foo::parse(x))(value)
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let validate: syn::Expr = parse_quote!(|v: &Foo| v.check());
+        let mut setter = default_setter!();
+        setter.validate = Some((&validate, parse_quote!(FooBuilderError)));
+        setter.try_setter = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: Foo)
+                    -> ::derive_builder::export::core::result::Result<&mut Self, FooBuilderError>
+                {
+                    let value: Foo = value;
+                    (|v: &Foo| v.check())(&value)?;
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    ::derive_builder::export::core::result::Result::Ok(new)
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn try_into() {
+        let mut setter = default_setter!();
+        setter.try_into = true;
+        setter.try_setter = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::TryInto<Foo>>(&mut self, value: VALUE)
+                    -> ::derive_builder::export::core::result::Result<&mut Self, VALUE::Error>
+                {
+                    let value: Foo = ::derive_builder::export::core::convert::TryInto::try_into(value)?;
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    ::derive_builder::export::core::result::Result::Ok(new)
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn type_alias() {
+        let alias: syn::Path = parse_quote!(crate::FooPort);
+        let mut setter = default_setter!();
+        setter.type_alias = Some(&alias);
+
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: crate::FooPort) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn doc_comment() {
+        let mut setter = default_setter!();
+        setter.doc_comment = Some(parse_quote!(#[doc = "Sets the foo field."]));
+
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[doc = "Sets the foo field."]
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: Foo) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn once() {
+        let mut setter = default_setter!();
+        setter.once = Some(SetOnce::Error);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: Foo)
+                    -> ::derive_builder::export::core::result::Result<&mut Self, ::derive_builder::AlreadySetError>
+                {
+                    if self.foo.is_some() {
+                        return ::derive_builder::export::core::result::Result::Err(
+                            ::derive_builder::AlreadySetError::from("foo")
+                        );
+                    }
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    ::derive_builder::export::core::result::Result::Ok(new)
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn once_panic() {
+        let mut setter = default_setter!();
+        setter.pattern = BuilderPattern::Owned;
+        setter.once = Some(SetOnce::Panic);
+
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(self, value: Foo) -> Self {
+                    if self.foo.is_some() {
+                        ::derive_builder::export::core::panic!(
+                            "{}",
+                            ::derive_builder::AlreadySetError::from("foo")
+                        );
+                    }
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn owned() {
+        let mut setter = default_setter!();
+        setter.pattern = BuilderPattern::Owned;
+
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(self, value: Foo) -> Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn private() {
+        let vis = Cow::Owned(syn::Visibility::Inherited);
+
+        let mut setter = default_setter!();
+        setter.visibility = vis;
+
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                fn foo(&mut self, value: Foo) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value);
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn generic() {
+        let mut setter = default_setter!();
+        setter.generic_into = true;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo<VALUE: ::derive_builder::export::core::convert::Into<Foo>>(
+                    &mut self,
+                    value: VALUE
+                ) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(value.into());
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn strip_option() {
+        let ty = parse_quote!(Option<Foo>);
+        let mut setter = default_setter!();
+        setter.strip_option = true;
+        setter.field_type = BuilderFieldType::Optional(&ty);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(&mut self, value: Foo) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::option::Option::Some(value)
+                    );
+                    new
+                }
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn lazy() {
+        let ty = parse_quote!(Foo);
+        let mut setter = default_setter!();
+        setter.pattern = BuilderPattern::Owned;
+        setter.field_type = BuilderFieldType::Lazy(&ty);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            quote!(#setter).to_string(),
+            quote!(
+                #[allow(unused_mut)]
+                pub fn foo(self, value: Foo) -> Self {
+                    let mut new = self;
+                    new.foo = ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::boxed::Box::new({
+                            let value: Foo = value;
+                            move || value
+                        })
                     );
                     new
                 }
@@ -489,10 +1731,10 @@ mod tests {
             #[some_attr]
             pub fn try_foo<VALUE: ::derive_builder::export::core::convert::TryInto<Foo>>(&mut self, value: VALUE)
                 -> ::derive_builder::export::core::result::Result<&mut Self, VALUE::Error> {
-                let converted : Foo = value.try_into()?;
+                let converted: Foo = ::derive_builder::export::core::convert::TryInto::try_into(value)?;
                 let mut new = self;
                 new.foo = ::derive_builder::export::core::option::Option::Some(converted);
-                Ok(new)
+                ::derive_builder::export::core::result::Result::Ok(new)
             }
         ).to_string()
         );
@@ -569,10 +1811,10 @@ mod tests {
                     &mut self,
                     value: VALUE
                 ) -> ::derive_builder::export::core::result::Result<&mut Self, VALUE::Error> {
-                    let converted: Foo = value.try_into()?;
+                    let converted: Foo = ::derive_builder::export::core::convert::TryInto::try_into(value)?;
                     let mut new = self;
                     new.foo = ::derive_builder::export::core::option::Option::Some(converted);
-                    Ok(new)
+                    ::derive_builder::export::core::result::Result::Ok(new)
                 }
             )
             .to_string()
@@ -584,14 +1826,15 @@ mod tests {
         let ty_foo = parse_quote!(Foo);
         assert_eq!(extract_type_from_option(&ty_foo), None);
 
-        for s in vec![
+        let option_types: [syn::Type; 5] = [
             parse_quote!(Option<Foo>),
             parse_quote!(std::option::Option<Foo>),
             parse_quote!(::std::option::Option<Foo>),
             parse_quote!(core::option::Option<Foo>),
             parse_quote!(::core::option::Option<Foo>),
-        ] {
-            assert_eq!(extract_type_from_option(&s), Some(&ty_foo));
+        ];
+        for s in &option_types {
+            assert_eq!(extract_type_from_option(s), Some(&ty_foo));
         }
     }
 }
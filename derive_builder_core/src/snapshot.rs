@@ -0,0 +1,377 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+use syn::ext::IdentExt;
+
+use BuilderFieldType;
+
+/// Snapshot of the state of a builder, implementing `quote::ToTokens`.
+///
+/// Emits a `FooBuilderSnapshot` struct with one `Option<String>` per builder field, a
+/// `snapshot` method on the builder producing it, and a `Display` implementation printing the
+/// snapshot as a TOML-like summary.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, BuilderSnapshot, SnapshotField};
+/// # fn main() {
+/// #    let mut snapshot = default_builder_snapshot!();
+/// #    snapshot.push_field(SnapshotField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #        sensitive: false,
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#snapshot).to_string(), quote!(
+/// #[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// pub struct FooBuilderSnapshot {
+///     pub foo: Option<String>,
+/// }
+///
+/// impl Display for FooBuilderSnapshot {
+///     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+///         write!(f, "[FooBuilder]")?;
+///         match self.foo {
+///             Some(ref value) => write!(f, "\nfoo = {}", value)?,
+///             None => write!(f, "\n# foo = unset")?,
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// impl FooBuilder {
+///     pub fn snapshot(&self) -> FooBuilderSnapshot
+///     where
+///         String: Debug
+///     {
+///         FooBuilderSnapshot {
+///             foo: self.foo.as_ref().map(|value| format!("{:?}", value)),
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderSnapshot<'a> {
+    /// Enables code generation for the snapshot struct and method.
+    pub enabled: bool,
+    /// Name of the snapshot struct.
+    pub ident: syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the snapshot struct and method, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// Names of the builder fields in the snapshot.
+    pub field_idents: Vec<syn::Ident>,
+    /// Expressions formatting each builder field, evaluating to `Option<String>`.
+    pub values: Vec<TokenStream>,
+    /// Bounds the `snapshot` method needs, e.g. `T: Debug` for formatted field types.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+/// A builder field formatted by `BuilderSnapshot`.
+#[derive(Debug, Clone)]
+pub struct SnapshotField<'a> {
+    /// Name of the builder field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the builder field.
+    pub field_type: BuilderFieldType<'a>,
+    /// Replace the value of the field with `<redacted>`, see `#[builder(field(sensitive))]`.
+    pub sensitive: bool,
+}
+
+impl<'a> SnapshotField<'a> {
+    /// The text replacing the value of a set field whose value must not be shown.
+    fn redaction(&self) -> Option<&'static str> {
+        if self.sensitive {
+            Some("<redacted>")
+        } else {
+            None
+        }
+    }
+
+    fn value(&self) -> TokenStream {
+        let ident = self.field_ident;
+        let placeholder =
+            |text: &str| quote!(::derive_builder::export::core::string::String::from(#text));
+        let format = |value: TokenStream| {
+            quote!(::derive_builder::export::core::string::ToString::to_string(
+                &format_args!("{:?}", #value)
+            ))
+        };
+        match (&self.field_type, self.redaction()) {
+            (BuilderFieldType::Optional(_), Some(redaction))
+            | (BuilderFieldType::Lazy(_), Some(redaction)) => {
+                let redacted = placeholder(redaction);
+                quote!(self.#ident.as_ref().map(|_| #redacted))
+            }
+            // The closure of a lazy field has no value to format.
+            (BuilderFieldType::Lazy(_), None) => {
+                let set = placeholder("<set>");
+                quote!(self.#ident.as_ref().map(|_| #set))
+            }
+            (BuilderFieldType::Optional(_), None) => {
+                let value = format(quote!(value));
+                quote!(self.#ident.as_ref().map(|value| #value))
+            }
+            (BuilderFieldType::Precise(_), redaction) => {
+                let value = match redaction {
+                    Some(redaction) => placeholder(redaction),
+                    None => format(quote!(&self.#ident)),
+                };
+                quote!(::derive_builder::export::core::option::Option::Some(#value))
+            }
+            (BuilderFieldType::Phantom(_), _) => {
+                unreachable!("phantom fields are not in the snapshot")
+            }
+        }
+    }
+
+    fn bound(&self) -> Option<syn::WherePredicate> {
+        match self.field_type {
+            _ if self.redaction().is_some() => None,
+            BuilderFieldType::Optional(ty) | BuilderFieldType::Precise(ty) => {
+                Some(parse_quote!(#ty: ::derive_builder::export::core::fmt::Debug))
+            }
+            BuilderFieldType::Lazy(_) | BuilderFieldType::Phantom(_) => None,
+        }
+    }
+}
+
+impl<'a> ToTokens for BuilderSnapshot<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let field_idents = &self.field_idents;
+        let values = &self.values;
+        let method_where_clause = if self.bounds.is_empty() {
+            quote!()
+        } else {
+            let bounds = &self.bounds;
+            quote!(where #(#bounds),*)
+        };
+        let header = format!("[{}]", builder_ident.unraw());
+        let lines = self.field_idents.iter().map(|field_ident| {
+            let name = field_ident.unraw();
+            let set = format!("\n{} = {{}}", name);
+            let unset = format!("\n# {} = unset", name);
+            quote!(
+                match self.#field_ident {
+                    ::derive_builder::export::core::option::Option::Some(ref value) => {
+                        write!(f, #set, value)?
+                    }
+                    ::derive_builder::export::core::option::Option::None => write!(f, #unset)?,
+                }
+            )
+        });
+        let struct_doc = format!(
+            "The fields of a `{}` formatted as strings, see `{}::snapshot`.",
+            builder_ident, builder_ident
+        );
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            #[doc = #struct_doc]
+            #[derive(Debug, Clone, PartialEq, Eq, Default)]
+            #vis struct #ident {
+                #(
+                    /// `None` if the field is unset, and its `Debug` representation otherwise.
+                    pub #field_idents: ::derive_builder::export::core::option::Option<
+                        ::derive_builder::export::core::string::String
+                    >,
+                )*
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl ::derive_builder::export::core::fmt::Display for #ident {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    write!(f, #header)?;
+                    #(#lines)*
+                    ::derive_builder::export::core::result::Result::Ok(())
+                }
+            }
+        ));
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                /// Format the fields of this builder as strings, e.g. to log a failing build.
+                #vis fn snapshot(&self) -> #ident
+                    #method_where_clause
+                {
+                    #ident {
+                        #(#field_idents: #values,)*
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> BuilderSnapshot<'a> {
+    /// Add another builder field to the snapshot.
+    pub fn push_field(&mut self, field: SnapshotField) -> &mut Self {
+        if let BuilderFieldType::Phantom(_) = field.field_type {
+            return self;
+        }
+
+        self.field_idents.push(field.field_ident.clone());
+        self.values.push(field.value());
+        if let Some(bound) = field.bound() {
+            self.bounds.push(bound);
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_snapshot {
+    () => {
+        BuilderSnapshot {
+            enabled: true,
+            ident: syn::Ident::new("FooBuilderSnapshot", ::proc_macro2::Span::call_site()),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            field_idents: vec![],
+            values: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn disabled() {
+        let mut snapshot = default_builder_snapshot!();
+        snapshot.enabled = false;
+
+        assert_eq!(quote!(#snapshot).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new("bar", Span::call_site());
+        let marker = syn::Ident::new("marker", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut snapshot = default_builder_snapshot!();
+        snapshot.push_field(SnapshotField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+            sensitive: true,
+        });
+        snapshot.push_field(SnapshotField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+            sensitive: false,
+        });
+        snapshot.push_field(SnapshotField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+            sensitive: false,
+        });
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            #[doc = "The fields of a `FooBuilder` formatted as strings, see `FooBuilder::snapshot`."]
+            #[derive(Debug, Clone, PartialEq, Eq, Default)]
+            pub struct FooBuilderSnapshot {
+                /// `None` if the field is unset, and its `Debug` representation otherwise.
+                pub foo: ::derive_builder::export::core::option::Option<
+                    ::derive_builder::export::core::string::String
+                >,
+                /// `None` if the field is unset, and its `Debug` representation otherwise.
+                pub bar: ::derive_builder::export::core::option::Option<
+                    ::derive_builder::export::core::string::String
+                >,
+            }
+        ));
+
+        #[rustfmt::skip]
+        expected.append_all(quote!(
+            impl ::derive_builder::export::core::fmt::Display for FooBuilderSnapshot {
+                fn fmt(&self, f: &mut ::derive_builder::export::core::fmt::Formatter) -> ::derive_builder::export::core::fmt::Result {
+                    write!(f, "[FooBuilder]")?;
+                    match self.foo {
+                        ::derive_builder::export::core::option::Option::Some(ref value) => {
+                            write!(f, "\nfoo = {}", value)?
+                        }
+                        ::derive_builder::export::core::option::Option::None => write!(f, "\n# foo = unset")?,
+                    }
+                    match self.bar {
+                        ::derive_builder::export::core::option::Option::Some(ref value) => {
+                            write!(f, "\nbar = {}", value)?
+                        }
+                        ::derive_builder::export::core::option::Option::None => write!(f, "\n# bar = unset")?,
+                    }
+                    ::derive_builder::export::core::result::Result::Ok(())
+                }
+            }
+        ));
+
+        expected.append_all(quote!(
+            impl FooBuilder {
+                /// Format the fields of this builder as strings, e.g. to log a failing build.
+                pub fn snapshot(&self) -> FooBuilderSnapshot
+                where
+                    Vec<u8>: ::derive_builder::export::core::fmt::Debug
+                {
+                    FooBuilderSnapshot {
+                        foo: self.foo.as_ref().map(|_| ::derive_builder::export::core::string::String::from("<redacted>")),
+                        bar: ::derive_builder::export::core::option::Option::Some(
+                            ::derive_builder::export::core::string::ToString::to_string(
+                                &format_args!("{:?}", &self.bar)
+                            )
+                        ),
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#snapshot).to_string(), expected.to_string());
+    }
+}
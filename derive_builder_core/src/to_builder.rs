@@ -0,0 +1,252 @@
+use std::borrow::Cow;
+
+use allow_lints;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt};
+use syn;
+
+use BuilderFieldType;
+use IntoBuilderField;
+
+/// A method on the built struct returning a builder set from a clone of it, implementing
+/// `quote::ToTokens`.
+///
+/// Every builder field is set from a clone of the field of the struct, so each field type
+/// must implement `Clone`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust,ignore
+/// # extern crate proc_macro2;
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderFieldType, IntoBuilderField, ToBuilder};
+/// # fn main() {
+/// #    let mut to_builder = default_to_builder!();
+/// #    to_builder.push_field(IntoBuilderField {
+/// #        field_ident: &syn::Ident::new("foo", ::proc_macro2::Span::call_site()),
+/// #        field_type: BuilderFieldType::Optional(&parse_quote!(String)),
+/// #    });
+/// #
+/// #    assert_eq!(quote!(#to_builder).to_string(), quote!(
+/// impl Foo {
+///     /// Create a builder with every field set to a clone of the field of `self`.
+///     pub fn to_builder(&self) -> FooBuilder
+///     where
+///         String: Clone
+///     {
+///         FooBuilder {
+///             foo: Some(Clone::clone(&self.foo)),
+///         }
+///     }
+/// }
+/// #    ).to_string());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ToBuilder<'a> {
+    /// Enables code generation for the method.
+    pub enabled: bool,
+    /// Name of the target struct.
+    pub target_ident: &'a syn::Ident,
+    /// Name of the builder struct.
+    pub builder_ident: syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the method, e.g. `syn::Visibility::Public`.
+    pub visibility: Cow<'a, syn::Visibility>,
+    /// The identifier of the inherent method that creates an empty builder, used to fill the
+    /// builder fields which do not hold a field of the target struct.
+    pub create_empty: &'a syn::Ident,
+    /// Whether the builder has fields which do not hold a field of the target struct, such as
+    /// `PhantomData` for fields without setters or the cache of a lazy build method.
+    pub has_other_fields: bool,
+    /// Initializers of the builder fields, e.g. `foo: Some(Clone::clone(&self.foo)),`.
+    pub initializers: Vec<TokenStream>,
+    /// `Clone` bounds of the cloned field types.
+    pub bounds: Vec<syn::WherePredicate>,
+    /// Lints allowed on the generated items, see `#[builder(allow_lints(...))]`.
+    pub allowed_lints: &'a [syn::Path],
+}
+
+impl<'a> ToTokens for ToBuilder<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.enabled {
+            return;
+        }
+
+        let vis = &self.visibility;
+        let target_ident = self.target_ident;
+        let builder_ident = &self.builder_ident;
+        let (impl_generics, ty_generics, where_clause) = self
+            .generics
+            .map(syn::Generics::split_for_impl)
+            .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+            .unwrap_or((None, None, None));
+        let method_where_clause = if self.bounds.is_empty() {
+            quote!()
+        } else {
+            let bounds = &self.bounds;
+            quote!(where #(#bounds),*)
+        };
+        let initializers = &self.initializers;
+        let create_empty = self.create_empty;
+        let other_fields = if self.has_other_fields {
+            quote!(..<#builder_ident #ty_generics>::#create_empty())
+        } else {
+            quote!()
+        };
+
+        tokens.append_all(allow_lints(self.allowed_lints));
+
+        tokens.append_all(quote!(
+            impl #impl_generics #target_ident #ty_generics #where_clause {
+                /// Create a builder with every field set to a clone of the field of `self`.
+                #vis fn to_builder(&self) -> #builder_ident #ty_generics
+                    #method_where_clause
+                {
+                    #builder_ident {
+                        #(#initializers)*
+                        #other_fields
+                    }
+                }
+            }
+        ));
+    }
+}
+
+impl<'a> ToBuilder<'a> {
+    /// Set another builder field from a clone of its field.
+    pub fn push_field(&mut self, field: IntoBuilderField) -> &mut Self {
+        let ident = field.field_ident;
+        let cloned = quote!(::derive_builder::export::core::clone::Clone::clone(&self.#ident));
+        let ty = match field.field_type {
+            BuilderFieldType::Optional(ty) => {
+                self.initializers.push(quote!(
+                    #ident: ::derive_builder::export::core::option::Option::Some(#cloned),
+                ));
+                ty
+            }
+            // The builder field has either the type of the field, or a custom type which
+            // must be convertible from it.
+            BuilderFieldType::Precise(ty) => {
+                self.initializers.push(quote!(
+                    #ident: ::derive_builder::export::core::convert::Into::into(#cloned),
+                ));
+                ty
+            }
+            BuilderFieldType::Lazy(ty) => {
+                self.initializers.push(quote!(
+                    #ident: ::derive_builder::export::core::option::Option::Some(
+                        ::derive_builder::export::core::boxed::Box::new({
+                            let field = #cloned;
+                            move || field
+                        })
+                    ),
+                ));
+                ty
+            }
+            BuilderFieldType::Phantom(_) => {
+                self.has_other_fields = true;
+                return self;
+            }
+        };
+        self.bounds
+            .push(parse_quote!(#ty: ::derive_builder::export::core::clone::Clone));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_to_builder {
+    () => {
+        ToBuilder {
+            enabled: true,
+            target_ident: Box::leak(Box::new(syn::Ident::new(
+                "Foo",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            builder_ident: syn::Ident::new("FooBuilder", ::proc_macro2::Span::call_site()),
+            generics: None,
+            visibility: ::std::borrow::Cow::Owned(parse_quote!(pub)),
+            create_empty: Box::leak(Box::new(syn::Ident::new(
+                "create_empty",
+                ::proc_macro2::Span::call_site(),
+            ))),
+            has_other_fields: false,
+            initializers: vec![],
+            bounds: vec![],
+            allowed_lints: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn disabled() {
+        let mut to_builder = default_to_builder!();
+        to_builder.enabled = false;
+
+        assert_eq!(quote!(#to_builder).to_string(), quote!().to_string());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo", Span::call_site());
+        let bar = syn::Ident::new("bar", Span::call_site());
+        let marker = syn::Ident::new("marker", Span::call_site());
+        let string: syn::Type = parse_quote!(String);
+        let vec: syn::Type = parse_quote!(Vec<u8>);
+        let mut to_builder = default_to_builder!();
+        to_builder.push_field(IntoBuilderField {
+            field_ident: &foo,
+            field_type: BuilderFieldType::Optional(&string),
+        });
+        to_builder.push_field(IntoBuilderField {
+            field_ident: &marker,
+            field_type: BuilderFieldType::Phantom(&string),
+        });
+        to_builder.push_field(IntoBuilderField {
+            field_ident: &bar,
+            field_type: BuilderFieldType::Precise(&vec),
+        });
+
+        let mut expected = quote!();
+
+        expected.append_all(quote!(
+            impl Foo {
+                /// Create a builder with every field set to a clone of the field of `self`.
+                pub fn to_builder(&self) -> FooBuilder
+                where
+                    String: ::derive_builder::export::core::clone::Clone,
+                    Vec<u8>: ::derive_builder::export::core::clone::Clone
+                {
+                    FooBuilder {
+                        foo: ::derive_builder::export::core::option::Option::Some(
+                            ::derive_builder::export::core::clone::Clone::clone(&self.foo)
+                        ),
+                        bar: ::derive_builder::export::core::convert::Into::into(
+                            ::derive_builder::export::core::clone::Clone::clone(&self.bar)
+                        ),
+                        ..<FooBuilder>::create_empty()
+                    }
+                }
+            }
+        ));
+
+        assert_eq!(quote!(#to_builder).to_string(), expected.to_string());
+    }
+}
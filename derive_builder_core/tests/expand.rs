@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate pretty_assertions;
+extern crate derive_builder_core;
+extern crate proc_macro2;
+#[macro_use]
+extern crate syn;
+
+use derive_builder_core::expand;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+
+/// Flatten `tokens` into one string per token, so that expansions compare equal regardless of
+/// their formatting and the spacing of their punctuation.
+fn flatten(tokens: TokenStream) -> Vec<String> {
+    let mut flat = Vec::new();
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                flat.push(open.to_string());
+                flat.extend(flatten(group.stream()));
+                flat.push(close.to_string());
+            }
+            TokenTree::Punct(punct) => flat.push(punct.as_char().to_string()),
+            token => flat.push(token.to_string()),
+        }
+    }
+    flat
+}
+
+#[test]
+fn readme_example() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[derive(Default, Builder, Debug)]
+        #[builder(setter(into))]
+        struct Channel {
+            token: i32,
+            special_info: i32,
+        }
+    };
+    let golden: TokenStream = include_str!("golden/readme.rs")
+        .parse()
+        .expect("the golden file is valid Rust");
+
+    assert_eq!(flatten(expand(input)), flatten(golden));
+}
+
+#[test]
+fn setter_doc_replaces_field_doc() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[derive(Builder)]
+        struct Letter {
+            /// Be creative.
+            #[builder(setter(doc = "Sets the message."))]
+            message: String,
+        }
+    };
+    let expanded = expand(input).to_string();
+
+    // The builder field keeps the doc comment, the setter only has its own.
+    assert_eq!(expanded.matches("Be creative.").count(), 1);
+    assert_eq!(expanded.matches("Sets the message.").count(), 1);
+}
+
+#[test]
+fn setters_in_declaration_order() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[derive(Builder)]
+        #[builder(build_fn(skip))]
+        struct Order {
+            zulu: u8,
+            #[builder(setter(name = "with_alpha"))]
+            alpha: u8,
+            #[builder(setter(each(name = "item")))]
+            mike: Vec<u8>,
+        }
+    };
+    let golden: TokenStream = include_str!("golden/setter_order.rs")
+        .parse()
+        .expect("the golden file is valid Rust");
+
+    let expanded = flatten(expand(input.clone()));
+    assert_eq!(expanded, flatten(golden));
+    // Expanding again gives the same tokens, so the order does not depend on hashing.
+    assert_eq!(expanded, flatten(expand(input)));
+}
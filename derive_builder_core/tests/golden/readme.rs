@@ -0,0 +1,274 @@
+// Expected expansion of the example in the README, compared token by token by
+// `tests/expand.rs`. Update it along with changes to the generated code.
+
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[derive(Clone)]
+#[doc = "Builder for [`Channel`](struct.Channel.html).\n"]
+#[must_use]
+struct ChannelBuilder {
+    token: ::derive_builder::export::core::option::Option<i32>,
+    special_info: ::derive_builder::export::core::option::Option<i32>,
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[allow(dead_code)]
+impl ChannelBuilder {
+    #[allow(unused_mut)]
+    pub fn token<VALUE: ::derive_builder::export::core::convert::Into<i32>>(
+        &mut self,
+        value: VALUE
+    ) -> &mut Self {
+        let mut new = self;
+        new.token = ::derive_builder::export::core::option::Option::Some(value.into());
+        new
+    }
+    #[allow(unused_mut)]
+    pub fn special_info<VALUE: ::derive_builder::export::core::convert::Into<i32>>(
+        &mut self,
+        value: VALUE
+    ) -> &mut Self {
+        let mut new = self;
+        new.special_info = ::derive_builder::export::core::option::Option::Some(value.into());
+        new
+    }
+    #[doc = "Builds a new `Channel`.\n\n# Errors\n\nIf a required field has not been initialized.\n"]
+    #[must_use = "the built value or its error should be handled"]
+    fn build(
+        &self
+    ) -> ::derive_builder::export::core::result::Result<Channel, ChannelBuilderError> {
+        let mut __missing_fields = ::derive_builder::export::core::vec::Vec::new();
+        if self.token.is_none() {
+            __missing_fields.push("token");
+        }
+        if self.special_info.is_none() {
+            __missing_fields.push("special_info");
+        }
+        if !__missing_fields.is_empty() {
+            return ::derive_builder::export::core::result::Result::Err(
+                ChannelBuilderError::UninitializedFields(__missing_fields)
+            );
+        }
+        ::derive_builder::export::core::result::Result::Ok(Channel {
+            token: match self.token {
+                ::derive_builder::export::core::option::Option::Some(ref value) =>
+                    ::derive_builder::export::core::clone::Clone::clone(value),
+                ::derive_builder::export::core::option::Option::None =>
+                    return ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::export::core::convert::Into::into(
+                            ::derive_builder::UninitializedFieldError::from("token")
+                        )
+                    ),
+            },
+            special_info: match self.special_info {
+                ::derive_builder::export::core::option::Option::Some(ref value) =>
+                    ::derive_builder::export::core::clone::Clone::clone(value),
+                ::derive_builder::export::core::option::Option::None =>
+                    return ::derive_builder::export::core::result::Result::Err(
+                        ::derive_builder::export::core::convert::Into::into(
+                            ::derive_builder::UninitializedFieldError::from("special_info")
+                        )
+                    ),
+            },
+        })
+    }
+    #[doc = r" Create an empty builder, with all fields set to `None` or `PhantomData`."]
+    #[doc = r""]
+    #[doc = r" Unlike the build method, this ignores default values; use it as a"]
+    #[doc = r" blank-slate constructor in tests."]
+    fn create_empty() -> Self {
+        Self {
+            token: ::derive_builder::export::core::default::Default::default(),
+            special_info: ::derive_builder::export::core::default::Default::default(),
+        }
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::default::Default for ChannelBuilder {
+    fn default() -> Self {
+        Self::create_empty()
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[allow(dead_code)]
+impl Channel {
+    #[doc = "Create an empty [`ChannelBuilder`]."]
+    fn builder() -> ChannelBuilder {
+        ::derive_builder::export::core::default::Default::default()
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[doc = "Error type for ChannelBuilder"]
+#[derive(Debug)]
+#[non_exhaustive]
+enum ChannelBuilderError {
+    #[doc = r" Uninitialized field"]
+    UninitializedField(&'static str),
+    #[doc = r" Uninitialized fields, all reported at once"]
+    UninitializedFields(::derive_builder::export::core::vec::Vec<&'static str>),
+    #[doc = r" Custom validation error"]
+    ValidationError(::derive_builder::export::core::string::String),
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::convert::From<::derive_builder::UninitializedFieldError>
+    for ChannelBuilderError
+{
+    fn from(s: ::derive_builder::UninitializedFieldError) -> Self {
+        Self::UninitializedField(s.field_name())
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::convert::From<::derive_builder::export::core::string::String>
+    for ChannelBuilderError
+{
+    fn from(s: ::derive_builder::export::core::string::String) -> Self {
+        Self::ValidationError(s)
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::fmt::Display for ChannelBuilderError {
+    fn fmt(
+        &self,
+        f: &mut ::derive_builder::export::core::fmt::Formatter
+    ) -> ::derive_builder::export::core::fmt::Result {
+        match self {
+            Self::UninitializedField(ref field) => write!(f, "`{}` must be initialized", field),
+            Self::UninitializedFields(ref fields) => {
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{}`", field)?;
+                }
+                write!(f, " must be initialized")
+            }
+            Self::ValidationError(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl std::error::Error for ChannelBuilderError {}
@@ -0,0 +1,248 @@
+// Expected expansion of the `Order` struct of `tests/expand.rs`, whose setters must follow
+// the declaration order of its fields. Update it along with changes to the generated code.
+
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[derive(Clone)]
+#[doc = "Builder for [`Order`](struct.Order.html).\n"]
+#[must_use]
+struct OrderBuilder {
+    zulu: ::derive_builder::export::core::option::Option<u8>,
+    alpha: ::derive_builder::export::core::option::Option<u8>,
+    mike: ::derive_builder::export::core::option::Option<Vec<u8>>,
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[allow(dead_code)]
+impl OrderBuilder {
+    #[allow(unused_mut)]
+    pub fn zulu(&mut self, value: u8) -> &mut Self {
+        let mut new = self;
+        new.zulu = ::derive_builder::export::core::option::Option::Some(value);
+        new
+    }
+    #[allow(unused_mut)]
+    pub fn with_alpha(&mut self, value: u8) -> &mut Self {
+        let mut new = self;
+        new.alpha = ::derive_builder::export::core::option::Option::Some(value);
+        new
+    }
+    #[allow(unused_mut)]
+    pub fn mike(&mut self, value: Vec<u8>) -> &mut Self {
+        let mut new = self;
+        new.mike = ::derive_builder::export::core::option::Option::Some(value);
+        new
+    }
+    #[allow(unused_mut)]
+    pub fn item<VALUE>(&mut self, item: VALUE) -> &mut Self
+    where
+        Vec<u8>: ::derive_builder::export::core::default::Default
+            + ::derive_builder::export::core::iter::Extend<VALUE>,
+    {
+        let mut new = self;
+        new.mike
+            .get_or_insert_with(::derive_builder::export::core::default::Default::default)
+            .extend(::derive_builder::export::core::option::Option::Some(item));
+        new
+    }
+    #[doc = r" Create an empty builder, with all fields set to `None` or `PhantomData`."]
+    #[doc = r""]
+    #[doc = r" Unlike the build method, this ignores default values; use it as a"]
+    #[doc = r" blank-slate constructor in tests."]
+    fn create_empty() -> Self {
+        Self {
+            zulu: ::derive_builder::export::core::default::Default::default(),
+            alpha: ::derive_builder::export::core::default::Default::default(),
+            mike: ::derive_builder::export::core::default::Default::default(),
+        }
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::default::Default for OrderBuilder {
+    fn default() -> Self {
+        Self::create_empty()
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[allow(dead_code)]
+impl Order {
+    #[doc = "Create an empty [`OrderBuilder`]."]
+    fn builder() -> OrderBuilder {
+        ::derive_builder::export::core::default::Default::default()
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+#[doc = "Error type for OrderBuilder"]
+#[derive(Debug)]
+#[non_exhaustive]
+enum OrderBuilderError {
+    #[doc = r" Uninitialized field"]
+    UninitializedField(&'static str),
+    #[doc = r" Uninitialized fields, all reported at once"]
+    UninitializedFields(::derive_builder::export::core::vec::Vec<&'static str>),
+    #[doc = r" Custom validation error"]
+    ValidationError(::derive_builder::export::core::string::String),
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::convert::From<::derive_builder::UninitializedFieldError>
+    for OrderBuilderError
+{
+    fn from(s: ::derive_builder::UninitializedFieldError) -> Self {
+        Self::UninitializedField(s.field_name())
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::convert::From<::derive_builder::export::core::string::String>
+    for OrderBuilderError
+{
+    fn from(s: ::derive_builder::export::core::string::String) -> Self {
+        Self::ValidationError(s)
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl ::derive_builder::export::core::fmt::Display for OrderBuilderError {
+    fn fmt(
+        &self,
+        f: &mut ::derive_builder::export::core::fmt::Formatter
+    ) -> ::derive_builder::export::core::fmt::Result {
+        match self {
+            Self::UninitializedField(ref field) => write!(f, "`{}` must be initialized", field),
+            Self::UninitializedFields(ref fields) => {
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{}`", field)?;
+                }
+                write!(f, " must be initialized")
+            }
+            Self::ValidationError(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+#[allow(
+    clippy::clone_on_copy,
+    clippy::let_and_return,
+    clippy::needless_update,
+    clippy::new_without_default,
+    clippy::question_mark,
+    clippy::redundant_clone,
+    clippy::redundant_field_names,
+    clippy::suspicious_else_formatting,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unit_arg,
+    clippy::useless_conversion,
+    clippy::wrong_self_convention
+)]
+impl std::error::Error for OrderBuilderError {}
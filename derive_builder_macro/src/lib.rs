@@ -1,4 +1,4 @@
-//! Derive a builder for a struct
+//! Derive a builder for a struct, or for choosing a variant of an enum, and the `Newtype` trait
 
 #![crate_type = "proc-macro"]
 #![deny(warnings)]
@@ -23,5 +23,19 @@ use proc_macro::TokenStream;
 )]
 pub fn derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as syn::DeriveInput);
-    derive_builder_core::builder_for_struct(ast).into()
+    derive_builder_core::expand(ast).into()
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(EnumBuilder, attributes(builder))]
+pub fn derive_enum(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    derive_builder_core::builder_for_enum(ast).into()
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(Newtype)]
+pub fn derive_newtype(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    derive_builder_core::newtype_for_struct(ast).into()
 }
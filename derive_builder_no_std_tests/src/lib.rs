@@ -1,5 +1,5 @@
 #![no_std]
-#![allow(unused, clippy::blacklisted_name)]
+#![allow(unused, clippy::disallowed_names)]
 
 extern crate alloc;
 #[macro_use]
@@ -23,6 +23,51 @@ pub fn build_foo_err() -> Option<String> {
     foo.err().map(|err| err.to_string())
 }
 
+#[derive(Builder)]
+#[builder(no_std, build_fn(validate = "Self::validate"))]
+pub struct Even {
+    pub value: i32,
+}
+
+impl EvenBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.value {
+            Some(value) if value % 2 != 0 => Err(value.to_string() + " is odd"),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn build_even_err() -> Option<String> {
+    let even = EvenBuilder::default().value(3).build();
+    even.err().map(|err| err.to_string())
+}
+
+#[derive(Builder)]
+#[builder(no_std(static_str_errors), build_fn(validate = "Self::validate"))]
+pub struct Odd {
+    pub value: i32,
+}
+
+impl OddBuilder {
+    fn validate(&self) -> Result<(), &'static str> {
+        match self.value {
+            Some(value) if value % 2 == 0 => Err("value is even"),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn build_odd_err() -> Option<String> {
+    let odd = OddBuilder::default().value(4).build();
+    odd.err().map(|err| err.to_string())
+}
+
+pub fn build_odd_missing() -> Option<String> {
+    let odd = OddBuilder::default().build();
+    odd.err().map(|err| err.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,4 +84,20 @@ mod tests {
             Some("`bar` must be initialized")
         );
     }
+
+    #[test]
+    fn test_validate_string() {
+        assert_eq!(EvenBuilder::default().value(2).build().unwrap().value, 2);
+        assert_eq!(build_even_err().as_deref(), Some("3 is odd"));
+    }
+
+    #[test]
+    fn test_validate_static_str() {
+        assert_eq!(OddBuilder::default().value(3).build().unwrap().value, 3);
+        assert_eq!(build_odd_err().as_deref(), Some("value is even"));
+        assert_eq!(
+            build_odd_missing().as_deref(),
+            Some("`value` must be initialized")
+        );
+    }
 }